@@ -0,0 +1,198 @@
+use std::time::{Duration, Instant};
+
+use solver::{Part, Puzzle, Year};
+
+/// Calls `f` and returns its result together with the time it took
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Returns `None` if there are no samples, the median of an even number of samples is the mean of the middle two
+    pub fn of(samples: &[Duration]) -> Option<Stats> {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let middle = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (*sorted.get(middle.checked_sub(1)?)? + sorted[middle]) / 2
+        } else {
+            sorted[middle]
+        };
+        Some(Stats {
+            min: *sorted.first()?,
+            median,
+            max: *sorted.last()?,
+        })
+    }
+}
+
+/// Durations of all runs of a single day
+pub struct DayTimings {
+    pub day: usize,
+    pub description: &'static str,
+    pub parse: Vec<Duration>,
+    pub parts: Vec<(Part, Vec<Duration>)>,
+}
+
+impl DayTimings {
+    /// Builds the solver from `input` and solves `parts` of it `runs` times, the input must be valid
+    pub fn measure(puzzle: &Puzzle, input: &str, parts: &[Part], runs: usize) -> DayTimings {
+        let mut timings = DayTimings {
+            day: puzzle.day(),
+            description: "",
+            parse: Vec::with_capacity(runs),
+            parts: parts
+                .iter()
+                .map(|&part| (part, Vec::with_capacity(runs)))
+                .collect(),
+        };
+        for _ in 0..runs {
+            let (solver, parse_time) = measure(|| puzzle.build(input));
+            let solver = solver.expect("input is validated before it is measured");
+            timings.description = solver.description();
+            timings.parse.push(parse_time);
+            for (part, samples) in &mut timings.parts {
+                let (_, solve_time) = measure(|| part.solve(solver.as_ref()));
+                samples.push(solve_time);
+            }
+        }
+        timings
+    }
+
+    pub fn parse_stats(&self) -> Stats {
+        Stats::of(&self.parse).expect("a day is measured at least once")
+    }
+
+    pub fn part_stats(&self) -> impl Iterator<Item = (Part, Stats)> + '_ {
+        self.parts.iter().map(|(part, samples)| {
+            let stats = Stats::of(samples).expect("a day is measured at least once");
+            (*part, stats)
+        })
+    }
+
+    /// Sum of the median durations of parsing and all solved parts
+    pub fn total_median(&self) -> Duration {
+        self.parse_stats().median
+            + self
+                .part_stats()
+                .map(|(_, stats)| stats.median)
+                .sum::<Duration>()
+    }
+}
+
+pub fn print_day_timings(timings: &DayTimings) {
+    println!("Day {}: {}", timings.day, timings.description);
+    print_stats("parse", timings.parse_stats());
+    for (part, stats) in timings.part_stats() {
+        print_stats(&format!("part {}", part.number()), stats);
+    }
+}
+
+fn print_stats(name: &str, stats: Stats) {
+    println!(
+        "  {name:<7} min {:>10.2?}  median {:>10.2?}  max {:>10.2?}",
+        stats.min, stats.median, stats.max
+    );
+}
+
+/// Prints the median durations of the days of a year, the slowest day first
+pub fn print_year_summary(year: Year, runs: usize, days: &mut [DayTimings]) {
+    days.sort_by_key(|timings| std::cmp::Reverse(timings.total_median()));
+    println!("Summary of {year}, median of {runs} run(s), slowest first");
+    println!(
+        "  {:>3}  {:>10}  {:>10}  {:>10}  {:>10}",
+        "day", "parse", "part 1", "part 2", "total"
+    );
+    for timings in days.iter() {
+        let part_median = |part: Part| {
+            timings
+                .part_stats()
+                .find(|(p, _)| *p == part)
+                .map_or("-".to_owned(), |(_, stats)| format!("{:.2?}", stats.median))
+        };
+        println!(
+            "  {:>3}  {:>10.2?}  {:>10}  {:>10}  {:>10.2?}",
+            timings.day,
+            timings.parse_stats().median,
+            part_median(Part::One),
+            part_median(Part::Two),
+            timings.total_median()
+        );
+    }
+}
+
+#[cfg(test)]
+mod bench_tests {
+    use super::*;
+    use solver::{Answer, SolverBase};
+
+    struct CountingSolver;
+
+    impl SolverBase for CountingSolver {
+        fn solve_part_one(&self) -> Answer {
+            Answer::Integer(1)
+        }
+
+        fn day_number(&self) -> usize {
+            3
+        }
+
+        fn description(&self) -> &'static str {
+            "Counting"
+        }
+    }
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn test_stats_of_odd_number_of_samples() {
+        assert_eq!(
+            Stats::of(&[ms(5), ms(1), ms(3)]),
+            Some(Stats {
+                min: ms(1),
+                median: ms(3),
+                max: ms(5),
+            })
+        );
+    }
+
+    #[test]
+    fn test_stats_of_even_number_of_samples() {
+        assert_eq!(
+            Stats::of(&[ms(8), ms(2), ms(4), ms(1)]),
+            Some(Stats {
+                min: ms(1),
+                median: ms(3),
+                max: ms(8),
+            })
+        );
+    }
+
+    #[test]
+    fn test_stats_of_no_samples() {
+        assert_eq!(Stats::of(&[]), None);
+    }
+
+    #[test]
+    fn test_measure_day() {
+        let puzzle = Puzzle::new(3, |_| Ok(Box::new(CountingSolver)));
+        let timings = DayTimings::measure(&puzzle, "", &[Part::Two], 4);
+        assert_eq!(timings.day, 3);
+        assert_eq!(timings.description, "Counting");
+        assert_eq!(timings.parse.len(), 4);
+        assert_eq!(timings.parts.len(), 1);
+        assert_eq!(timings.parts[0].0, Part::Two);
+        assert_eq!(timings.parts[0].1.len(), 4);
+    }
+}
@@ -0,0 +1,235 @@
+use std::path::PathBuf;
+
+use solver::{Part, Year};
+
+pub const USAGE: &str = r"Usage:
+  aoc run [--year <year>] [--day <day>] [--part <1|2>] [--input <file>] [--input-dir <dir>]
+          [--time] [--include-skipped]
+  aoc bench [--year <year>] [--day <day>] [--part <1|2>] [--input-dir <dir>] [--runs <n>]
+            [--include-skipped]
+  aoc verify [--year <year>] [--day <day>] [--part <1|2>] [--input-dir <dir>]
+             [--include-skipped]
+  aoc list [--year <year>] [--input-dir <dir>]
+  aoc help
+
+Inputs are read from `<dir>/<year>/dayNN.txt` when `--input-dir` or the AOC_INPUT_DIR
+environment variable is set, otherwise from the `input` directory of each year.
+`--input` reads the input of a single selected day from the given file.
+Days marked as too slow are skipped unless a day is selected or `--include-skipped` is given.
+`--time` prints how long parsing and each part took, `bench` repeats that `--runs` times
+(default 5) and prints a summary of each year sorted by the slowest day.
+`verify` compares the answers with the ones recorded in `answers.json` next to the inputs
+of each year and fails if any of them differs.";
+
+/// Number of runs of each day done by `bench` if `--runs` is not given
+pub const DEFAULT_BENCH_RUNS: usize = 5;
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Selection {
+    pub year: Option<Year>,
+    pub day: Option<usize>,
+    pub part: Option<Part>,
+    pub input: Option<PathBuf>,
+    pub input_dir: Option<PathBuf>,
+    pub include_skipped: bool,
+    pub time: bool,
+    pub runs: Option<usize>,
+}
+
+impl Selection {
+    /// Parts to solve, both unless a single part is selected
+    pub fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(part) => vec![part],
+            None => vec![Part::One, Part::Two],
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    Run(Selection),
+    Bench(Selection),
+    Verify(Selection),
+    List(Selection),
+    Help,
+}
+
+pub fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Command, String> {
+    let mut args = args.into_iter();
+    let command = match args.next().as_deref() {
+        None | Some("run") => Command::Run(parse_selection(args)?),
+        Some("bench") => Command::Bench(parse_selection(args)?),
+        Some("verify") => Command::Verify(parse_selection(args)?),
+        Some("list") => Command::List(parse_selection(args)?),
+        Some("help" | "--help" | "-h") => Command::Help,
+        Some(other) => return Err(format!("unknown command `{other}`")),
+    };
+    match &command {
+        Command::Run(selection) if selection.runs.is_some() => {
+            Err("`--runs` is only supported by `bench`".to_owned())
+        }
+        Command::Bench(selection) if selection.input.is_some() || selection.time => {
+            Err("`--input` and `--time` are not supported by `bench`".to_owned())
+        }
+        Command::Verify(selection)
+            if selection.input.is_some() || selection.time || selection.runs.is_some() =>
+        {
+            Err("`--input`, `--time` and `--runs` are not supported by `verify`".to_owned())
+        }
+        _ => Ok(command),
+    }
+}
+
+fn parse_selection(mut args: impl Iterator<Item = String>) -> Result<Selection, String> {
+    let mut selection = Selection::default();
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| {
+            args.next()
+                .ok_or_else(|| format!("missing value for `{name}`"))
+        };
+        match arg.as_str() {
+            "--year" | "-y" => selection.year = Some(parse_number(&arg, &value(&arg)?)?),
+            "--day" | "-d" => selection.day = Some(parse_number(&arg, &value(&arg)?)?),
+            "--part" | "-p" => {
+                let number = parse_number(&arg, &value(&arg)?)?;
+                let part = Part::from_number(number)
+                    .ok_or_else(|| format!("part must be 1 or 2, got {number}"))?;
+                selection.part = Some(part);
+            }
+            "--input" | "-i" => selection.input = Some(PathBuf::from(value(&arg)?)),
+            "--input-dir" => selection.input_dir = Some(PathBuf::from(value(&arg)?)),
+            "--include-skipped" => selection.include_skipped = true,
+            "--time" | "-t" => selection.time = true,
+            "--runs" | "-r" => {
+                let runs = parse_number(&arg, &value(&arg)?)?;
+                if runs == 0 {
+                    return Err("`--runs` must be at least 1".to_owned());
+                }
+                selection.runs = Some(runs);
+            }
+            _ => return Err(format!("unknown argument `{arg}`")),
+        }
+    }
+    Ok(selection)
+}
+
+fn parse_number<T: std::str::FromStr>(name: &str, value: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("invalid value `{value}` for `{name}`"))
+}
+
+#[cfg(test)]
+mod cli_tests {
+    use super::*;
+
+    fn parse(args: &str) -> Result<Command, String> {
+        parse_args(args.split_whitespace().map(str::to_owned))
+    }
+
+    #[test]
+    fn test_run_everything_by_default() {
+        assert_eq!(parse(""), Ok(Command::Run(Selection::default())));
+    }
+
+    #[test]
+    fn test_run_single_part() {
+        assert_eq!(
+            parse("run --year 2017 --day 13 --part 2"),
+            Ok(Command::Run(Selection {
+                year: Some(2017),
+                day: Some(13),
+                part: Some(Part::Two),
+                ..Selection::default()
+            }))
+        );
+    }
+
+    #[test]
+    fn test_run_with_input_override() {
+        assert_eq!(
+            parse("run -y 2025 -d 10 --input my/day10.txt --input-dir inputs"),
+            Ok(Command::Run(Selection {
+                year: Some(2025),
+                day: Some(10),
+                input: Some(PathBuf::from("my/day10.txt")),
+                input_dir: Some(PathBuf::from("inputs")),
+                ..Selection::default()
+            }))
+        );
+    }
+
+    #[test]
+    fn test_list_year() {
+        assert_eq!(
+            parse("list -y 2015"),
+            Ok(Command::List(Selection {
+                year: Some(2015),
+                ..Selection::default()
+            }))
+        );
+    }
+
+    #[test]
+    fn test_run_with_timing() {
+        assert_eq!(
+            parse("run -y 2016 --time --include-skipped"),
+            Ok(Command::Run(Selection {
+                year: Some(2016),
+                time: true,
+                include_skipped: true,
+                ..Selection::default()
+            }))
+        );
+    }
+
+    #[test]
+    fn test_bench() {
+        assert_eq!(
+            parse("bench --year 2015 --runs 3"),
+            Ok(Command::Bench(Selection {
+                year: Some(2015),
+                runs: Some(3),
+                ..Selection::default()
+            }))
+        );
+    }
+
+    #[test]
+    fn test_verify() {
+        assert_eq!(
+            parse("verify -y 2024 --include-skipped"),
+            Ok(Command::Verify(Selection {
+                year: Some(2024),
+                include_skipped: true,
+                ..Selection::default()
+            }))
+        );
+    }
+
+    #[test]
+    fn test_selected_parts() {
+        assert_eq!(Selection::default().parts(), vec![Part::One, Part::Two]);
+        let selection = Selection {
+            part: Some(Part::Two),
+            ..Selection::default()
+        };
+        assert_eq!(selection.parts(), vec![Part::Two]);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(parse("run --part 3").is_err());
+        assert!(parse("run --day").is_err());
+        assert!(parse("run --input").is_err());
+        assert!(parse("run --day x").is_err());
+        assert!(parse("run --verbose").is_err());
+        assert!(parse("solve").is_err());
+        assert!(parse("run --runs 3").is_err());
+        assert!(parse("bench --runs 0").is_err());
+        assert!(parse("bench --time").is_err());
+        assert!(parse("bench --input day01.txt").is_err());
+        assert!(parse("verify -d 1 --input day01.txt").is_err());
+    }
+}
@@ -0,0 +1,305 @@
+use std::process::ExitCode;
+
+use solver::{
+    Answer, Answers, AnswersError, InputLocator, LoadError, Part, Puzzle, Registry, SolveError,
+    SolverBase, Verdict, read_input,
+};
+
+mod bench;
+mod cli;
+
+use bench::{DayTimings, measure};
+use cli::{Command, Selection};
+
+fn registry() -> Registry {
+    let mut registry = Registry::new();
+    aoc_2015::register(&mut registry);
+    aoc_2016::register(&mut registry);
+    aoc_2017::register(&mut registry);
+    aoc_2024::register(&mut registry);
+    aoc_2025::register(&mut registry);
+    registry
+}
+
+fn main() -> ExitCode {
+    let command = match cli::parse_args(std::env::args().skip(1)) {
+        Ok(command) => command,
+        Err(error) => {
+            eprintln!("error: {error}");
+            eprintln!("{}", cli::USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    match command {
+        Command::Run(selection) => run(&registry(), &selection),
+        Command::Bench(selection) => bench(&registry(), &selection),
+        Command::Verify(selection) => verify(&registry(), &selection),
+        Command::List(selection) => list(&registry(), &selection),
+        Command::Help => {
+            println!("{}", cli::USAGE);
+            ExitCode::SUCCESS
+        }
+    }
+}
+
+fn input_locator(selection: &Selection) -> InputLocator {
+    match &selection.input_dir {
+        Some(input_dir) => InputLocator::new(Some(input_dir.clone())),
+        None => InputLocator::from_env(),
+    }
+}
+
+fn load_input(
+    puzzle: &Puzzle,
+    selection: &Selection,
+    locator: &InputLocator,
+) -> Result<String, String> {
+    let path = match &selection.input {
+        Some(input) => input.clone(),
+        None => puzzle.input_path(locator),
+    };
+    read_input(&path).map_err(|error| error.to_string())
+}
+
+/// An explicitly selected day runs even if it is too slow for a full run
+fn is_skipped(solver: &dyn SolverBase, selection: &Selection) -> bool {
+    solver.skip_run() && selection.day.is_none() && !selection.include_skipped
+}
+
+fn run(registry: &Registry, selection: &Selection) -> ExitCode {
+    let puzzles = registry.select(selection.year, selection.day);
+    if puzzles.is_empty() {
+        eprintln!("error: no solver matches the selection");
+        return ExitCode::FAILURE;
+    }
+    if selection.input.is_some() && puzzles.len() > 1 {
+        eprintln!("error: `--input` requires a single day to be selected");
+        return ExitCode::from(2);
+    }
+
+    let locator = input_locator(selection);
+    let mut exit_code = ExitCode::SUCCESS;
+    let mut current_year = None;
+    for puzzle in puzzles {
+        if current_year != Some(puzzle.year()) {
+            println!("Year {}", puzzle.year());
+            current_year = Some(puzzle.year());
+        }
+        let input = match load_input(puzzle, selection, &locator) {
+            Ok(input) => input,
+            Err(error) => {
+                println!("Day {}", puzzle.day());
+                eprintln!("  error: {error}");
+                exit_code = ExitCode::FAILURE;
+                continue;
+            }
+        };
+        let (solver, parse_time) = measure(|| puzzle.build(&input));
+        let solver = match solver {
+            Ok(solver) => solver,
+            Err(error) => {
+                println!("Day {}", puzzle.day());
+                eprintln!("  error: {error}");
+                exit_code = ExitCode::FAILURE;
+                continue;
+            }
+        };
+        if selection.time {
+            println!(
+                "Day {}: {} (parse {parse_time:.2?})",
+                solver.day_number(),
+                solver.description()
+            );
+        } else {
+            println!("Day {}: {}", solver.day_number(), solver.description());
+        }
+        if is_skipped(solver.as_ref(), selection) {
+            println!("  skipping...");
+            continue;
+        }
+        for part in selection.parts() {
+            if let Err(SolveError::Panicked(_)) = print_part(solver.as_ref(), part, selection.time)
+            {
+                exit_code = ExitCode::FAILURE;
+            }
+        }
+    }
+    exit_code
+}
+
+fn print_part(solver: &dyn SolverBase, part: Part, time: bool) -> Result<(), SolveError> {
+    let (result, solve_time) = measure(|| part.solve(solver));
+    let time = if time {
+        format!(" ({solve_time:.2?})")
+    } else {
+        String::new()
+    };
+    match &result {
+        Ok(Answer::Grid(rows)) => {
+            println!("  Part {}:{time}", part.number());
+            for row in rows {
+                println!("    {row}");
+            }
+        }
+        Ok(answer) => println!("  Part {}: {answer}{time}", part.number()),
+        Err(error) => println!("  Part {}: {error}{time}", part.number()),
+    }
+    result.map(|_| ())
+}
+
+fn bench(registry: &Registry, selection: &Selection) -> ExitCode {
+    let puzzles = registry.select(selection.year, selection.day);
+    if puzzles.is_empty() {
+        eprintln!("error: no solver matches the selection");
+        return ExitCode::FAILURE;
+    }
+
+    let runs = selection.runs.unwrap_or(cli::DEFAULT_BENCH_RUNS);
+    let locator = input_locator(selection);
+    let mut exit_code = ExitCode::SUCCESS;
+    for year in registry.years() {
+        let year_puzzles: Vec<_> = puzzles.iter().filter(|p| p.year() == year).collect();
+        if year_puzzles.is_empty() {
+            continue;
+        }
+        println!("Year {year}");
+        let mut days = Vec::new();
+        for puzzle in year_puzzles {
+            let input = match load_input(puzzle, selection, &locator) {
+                Ok(input) => input,
+                Err(error) => {
+                    println!("Day {}", puzzle.day());
+                    eprintln!("  error: {error}");
+                    exit_code = ExitCode::FAILURE;
+                    continue;
+                }
+            };
+            let solver = match puzzle.build(&input) {
+                Ok(solver) => solver,
+                Err(error) => {
+                    println!("Day {}", puzzle.day());
+                    eprintln!("  error: {error}");
+                    exit_code = ExitCode::FAILURE;
+                    continue;
+                }
+            };
+            if is_skipped(solver.as_ref(), selection) {
+                println!("Day {}: {}", puzzle.day(), solver.description());
+                println!("  skipping...");
+                continue;
+            }
+            let timings = DayTimings::measure(puzzle, &input, &selection.parts(), runs);
+            bench::print_day_timings(&timings);
+            days.push(timings);
+        }
+        if !days.is_empty() {
+            bench::print_year_summary(year, runs, &mut days);
+        }
+    }
+    exit_code
+}
+
+fn load_answers(puzzle: &Puzzle, locator: &InputLocator) -> Result<Answers, AnswersError> {
+    match Answers::load(&puzzle.answers_path(locator)) {
+        Err(AnswersError::Read(error)) if error.kind() == std::io::ErrorKind::NotFound => {
+            eprintln!("note: no answers recorded in `{}`", error.path().display());
+            Ok(Answers::default())
+        }
+        result => result,
+    }
+}
+
+fn verify(registry: &Registry, selection: &Selection) -> ExitCode {
+    let puzzles = registry.select(selection.year, selection.day);
+    if puzzles.is_empty() {
+        eprintln!("error: no solver matches the selection");
+        return ExitCode::FAILURE;
+    }
+
+    let locator = input_locator(selection);
+    let mut exit_code = ExitCode::SUCCESS;
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    let mut current_year = None;
+    let mut answers = Answers::default();
+    for puzzle in puzzles {
+        if current_year != Some(puzzle.year()) {
+            println!("Year {}", puzzle.year());
+            current_year = Some(puzzle.year());
+            answers = load_answers(puzzle, &locator).unwrap_or_else(|error| {
+                eprintln!("error: {error}");
+                exit_code = ExitCode::FAILURE;
+                Answers::default()
+            });
+        }
+        let solver = match puzzle.load(&locator) {
+            Ok(solver) => solver,
+            Err(error) => {
+                println!("Day {}", puzzle.day());
+                eprintln!("  error: {error}");
+                exit_code = ExitCode::FAILURE;
+                continue;
+            }
+        };
+        println!("Day {}: {}", solver.day_number(), solver.description());
+        if is_skipped(solver.as_ref(), selection) {
+            println!("  skipping...");
+            continue;
+        }
+        for part in selection.parts() {
+            let expected = answers.get(puzzle.day(), part);
+            let answer = match part.solve(solver.as_ref()) {
+                Ok(answer) => answer.to_string(),
+                Err(error) if expected.is_some() || matches!(error, SolveError::Panicked(_)) => {
+                    failed += 1;
+                    println!("  Part {}: FAIL, {error}", part.number());
+                    continue;
+                }
+                Err(error) => {
+                    missing += 1;
+                    println!("  Part {}: missing answer, {error}", part.number());
+                    continue;
+                }
+            };
+            match Verdict::of(expected, &answer) {
+                Verdict::Pass => {
+                    passed += 1;
+                    println!("  Part {}: pass", part.number());
+                }
+                Verdict::Fail { expected } => {
+                    failed += 1;
+                    println!(
+                        "  Part {}: FAIL, expected `{expected}`, got `{answer}`",
+                        part.number()
+                    );
+                }
+                Verdict::Missing => {
+                    missing += 1;
+                    println!("  Part {}: missing answer, got `{answer}`", part.number());
+                }
+            }
+        }
+    }
+
+    println!("Verified: {passed} passed, {failed} failed, {missing} missing");
+    if failed > 0 {
+        exit_code = ExitCode::FAILURE;
+    }
+    exit_code
+}
+
+fn list(registry: &Registry, selection: &Selection) -> ExitCode {
+    let locator = input_locator(selection);
+    for puzzle in registry.select(selection.year, selection.day) {
+        let description = match puzzle.load(&locator) {
+            Ok(solver) if solver.skip_run() => format!("{} (skipped)", solver.description()),
+            Ok(solver) => solver.description().to_owned(),
+            Err(LoadError::Input(_)) => {
+                format!("missing input {}", puzzle.input_path(&locator).display())
+            }
+            Err(LoadError::Parse(error)) => format!("invalid input ({error})"),
+        };
+        println!("{} day {:>2}: {description}", puzzle.year(), puzzle.day());
+    }
+    ExitCode::SUCCESS
+}
@@ -1,17 +1,19 @@
-use solver::SolverBase;
+use solver::{Answer, ParseError, SolverBase};
 
 pub struct Solver {
-    input: &'static str,
+    input: String,
 }
 
 impl Solver {
-    pub fn new(input: &'static str) -> Self {
-        Solver { input }
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        Ok(Solver {
+            input: input.to_owned(),
+        })
     }
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
+    fn solve_part_one(&self) -> Answer {
         self.input
             .chars()
             .fold(0, |acc, c| match c {
@@ -19,10 +21,10 @@ impl SolverBase for Solver {
                 ')' => acc - 1,
                 _ => acc,
             })
-            .to_string()
+            .into()
     }
 
-    fn solve_part_two(&self) -> String {
+    fn solve_part_two(&self) -> Answer {
         let mut floor = 0;
         for (i, c) in self.input.chars().enumerate() {
             floor += match c {
@@ -31,7 +33,7 @@ impl SolverBase for Solver {
                 _ => 0,
             };
             if floor == -1 {
-                return (i + 1).to_string();
+                return (i + 1).into();
             }
         }
         panic!("Basement not reached");
@@ -51,55 +53,55 @@ mod part1_tests {
     use super::*;
     #[test]
     fn floor_0_1() {
-        let result = Solver::new("(())").solve_part_one();
+        let result = Solver::new("(())").unwrap().solve_part_one();
         assert_eq!(result, "0");
     }
 
     #[test]
     fn floor_0_2() {
-        let result = Solver::new("()()").solve_part_one();
+        let result = Solver::new("()()").unwrap().solve_part_one();
         assert_eq!(result, "0");
     }
 
     #[test]
     fn floor_3_1() {
-        let result = Solver::new("(((").solve_part_one();
+        let result = Solver::new("(((").unwrap().solve_part_one();
         assert_eq!(result, "3");
     }
 
     #[test]
     fn floor_3_2() {
-        let result = Solver::new("(()(()(").solve_part_one();
+        let result = Solver::new("(()(()(").unwrap().solve_part_one();
         assert_eq!(result, "3");
     }
 
     #[test]
     fn floor_3_3() {
-        let result = Solver::new("))(((((").solve_part_one();
+        let result = Solver::new("))(((((").unwrap().solve_part_one();
         assert_eq!(result, "3");
     }
 
     #[test]
     fn floor_neg1_1() {
-        let result = Solver::new("())").solve_part_one();
+        let result = Solver::new("())").unwrap().solve_part_one();
         assert_eq!(result, "-1");
     }
 
     #[test]
     fn floor_neg1_2() {
-        let result = Solver::new("))(").solve_part_one();
+        let result = Solver::new("))(").unwrap().solve_part_one();
         assert_eq!(result, "-1");
     }
 
     #[test]
     fn floor_neg3_1() {
-        let result = Solver::new(")))").solve_part_one();
+        let result = Solver::new(")))").unwrap().solve_part_one();
         assert_eq!(result, "-3");
     }
 
     #[test]
     fn floor_neg3_2() {
-        let result = Solver::new(")())())").solve_part_one();
+        let result = Solver::new(")())())").unwrap().solve_part_one();
         assert_eq!(result, "-3");
     }
 }
@@ -109,13 +111,13 @@ mod part2_tests {
     use super::*;
     #[test]
     fn basement_1() {
-        let result = Solver::new(")").solve_part_two();
+        let result = Solver::new(")").unwrap().solve_part_two();
         assert_eq!(result, "1");
     }
 
     #[test]
     fn basement_5() {
-        let result = Solver::new("()())").solve_part_two();
+        let result = Solver::new("()())").unwrap().solve_part_two();
         assert_eq!(result, "5");
     }
 }
@@ -1,4 +1,4 @@
-use solver::SolverBase;
+use solver::{Answer, ParseError, SolverBase, parse_field, parse_lines};
 
 struct Box {
     length: u32,
@@ -37,38 +37,41 @@ pub struct Solver {
 }
 
 impl Solver {
-    pub fn new(input: &'static str) -> Self {
-        let boxes = input
-            .lines()
-            .map(|line| {
-                let mut dimensions = line.split('x').map(|s| s.parse().unwrap());
-                Box {
-                    length: dimensions.next().unwrap(),
-                    width: dimensions.next().unwrap(),
-                    height: dimensions.next().unwrap(),
-                }
-            })
-            .collect();
-        Solver { boxes }
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let boxes = parse_lines(input, |line| {
+            let dimensions = line
+                .split('x')
+                .map(parse_field)
+                .collect::<Result<Vec<_>, _>>()?;
+            match dimensions[..] {
+                [length, width, height] => Ok(Box {
+                    length,
+                    width,
+                    height,
+                }),
+                _ => Err(ParseError::new("expected dimensions `LxWxH`")),
+            }
+        })?;
+        Ok(Solver { boxes })
     }
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
+    fn solve_part_one(&self) -> Answer {
         let mut total_area = 0;
         for b in self.boxes.iter() {
             total_area += b.get_sides().iter().sum::<u32>() + b.get_sides().iter().min().unwrap();
         }
-        total_area.to_string()
+        total_area.into()
     }
 
-    fn solve_part_two(&self) -> String {
+    fn solve_part_two(&self) -> Answer {
         let mut total_length = 0;
         for b in self.boxes.iter() {
             total_length +=
                 b.get_dimensions_sorted().iter().take(4).sum::<u32>() + b.get_cubic_volume();
         }
-        total_length.to_string()
+        total_length.into()
     }
 
     fn day_number(&self) -> usize {
@@ -86,13 +89,13 @@ mod part1_tests {
 
     #[test]
     fn wrapping_paper_area_1() {
-        let result = Solver::new("2x3x4").solve_part_one();
+        let result = Solver::new("2x3x4").unwrap().solve_part_one();
         assert_eq!(result, "58");
     }
 
     #[test]
     fn wrapping_paper_area_2() {
-        let result = Solver::new("1x1x10").solve_part_one();
+        let result = Solver::new("1x1x10").unwrap().solve_part_one();
         assert_eq!(result, "43");
     }
 }
@@ -103,13 +106,13 @@ mod part2_tests {
 
     #[test]
     fn ribbon_1() {
-        let result = Solver::new("2x3x4").solve_part_two();
+        let result = Solver::new("2x3x4").unwrap().solve_part_two();
         assert_eq!(result, "34");
     }
 
     #[test]
     fn ribbon_2() {
-        let result = Solver::new("1x1x10").solve_part_two();
+        let result = Solver::new("1x1x10").unwrap().solve_part_two();
         assert_eq!(result, "14");
     }
 }
@@ -1,74 +1,57 @@
-use solver::SolverBase;
+use solver::{Answer, Direction, ParseError, Point2, SolverBase};
 use std::collections::HashSet;
 
-#[derive(Debug, PartialEq, Clone, Copy)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
-
-#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
-struct Position(isize, isize);
-
-impl Position {
-    fn move_in_direction(&self, direction: Direction) -> Position {
-        match direction {
-            Direction::Up => Position(self.0, self.1 - 1),
-            Direction::Down => Position(self.0, self.1 + 1),
-            Direction::Left => Position(self.0 - 1, self.1),
-            Direction::Right => Position(self.0 + 1, self.1),
-        }
-    }
-}
+type Position = Point2<isize>;
 
 pub struct Solver {
     directions: Vec<Direction>,
 }
 
 impl Solver {
-    pub fn new(input: &'static str) -> Self {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
         let directions = input
             .chars()
-            .map(|c| match c {
-                '^' => Direction::Up,
-                'v' => Direction::Down,
-                '<' => Direction::Left,
-                '>' => Direction::Right,
-                _ => panic!("unrecognized symbol"),
+            .enumerate()
+            .map(|(index, c)| {
+                Direction::from_char(c)
+                    .filter(|_| "^v<>".contains(c))
+                    .ok_or_else(|| {
+                        ParseError::expected("one of `^v<>`", &c.to_string())
+                            .at_column(index + 1)
+                            .on_line(1, input)
+                    })
             })
-            .collect();
-        Solver { directions }
+            .collect::<Result<_, _>>()?;
+        Ok(Solver { directions })
     }
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
-        let mut position = Position(0, 0);
+    fn solve_part_one(&self) -> Answer {
+        let mut position = Position::default();
         let mut visited_positions = HashSet::new();
         visited_positions.insert(position);
         for direction in self.directions.iter() {
-            position = position.move_in_direction(*direction);
+            position = position.step(*direction);
             visited_positions.insert(position);
         }
-        visited_positions.len().to_string()
+        visited_positions.len().into()
     }
 
-    fn solve_part_two(&self) -> String {
-        let mut santa_position = Position(0, 0);
-        let mut robot_position = Position(0, 0);
+    fn solve_part_two(&self) -> Answer {
+        let mut santa_position = Position::default();
+        let mut robot_position = Position::default();
         let mut visited_positions = HashSet::new();
         visited_positions.insert(santa_position);
         visited_positions.insert(robot_position);
         for chunk in self.directions.chunks(2) {
-            santa_position = santa_position.move_in_direction(chunk[0]);
-            robot_position = robot_position.move_in_direction(chunk[1]);
+            santa_position = santa_position.step(chunk[0]);
+            robot_position = robot_position.step(chunk[1]);
 
             visited_positions.insert(santa_position);
             visited_positions.insert(robot_position);
         }
-        visited_positions.len().to_string()
+        visited_positions.len().into()
     }
 
     fn day_number(&self) -> usize {
@@ -86,19 +69,19 @@ mod part1_tests {
 
     #[test]
     fn test_1() {
-        let result = Solver::new(">").solve_part_one();
+        let result = Solver::new(">").unwrap().solve_part_one();
         assert_eq!(result, "2");
     }
 
     #[test]
     fn test_2() {
-        let result = Solver::new("^>v<").solve_part_one();
+        let result = Solver::new("^>v<").unwrap().solve_part_one();
         assert_eq!(result, "4");
     }
 
     #[test]
     fn test_3() {
-        let result = Solver::new("^v^v^v^v^v").solve_part_one();
+        let result = Solver::new("^v^v^v^v^v").unwrap().solve_part_one();
         assert_eq!(result, "2");
     }
 }
@@ -109,19 +92,19 @@ mod part2_tests {
 
     #[test]
     fn test_1() {
-        let result = Solver::new("^v").solve_part_two();
+        let result = Solver::new("^v").unwrap().solve_part_two();
         assert_eq!(result, "3");
     }
 
     #[test]
     fn test_2() {
-        let result = Solver::new("^>v<").solve_part_two();
+        let result = Solver::new("^>v<").unwrap().solve_part_two();
         assert_eq!(result, "3");
     }
 
     #[test]
     fn test_3() {
-        let result = Solver::new("^v^v^v^v^v").solve_part_two();
+        let result = Solver::new("^v^v^v^v^v").unwrap().solve_part_two();
         assert_eq!(result, "11");
     }
 }
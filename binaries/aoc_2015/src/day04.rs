@@ -1,38 +1,28 @@
-use solver::SolverBase;
+use solver::{Answer, NonceMiner, ParseError, SolverBase};
 
 pub struct Solver {
-    input: &'static str,
+    input: String,
 }
 
 impl Solver {
-    pub fn new(input: &'static str) -> Self {
-        Solver { input }
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        Ok(Solver {
+            input: input.to_owned(),
+        })
     }
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
-        for n in 0..u32::MAX {
-            let test = format!("{}{}", self.input, n);
-            let hash = md5::compute(test);
-            let hash_hex = format!("{:x}", hash);
-            if hash_hex.starts_with("00000") {
-                return n.to_string();
-            }
-        }
-        "".to_string()
+    fn solve_part_one(&self) -> Answer {
+        NonceMiner::new(&self.input)
+            .first_with_leading_zeros(5)
+            .map_or(Answer::NoSolution, Answer::from)
     }
 
-    fn solve_part_two(&self) -> String {
-        for n in 0..u32::MAX {
-            let test = format!("{}{}", self.input, n);
-            let hash = md5::compute(test);
-            let hash_hex = format!("{:x}", hash);
-            if hash_hex.starts_with("000000") {
-                return n.to_string();
-            }
-        }
-        "".to_string()
+    fn solve_part_two(&self) -> Answer {
+        NonceMiner::new(&self.input)
+            .first_with_leading_zeros(6)
+            .map_or(Answer::NoSolution, Answer::from)
     }
 
     fn day_number(&self) -> usize {
@@ -42,10 +32,6 @@ impl SolverBase for Solver {
     fn description(&self) -> &'static str {
         "MD5 hashes"
     }
-
-    fn skip_run(&self) -> bool {
-        true // too slow
-    }
 }
 
 #[cfg(test)]
@@ -54,13 +40,13 @@ mod part1_tests {
 
     #[test]
     fn test_1() {
-        let result = Solver::new("abcdef").solve_part_one();
+        let result = Solver::new("abcdef").unwrap().solve_part_one();
         assert_eq!(result, "609043");
     }
 
     #[test]
     fn test_2() {
-        let result = Solver::new("pqrstuv").solve_part_one();
+        let result = Solver::new("pqrstuv").unwrap().solve_part_one();
         assert_eq!(result, "1048970");
     }
 }
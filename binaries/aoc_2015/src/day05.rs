@@ -1,19 +1,21 @@
 use std::collections::HashMap;
 
-use solver::SolverBase;
+use solver::{Answer, ParseError, SolverBase};
 
 pub struct Solver {
-    input: &'static str,
+    input: String,
 }
 
 impl Solver {
-    pub fn new(input: &'static str) -> Self {
-        Solver { input }
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        Ok(Solver {
+            input: input.to_owned(),
+        })
     }
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
+    fn solve_part_one(&self) -> Answer {
         let mut nice_string_counter = 0;
         for s in self.input.lines() {
             let mut vowel_counter = 0;
@@ -39,10 +41,10 @@ impl SolverBase for Solver {
                 nice_string_counter += 1;
             }
         }
-        nice_string_counter.to_string()
+        nice_string_counter.into()
     }
 
-    fn solve_part_two(&self) -> String {
+    fn solve_part_two(&self) -> Answer {
         let mut nice_string_counter = 0;
         for s in self.input.lines() {
             let mut contains_double_pair = false;
@@ -68,7 +70,7 @@ impl SolverBase for Solver {
                 nice_string_counter += 1;
             }
         }
-        nice_string_counter.to_string()
+        nice_string_counter.into()
     }
 
     fn day_number(&self) -> usize {
@@ -86,31 +88,31 @@ mod part1_tests {
 
     #[test]
     fn test_1() {
-        let result = Solver::new("ugknbfddgicrmopn").solve_part_one();
+        let result = Solver::new("ugknbfddgicrmopn").unwrap().solve_part_one();
         assert_eq!(result, "1");
     }
 
     #[test]
     fn test_2() {
-        let result = Solver::new("aaa").solve_part_one();
+        let result = Solver::new("aaa").unwrap().solve_part_one();
         assert_eq!(result, "1");
     }
 
     #[test]
     fn test_3() {
-        let result = Solver::new("jchzalrnumimnmhp").solve_part_one();
+        let result = Solver::new("jchzalrnumimnmhp").unwrap().solve_part_one();
         assert_eq!(result, "0");
     }
 
     #[test]
     fn test_4() {
-        let result = Solver::new("haegwjzuvuyypxyu").solve_part_one();
+        let result = Solver::new("haegwjzuvuyypxyu").unwrap().solve_part_one();
         assert_eq!(result, "0");
     }
 
     #[test]
     fn test_5() {
-        let result = Solver::new("dvszwmarrgswjxmb").solve_part_one();
+        let result = Solver::new("dvszwmarrgswjxmb").unwrap().solve_part_one();
         assert_eq!(result, "0");
     }
 }
@@ -121,31 +123,31 @@ mod part2_tests {
 
     #[test]
     fn test_1() {
-        let result = Solver::new("qjhvhtzxzqqjkmpb").solve_part_two();
+        let result = Solver::new("qjhvhtzxzqqjkmpb").unwrap().solve_part_two();
         assert_eq!(result, "1");
     }
 
     #[test]
     fn test_2() {
-        let result = Solver::new("xxyxx").solve_part_two();
+        let result = Solver::new("xxyxx").unwrap().solve_part_two();
         assert_eq!(result, "1");
     }
 
     #[test]
     fn test_3() {
-        let result = Solver::new("uurcxstgmygtbstg").solve_part_two();
+        let result = Solver::new("uurcxstgmygtbstg").unwrap().solve_part_two();
         assert_eq!(result, "0");
     }
 
     #[test]
     fn test_4() {
-        let result = Solver::new("ieodomkazucvgmuy").solve_part_two();
+        let result = Solver::new("ieodomkazucvgmuy").unwrap().solve_part_two();
         assert_eq!(result, "0");
     }
 
     #[test]
     fn test_5() {
-        let result = Solver::new("aa").solve_part_two();
+        let result = Solver::new("aa").unwrap().solve_part_two();
         assert_eq!(result, "0");
     }
 }
@@ -1,5 +1,5 @@
 #![allow(clippy::needless_range_loop)]
-use solver::SolverBase;
+use solver::{Answer, ParseError, SolverBase, parse_field, parse_lines};
 
 #[derive(Debug, PartialEq, Clone, Copy)]
 enum Action {
@@ -29,54 +29,46 @@ pub struct Solver {
 }
 
 impl Solver {
-    pub fn new(input: &'static str) -> Self {
-        fn parse_command(text: &str) -> Command {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        fn parse_position(x: &str, y: &str) -> Result<Position, ParseError> {
+            Ok(Position {
+                x: parse_field(x)?,
+                y: parse_field(y)?,
+            })
+        }
+
+        fn parse_command(text: &str) -> Result<Command, ParseError> {
             let tokens: Vec<_> = text.split([' ', ',']).collect();
-            match &tokens[..] {
-                ["turn", "on", from_x, from_y, "through", to_x, to_y] => Command {
-                    action: Action::TurnOn,
-                    from: Position {
-                        x: from_x.parse().unwrap(),
-                        y: from_y.parse().unwrap(),
-                    },
-                    to: Position {
-                        x: to_x.parse().unwrap(),
-                        y: to_y.parse().unwrap(),
-                    },
-                },
-                ["turn", "off", from_x, from_y, "through", to_x, to_y] => Command {
-                    action: Action::TurnOff,
-                    from: Position {
-                        x: from_x.parse().unwrap(),
-                        y: from_y.parse().unwrap(),
-                    },
-                    to: Position {
-                        x: to_x.parse().unwrap(),
-                        y: to_y.parse().unwrap(),
-                    },
-                },
-                ["toggle", from_x, from_y, "through", to_x, to_y] => Command {
-                    action: Action::Toggle,
-                    from: Position {
-                        x: from_x.parse().unwrap(),
-                        y: from_y.parse().unwrap(),
-                    },
-                    to: Position {
-                        x: to_x.parse().unwrap(),
-                        y: to_y.parse().unwrap(),
-                    },
-                },
-                _ => panic!("unexpected input"),
-            }
+            let (action, from_x, from_y, to_x, to_y) = match &tokens[..] {
+                ["turn", "on", from_x, from_y, "through", to_x, to_y] => {
+                    (Action::TurnOn, from_x, from_y, to_x, to_y)
+                }
+                ["turn", "off", from_x, from_y, "through", to_x, to_y] => {
+                    (Action::TurnOff, from_x, from_y, to_x, to_y)
+                }
+                ["toggle", from_x, from_y, "through", to_x, to_y] => {
+                    (Action::Toggle, from_x, from_y, to_x, to_y)
+                }
+                _ => {
+                    return Err(ParseError::new(
+                        "expected `turn on`, `turn off` or `toggle` command",
+                    ));
+                }
+            };
+            Ok(Command {
+                action,
+                from: parse_position(from_x, from_y)?,
+                to: parse_position(to_x, to_y)?,
+            })
         }
 
-        let commands = input.lines().map(parse_command).collect();
-        Solver { commands }
+        let commands = parse_lines(input, parse_command)?;
+        Ok(Solver { commands })
     }
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
+    fn solve_part_one(&self) -> Answer {
         let mut grid = vec![vec![false; GRID_WIDTH]; GRID_HEIGHT];
 
         for command in self.commands.iter() {
@@ -116,10 +108,10 @@ impl SolverBase for Solver {
             }
             counter
         }
-        count_lights(&grid).to_string()
+        count_lights(&grid).into()
     }
 
-    fn solve_part_two(&self) -> String {
+    fn solve_part_two(&self) -> Answer {
         let mut grid = vec![vec![0u32; GRID_WIDTH]; GRID_HEIGHT];
 
         for command in self.commands.iter() {
@@ -157,7 +149,7 @@ impl SolverBase for Solver {
             }
             counter as usize
         }
-        count_brightness(&grid).to_string()
+        count_brightness(&grid).into()
     }
 
     fn day_number(&self) -> usize {
@@ -175,19 +167,25 @@ mod part1_tests {
 
     #[test]
     fn test_1() {
-        let result = Solver::new("turn on 0,0 through 999,999").solve_part_one();
+        let result = Solver::new("turn on 0,0 through 999,999")
+            .unwrap()
+            .solve_part_one();
         assert_eq!(result, "1000000");
     }
 
     #[test]
     fn test_2() {
-        let result = Solver::new("toggle 0,0 through 999,0").solve_part_one();
+        let result = Solver::new("toggle 0,0 through 999,0")
+            .unwrap()
+            .solve_part_one();
         assert_eq!(result, "1000");
     }
 
     #[test]
     fn test_3() {
-        let result = Solver::new("turn off 499,499 through 500,500").solve_part_one();
+        let result = Solver::new("turn off 499,499 through 500,500")
+            .unwrap()
+            .solve_part_one();
         assert_eq!(result, "0");
     }
 
@@ -198,8 +196,9 @@ mod part1_tests {
 toggle 0,0 through 999,0
 turn off 499,499 through 500,500",
         )
+        .unwrap()
         .solve_part_one();
-        assert_eq!(result, (1000_000 - 1000 - 4).to_string());
+        assert_eq!(result, (1_000_000 - 1000 - 4).to_string());
     }
 }
 
@@ -209,7 +208,9 @@ mod part2_tests {
 
     #[test]
     fn test_1() {
-        let result = Solver::new("toggle 0,0 through 999,999").solve_part_two();
+        let result = Solver::new("toggle 0,0 through 999,999")
+            .unwrap()
+            .solve_part_two();
         assert_eq!(result, "2000000");
     }
 
@@ -222,6 +223,7 @@ turn on 0,0 through 0,0
 turn on 0,0 through 0,0
 turn on 0,0 through 0,0",
         )
+        .unwrap()
         .solve_part_two();
         assert_eq!(result, "5");
     }
@@ -235,6 +237,7 @@ turn on 0,0 through 0,0
 turn off 0,0 through 0,0
 toggle 0,0 through 0,0",
         )
+        .unwrap()
         .solve_part_two();
         assert_eq!(result, "2");
     }
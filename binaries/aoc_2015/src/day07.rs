@@ -1,15 +1,15 @@
 use std::{cell::RefCell, collections::HashMap};
 
-use solver::SolverBase;
+use solver::{Answer, ParseError, SolverBase, parse_field, parse_lines};
 
 pub struct Solver {
-    instructions: HashMap<&'static str, Instruction>,
-    memo: RefCell<HashMap<&'static str, u16>>,
+    instructions: HashMap<String, Instruction>,
+    memo: RefCell<HashMap<String, u16>>,
 }
 
 #[derive(Debug)]
 enum WireOrValue {
-    Wire(&'static str),
+    Wire(String),
     Value(u16),
 }
 
@@ -24,14 +24,15 @@ enum Instruction {
 }
 
 impl Solver {
-    pub fn new(input: &'static str) -> Self {
-        fn parse_wire_or_value(op: &'static str) -> WireOrValue {
-            op.parse().map_or(WireOrValue::Wire(op), WireOrValue::Value)
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        fn parse_wire_or_value(op: &str) -> WireOrValue {
+            op.parse()
+                .map_or(WireOrValue::Wire(op.to_owned()), WireOrValue::Value)
         }
 
-        fn parse_line(line: &'static str) -> (&'static str, Instruction) {
+        fn parse_line(line: &str) -> Result<(String, Instruction), ParseError> {
             let tokens = line.split_whitespace().collect::<Vec<_>>();
-            match &tokens[..] {
+            let (wire, instruction) = match &tokens[..] {
                 [op, "->", wire] => (wire, Instruction::Forward(parse_wire_or_value(op))),
                 [left, "AND", right, "->", wire] => (
                     wire,
@@ -44,24 +45,21 @@ impl Solver {
                 ["NOT", op, "->", wire] => (wire, Instruction::Not(parse_wire_or_value(op))),
                 [op, "LSHIFT", value, "->", wire] => (
                     wire,
-                    Instruction::Lshift(parse_wire_or_value(op), value.parse().unwrap()),
+                    Instruction::Lshift(parse_wire_or_value(op), parse_field(value)?),
                 ),
                 [op, "RSHIFT", value, "->", wire] => (
                     wire,
-                    Instruction::Rshift(parse_wire_or_value(op), value.parse().unwrap()),
+                    Instruction::Rshift(parse_wire_or_value(op), parse_field(value)?),
                 ),
-                _ => panic!("unrecognized instruction"),
-            }
+                _ => return Err(ParseError::new("expected `<operation> -> <wire>`")),
+            };
+            Ok((wire.to_string(), instruction))
         }
-        let mut instructions: HashMap<&'static str, Instruction> = HashMap::new();
-        for line in input.lines() {
-            let (wire, instruction) = parse_line(line);
-            instructions.insert(wire, instruction);
-        }
-        Solver {
+        let instructions = parse_lines(input, parse_line)?.into_iter().collect();
+        Ok(Solver {
             instructions,
             memo: RefCell::new(HashMap::new()),
-        }
+        })
     }
 
     fn get_value(&self, wire_or_value: &WireOrValue) -> u16 {
@@ -71,7 +69,7 @@ impl Solver {
         }
     }
 
-    fn evaluate(&self, instruction: &Instruction, wire_name: &'static str) -> u16 {
+    fn evaluate(&self, instruction: &Instruction, wire_name: &str) -> u16 {
         if let Some(&value) = self.memo.borrow().get(wire_name) {
             return value;
         }
@@ -83,18 +81,18 @@ impl Solver {
             Instruction::Lshift(op, value) => self.get_value(op) << value,
             Instruction::Rshift(op, value) => self.get_value(op) >> value,
         };
-        self.memo.borrow_mut().insert(wire_name, result);
+        self.memo.borrow_mut().insert(wire_name.to_owned(), result);
         result
     }
 
     #[allow(dead_code)]
     fn evaluate_all(&self) -> String {
-        let mut signals: Vec<(&'static str, u16)> = Vec::new();
+        let mut signals: Vec<(&str, u16)> = Vec::new();
         for (wire, instruction) in self.instructions.iter() {
             let signal = self.evaluate(instruction, wire);
             signals.push((wire, signal));
         }
-        signals.sort_by(|(wire_a, _), (wire_b, _)| wire_a.cmp(wire_b));
+        signals.sort_by_key(|(wire_a, _)| *wire_a);
         println!("{:?}", signals);
         let mut result = Vec::new();
         for (wire, signal) in signals {
@@ -105,21 +103,21 @@ impl Solver {
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
+    fn solve_part_one(&self) -> Answer {
         let a_signal = self.evaluate(self.instructions.get("a").unwrap(), "a");
-        a_signal.to_string()
+        a_signal.into()
     }
 
-    fn solve_part_two(&self) -> String {
+    fn solve_part_two(&self) -> Answer {
         {
             //_ = self.evaluate(self.instructions.get("a").unwrap(), "a");
             let mut memo = self.memo.borrow_mut();
             let a_signal = *memo.get("a").unwrap();
             memo.clear();
-            memo.insert("b", a_signal);
+            memo.insert("b".to_owned(), a_signal);
         }
         let a_signal = self.evaluate(self.instructions.get("a").unwrap(), "a");
-        a_signal.to_string()
+        a_signal.into()
     }
 
     fn day_number(&self) -> usize {
@@ -147,6 +145,7 @@ y RSHIFT 2 -> g
 NOT x -> h
 NOT y -> i",
         )
+        .unwrap()
         .evaluate_all();
         assert_eq!(
             result,
@@ -168,7 +167,7 @@ y: 456"
 
 //     #[test]
 //     fn test_1() {
-//         let result = Solver::new("abc").solve_part_two();
+//         let result = Solver::new("abc").unwrap().solve_part_two();
 //         assert_eq!(result, "0");
 //     }
 // }
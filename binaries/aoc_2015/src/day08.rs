@@ -1,15 +1,17 @@
-use solver::SolverBase;
+use solver::{Answer, ParseError, SolverBase};
 
 pub struct Solver {
-    input: &'static str,
+    input: String,
 }
 
 impl Solver {
-    pub fn new(input: &'static str) -> Self {
-        Solver { input }
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        Ok(Solver {
+            input: input.to_owned(),
+        })
     }
 
-    pub fn encode(text: &'static str) -> String {
+    pub fn encode(text: &str) -> String {
         let mut encoded_text = String::new();
         encoded_text.push('\"');
         for c in text.chars() {
@@ -25,7 +27,7 @@ impl Solver {
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
+    fn solve_part_one(&self) -> Answer {
         let mut code_char_count = 0;
         let mut memory_char_count = 0;
         for line in self.input.lines() {
@@ -56,10 +58,10 @@ impl SolverBase for Solver {
                 i += 1;
             }
         }
-        (code_char_count - memory_char_count).to_string()
+        (code_char_count - memory_char_count).into()
     }
 
-    fn solve_part_two(&self) -> String {
+    fn solve_part_two(&self) -> Answer {
         let mut old_char_count = 0;
         let mut new_char_count = 0;
         for line in self.input.lines() {
@@ -72,7 +74,7 @@ impl SolverBase for Solver {
             let length = bytes.len();
             new_char_count += length;
         }
-        (new_char_count - old_char_count).to_string()
+        (new_char_count - old_char_count).into()
     }
 
     fn day_number(&self) -> usize {
@@ -94,6 +96,7 @@ mod part1_tests {
             r#"""
 "#,
         )
+        .unwrap()
         .solve_part_one();
         assert_eq!(result, "2");
     }
@@ -104,6 +107,7 @@ mod part1_tests {
             r#""abc"
 "#,
         )
+        .unwrap()
         .solve_part_one();
         assert_eq!(result, "2");
     }
@@ -114,6 +118,7 @@ mod part1_tests {
             r#""aaa\"aaa"
 "#,
         )
+        .unwrap()
         .solve_part_one();
         assert_eq!(result, "3");
     }
@@ -124,6 +129,7 @@ mod part1_tests {
             r#""\x27"
 "#,
         )
+        .unwrap()
         .solve_part_one();
         assert_eq!(result, "5");
     }
@@ -137,6 +143,7 @@ mod part1_tests {
 "\x27"
 "#,
         )
+        .unwrap()
         .solve_part_one();
         assert_eq!(result, "12");
     }
@@ -1,30 +1,35 @@
 use itertools::Itertools;
 use std::collections::HashMap;
 
-use solver::SolverBase;
+use solver::{Answer, ParseError, SolverBase, parse_field, parse_lines};
 
 pub struct Solver {
-    pub graph: HashMap<&'static str, HashMap<&'static str, i32>>,
+    pub graph: HashMap<String, HashMap<String, i32>>,
 }
 
 impl Solver {
-    pub fn new(input: &'static str) -> Self {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
         let mut graph = HashMap::new();
-        for line in input.lines() {
+        let routes = parse_lines(input, |line| {
             let tokens = line.split_whitespace().collect::<Vec<_>>();
-            if let [city_from, "to", city_to, "=", distance] = &tokens[..] {
-                let distance = distance.parse().unwrap();
-                graph
-                    .entry(*city_from)
-                    .or_insert(HashMap::new())
-                    .insert(*city_to, distance);
-                graph
-                    .entry(*city_to)
-                    .or_insert(HashMap::new())
-                    .insert(*city_from, distance);
+            match &tokens[..] {
+                [city_from, "to", city_to, "=", distance] => {
+                    Ok((*city_from, *city_to, parse_field(distance)?))
+                }
+                _ => Err(ParseError::new("expected `<city> to <city> = <distance>`")),
             }
+        })?;
+        for (city_from, city_to, distance) in routes {
+            graph
+                .entry(city_from.to_string())
+                .or_insert(HashMap::new())
+                .insert(city_to.to_string(), distance);
+            graph
+                .entry(city_to.to_string())
+                .or_insert(HashMap::new())
+                .insert(city_from.to_string(), distance);
         }
-        Solver { graph }
+        Ok(Solver { graph })
     }
 
     pub fn get_all_distances(&self, return_to_start: bool) -> Vec<i32> {
@@ -34,7 +39,7 @@ impl Solver {
                 permutation.push(permutation[0]);
             }
             let mut total_distance = 0;
-            for (&&city_from, &&city_to) in permutation.iter().tuple_windows() {
+            for (&city_from, &city_to) in permutation.iter().tuple_windows() {
                 let distance = self.graph.get(city_from).unwrap().get(city_to).unwrap();
                 total_distance += distance;
             }
@@ -45,16 +50,16 @@ impl Solver {
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
+    fn solve_part_one(&self) -> Answer {
         let distances = self.get_all_distances(false);
         let shortest_distance = *distances.iter().min().unwrap();
-        shortest_distance.to_string()
+        shortest_distance.into()
     }
 
-    fn solve_part_two(&self) -> String {
+    fn solve_part_two(&self) -> Answer {
         let distances = self.get_all_distances(false);
         let longest_distance = *distances.iter().max().unwrap();
-        longest_distance.to_string()
+        longest_distance.into()
     }
 
     fn day_number(&self) -> usize {
@@ -77,6 +82,7 @@ mod part1_tests {
 London to Belfast = 518
 Dublin to Belfast = 141",
         )
+        .unwrap()
         .solve_part_one();
         assert_eq!(result, "605");
     }
@@ -93,6 +99,7 @@ mod part2_tests {
 London to Belfast = 518
 Dublin to Belfast = 141",
         )
+        .unwrap()
         .solve_part_two();
         assert_eq!(result, "982");
     }
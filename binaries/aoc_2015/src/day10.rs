@@ -1,18 +1,22 @@
-use solver::SolverBase;
+use solver::{Answer, ParseError, SolverBase};
 
 pub struct Solver {
-    input: &'static str,
+    input: String,
     repeat_count_part1: usize,
     repeat_count_part2: usize,
 }
 
 impl Solver {
-    pub fn new(input: &'static str, repeat_count_part1: usize, repeat_count_part2: usize) -> Self {
-        Solver {
-            input,
+    pub fn new(
+        input: &str,
+        repeat_count_part1: usize,
+        repeat_count_part2: usize,
+    ) -> Result<Self, ParseError> {
+        Ok(Solver {
+            input: input.to_owned(),
             repeat_count_part1,
             repeat_count_part2,
-        }
+        })
     }
 
     fn look_and_say(text: &str) -> String {
@@ -39,14 +43,14 @@ impl Solver {
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
-        let result = Solver::look_and_say_repeat(self.input, self.repeat_count_part1);
-        result.len().to_string()
+    fn solve_part_one(&self) -> Answer {
+        let result = Solver::look_and_say_repeat(&self.input, self.repeat_count_part1);
+        result.len().into()
     }
 
-    fn solve_part_two(&self) -> String {
-        let result = Solver::look_and_say_repeat(self.input, self.repeat_count_part2);
-        result.len().to_string()
+    fn solve_part_two(&self) -> Answer {
+        let result = Solver::look_and_say_repeat(&self.input, self.repeat_count_part2);
+        result.len().into()
     }
 
     fn day_number(&self) -> usize {
@@ -100,7 +104,7 @@ mod part1_tests {
 
     #[test]
     fn test_7() {
-        let result = Solver::new("1", 5, 0).solve_part_one();
+        let result = Solver::new("1", 5, 0).unwrap().solve_part_one();
         assert_eq!(result, "6");
     }
 }
@@ -111,7 +115,7 @@ mod part1_tests {
 
 //     #[test]
 //     fn test_1() {
-//         let result = Solver::new("abc").solve_part_two();
+//         let result = Solver::new("abc").unwrap().solve_part_two();
 //         assert_eq!(result, "0");
 //     }
 // }
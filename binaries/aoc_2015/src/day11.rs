@@ -1,17 +1,17 @@
 use std::collections::HashSet;
 
 use itertools::Itertools;
-use solver::SolverBase;
+use solver::{Answer, ParseError, SolverBase};
 
 pub struct Solver {
-    current_password: &'static str,
+    current_password: String,
 }
 
 impl Solver {
-    pub fn new(input: &'static str) -> Self {
-        Solver {
-            current_password: input,
-        }
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        Ok(Solver {
+            current_password: input.to_owned(),
+        })
     }
 
     fn is_valid_password(text: &str) -> bool {
@@ -70,12 +70,13 @@ impl Solver {
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
-        Solver::generate_next_password(self.current_password)
+    fn solve_part_one(&self) -> Answer {
+        Solver::generate_next_password(&self.current_password).into()
     }
 
-    fn solve_part_two(&self) -> String {
-        Solver::generate_next_password(&Solver::generate_next_password(self.current_password))
+    fn solve_part_two(&self) -> Answer {
+        Solver::generate_next_password(&Solver::generate_next_password(&self.current_password))
+            .into()
     }
 
     fn day_number(&self) -> usize {
@@ -93,56 +94,56 @@ mod part1_tests {
 
     #[test]
     fn test_1() {
-        let result = Solver::new("abcdefgh").solve_part_one();
+        let result = Solver::new("abcdefgh").unwrap().solve_part_one();
         assert_eq!(result, "abcdffaa");
     }
 
     #[test]
     fn test_2() {
-        let result = Solver::new("ghijklmn").solve_part_one();
+        let result = Solver::new("ghijklmn").unwrap().solve_part_one();
         assert_eq!(result, "ghjaabcc");
     }
 
     #[test]
     fn test_3() {
         let result = Solver::is_valid_password("hijklmmn");
-        assert_eq!(result, false);
+        assert!(!result);
     }
 
     #[test]
     fn test_4() {
         let result = Solver::is_valid_password("abbceffg");
-        assert_eq!(result, false);
+        assert!(!result);
     }
 
     #[test]
     fn test_5() {
         let result = Solver::is_valid_password("abbcegjk");
-        assert_eq!(result, false);
+        assert!(!result);
     }
 
     #[test]
     fn test_6() {
         let result = Solver::is_valid_password("abcdefgh");
-        assert_eq!(result, false);
+        assert!(!result);
     }
 
     #[test]
     fn test_7() {
         let result = Solver::is_valid_password("abcdffaa");
-        assert_eq!(result, true);
+        assert!(result);
     }
 
     #[test]
     fn test_8() {
         let result = Solver::is_valid_password("ghijklmn");
-        assert_eq!(result, false);
+        assert!(!result);
     }
 
     #[test]
     fn test_9() {
         let result = Solver::is_valid_password("ghjaabcc");
-        assert_eq!(result, true);
+        assert!(result);
     }
 
     #[test]
@@ -169,7 +170,7 @@ mod part1_tests {
 
 //     #[test]
 //     fn test_1() {
-//         let result = Solver::new("abc").solve_part_two();
+//         let result = Solver::new("abc").unwrap().solve_part_two();
 //         assert_eq!(result, "0");
 //     }
 // }
@@ -1,18 +1,18 @@
 use itertools::Itertools;
-use solver::SolverBase;
+use solver::{Answer, ParseError, SolverBase, parse_field, parse_lines};
 use std::collections::HashMap;
 
 pub struct Solver {
-    graph: HashMap<&'static str, HashMap<&'static str, i32>>,
+    graph: HashMap<String, HashMap<String, i32>>,
 }
 
 impl Solver {
-    pub fn new(input: &'static str) -> Self {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
         let mut graph = HashMap::new();
-        for line in input.lines() {
+        let preferences = parse_lines(input, |line| {
             let tokens = line.split([' ', '.']).collect::<Vec<_>>();
             // Alice would lose 57 happiness units by sitting next to Bob.
-            if let [
+            let [
                 guest_name,
                 "would",
                 gain_or_lose,
@@ -26,32 +26,38 @@ impl Solver {
                 neighbor_name,
                 "",
             ] = &tokens[..]
-            {
-                let hapiness_units: i32 = hapiness_units.parse().unwrap();
-                let hapiness_units = match *gain_or_lose {
-                    "gain" => hapiness_units,
-                    "lose" => -hapiness_units,
-                    _ => panic!("what?"),
-                };
-                graph
-                    .entry(*guest_name)
-                    .or_insert(HashMap::new())
-                    .insert(*neighbor_name, hapiness_units);
-            }
+            else {
+                return Err(ParseError::new(
+                    "expected `<name> would <gain|lose> <units> happiness units by sitting next to <name>.`",
+                ));
+            };
+            let hapiness_units: i32 = parse_field(hapiness_units)?;
+            let hapiness_units = match *gain_or_lose {
+                "gain" => hapiness_units,
+                "lose" => -hapiness_units,
+                _ => return Err(ParseError::expected("`gain` or `lose`", gain_or_lose)),
+            };
+            Ok((*guest_name, *neighbor_name, hapiness_units))
+        })?;
+        for (guest_name, neighbor_name, hapiness_units) in preferences {
+            graph
+                .entry(guest_name.to_string())
+                .or_insert(HashMap::new())
+                .insert(neighbor_name.to_string(), hapiness_units);
         }
-        Solver { graph }
+        Ok(Solver { graph })
     }
 
     fn get_max_happiness_seating(
-        graph: &HashMap<&'static str, HashMap<&'static str, i32>>,
-    ) -> (i32, Vec<&'static str>, Vec<i32>) {
+        graph: &HashMap<String, HashMap<String, i32>>,
+    ) -> (i32, Vec<&String>, Vec<i32>) {
         let mut result = Vec::new();
         for mut permutation in graph.keys().permutations(graph.len()) {
             permutation.push(permutation[0]);
             let mut sum_happiness = 0;
             let mut names = Vec::new();
             let mut happiness_values = Vec::new();
-            for (&&name_from, &&name_to) in permutation.iter().tuple_windows() {
+            for (&name_from, &name_to) in permutation.iter().tuple_windows() {
                 let happiness_factor_from = graph.get(name_from).unwrap().get(name_to).unwrap();
                 let happiness_factor_to = graph.get(name_to).unwrap().get(name_from).unwrap();
                 sum_happiness += happiness_factor_from;
@@ -71,23 +77,29 @@ impl Solver {
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
+    fn solve_part_one(&self) -> Answer {
         let (sum_hapiness, _names, _happiness_values) =
             Solver::get_max_happiness_seating(&self.graph);
         //println!("optimal seating: {sum_hapiness}, {names:?}, {happiness_values:?}");
-        sum_hapiness.to_string()
+        sum_hapiness.into()
     }
 
-    fn solve_part_two(&self) -> String {
+    fn solve_part_two(&self) -> Answer {
         let me = "me";
         let mut graph = self.graph.clone();
-        for &name in self.graph.keys() {
-            graph.entry(name).or_default().insert(me, 0);
-            graph.entry(me).or_default().insert(name, 0);
+        for name in self.graph.keys() {
+            graph
+                .entry(name.clone())
+                .or_default()
+                .insert(me.to_string(), 0);
+            graph
+                .entry(me.to_string())
+                .or_default()
+                .insert(name.clone(), 0);
         }
         let (sum_hapiness, _names, _happiness_values) = Solver::get_max_happiness_seating(&graph);
         //println!("optimal seating: {sum_hapiness}, {names:?}, {happiness_values:?}");
-        sum_hapiness.to_string()
+        sum_hapiness.into()
     }
 
     fn day_number(&self) -> usize {
@@ -119,6 +131,7 @@ David would gain 46 happiness units by sitting next to Alice.
 David would lose 7 happiness units by sitting next to Bob.
 David would gain 41 happiness units by sitting next to Carol.",
         )
+        .unwrap()
         .solve_part_one();
         assert_eq!(result, "330");
     }
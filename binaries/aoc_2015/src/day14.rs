@@ -1,4 +1,4 @@
-use solver::SolverBase;
+use solver::{Answer, ParseError, SolverBase, parse_field, parse_lines};
 
 struct Reindeer {
     speed: u32,     // km/s
@@ -18,11 +18,10 @@ pub struct Solver {
 }
 
 impl Solver {
-    pub fn new(input: &'static str, time: u32) -> Self {
-        let mut reindeers = Vec::new();
-        for line in input.lines() {
+    pub fn new(input: &str, time: u32) -> Result<Self, ParseError> {
+        let reindeers = parse_lines(input, |line| {
             let tokens: Vec<_> = line.split_whitespace().collect();
-            if let [
+            let [
                 _name,
                 "can",
                 "fly",
@@ -39,15 +38,18 @@ impl Solver {
                 rest_time,
                 "seconds.",
             ] = &tokens[..]
-            {
-                reindeers.push(Reindeer {
-                    speed: speed.parse().unwrap(),
-                    fly_time: fly_time.parse().unwrap(),
-                    rest_time: rest_time.parse().unwrap(),
-                });
-            }
-        }
-        Solver { reindeers, time }
+            else {
+                return Err(ParseError::new(
+                    "expected `<name> can fly <speed> km/s for <time> seconds, but then must rest for <time> seconds.`",
+                ));
+            };
+            Ok(Reindeer {
+                speed: parse_field(speed)?,
+                fly_time: parse_field(fly_time)?,
+                rest_time: parse_field(rest_time)?,
+            })
+        })?;
+        Ok(Solver { reindeers, time })
     }
 
     fn simulate_flying(&self, reindeer: &Reindeer) -> u32 {
@@ -112,19 +114,19 @@ impl Solver {
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
+    fn solve_part_one(&self) -> Answer {
         let distances: Vec<u32> = self
             .reindeers
             .iter()
             .map(|reindeer| self.simulate_flying(reindeer))
             .collect();
         let max = distances.iter().max().unwrap();
-        max.to_string()
+        (*max).into()
     }
 
-    fn solve_part_two(&self) -> String {
+    fn solve_part_two(&self) -> Answer {
         let points = self.simulate_flying_with_bonus_points();
-        points.to_string()
+        points.into()
     }
 
     fn day_number(&self) -> usize {
@@ -147,6 +149,7 @@ mod part1_tests {
 Dancer can fly 16 km/s for 11 seconds, but then must rest for 162 seconds.",
             1000,
         )
+        .unwrap()
         .solve_part_one();
         assert_eq!(result, "1120");
     }
@@ -163,6 +166,7 @@ mod part2_tests {
 Dancer can fly 16 km/s for 11 seconds, but then must rest for 162 seconds.",
             1000,
         )
+        .unwrap()
         .solve_part_two();
         assert_eq!(result, "689");
     }
@@ -1,9 +1,9 @@
 use itertools::Itertools;
-use solver::SolverBase;
+use solver::{Answer, Model, ParseError, SolverBase, parse_field, parse_lines};
 
 #[derive(Debug, Clone)]
 struct Ingredient {
-    _name: &'static str,
+    _name: String,
     capacity: i64,
     durability: i64,
     flavor: i64,
@@ -16,12 +16,11 @@ pub struct Solver {
 }
 
 impl Solver {
-    pub fn new(input: &'static str) -> Self {
-        let mut ingredients = Vec::new();
-        for line in input.lines() {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let ingredients = parse_lines(input, |line| {
             let tokens: Vec<_> = line.split([':', ' ', ',']).collect();
             // Sprinkles: capacity 5, durability -1, flavor 0, texture 0, calories 5
-            if let [
+            let [
                 _name,
                 "",
                 "capacity",
@@ -39,18 +38,21 @@ impl Solver {
                 "calories",
                 calories,
             ] = &tokens[..]
-            {
-                ingredients.push(Ingredient {
-                    _name,
-                    capacity: capacity.parse().unwrap(),
-                    durability: durability.parse().unwrap(),
-                    flavor: flavor.parse().unwrap(),
-                    texture: texture.parse().unwrap(),
-                    calories: calories.parse().unwrap(),
-                });
-            }
-        }
-        Solver { ingredients }
+            else {
+                return Err(ParseError::new(
+                    "expected `<name>: capacity <n>, durability <n>, flavor <n>, texture <n>, calories <n>`",
+                ));
+            };
+            Ok(Ingredient {
+                _name: _name.to_string(),
+                capacity: parse_field(capacity)?,
+                durability: parse_field(durability)?,
+                flavor: parse_field(flavor)?,
+                texture: parse_field(texture)?,
+                calories: parse_field(calories)?,
+            })
+        })?;
+        Ok(Solver { ingredients })
     }
 
     fn score_function(items: &[(Ingredient, usize)], required_calories: Option<i64>) -> i64 {
@@ -67,10 +69,10 @@ impl Solver {
             calories_sum += ingredient.calories * *teaspoons as i64;
         }
 
-        if let Some(required_calories) = required_calories {
-            if calories_sum != required_calories {
-                return 0;
-            }
+        if let Some(required_calories) = required_calories
+            && calories_sum != required_calories
+        {
+            return 0;
         }
 
         capacity_sum.max(0) * durability_sum.max(0) * flavor_sum.max(0) * texture_sum.max(0)
@@ -122,19 +124,70 @@ impl Solver {
 
         (max_score, best_combination)
     }
+
+    /// Every split of the 100 teaspoons which adds up to `calories`, enumerated with a SAT model
+    fn allocations_with_calories(&self, calories: u64) -> Vec<Vec<usize>> {
+        let mut model = Model::new();
+        let teaspoons: Vec<_> = self
+            .ingredients
+            .iter()
+            .map(|_| {
+                let teaspoons = model.int(7);
+                model.assert_at_most(&teaspoons, 100);
+                teaspoons
+            })
+            .collect();
+        let total = model.sum(&teaspoons);
+        model.assert_equal_to(&total, 100);
+        let ingredient_calories: Vec<_> = self
+            .ingredients
+            .iter()
+            .zip(&teaspoons)
+            .map(|(ingredient, teaspoons)| {
+                let per_teaspoon =
+                    u64::try_from(ingredient.calories).expect("calories are not negative");
+                model.scale(teaspoons, per_teaspoon)
+            })
+            .collect();
+        let total_calories = model.sum(&ingredient_calories);
+        model.assert_equal_to(&total_calories, calories);
+
+        let projection: Vec<_> = teaspoons
+            .iter()
+            .flat_map(|teaspoons| teaspoons.bits().to_vec())
+            .collect();
+        model
+            .solutions(&projection)
+            .map(|solution| {
+                teaspoons
+                    .iter()
+                    .map(|teaspoons| solution.int(teaspoons) as usize)
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn maximize_score_with_calories(&self, calories: u64) -> i64 {
+        self.allocations_with_calories(calories)
+            .into_iter()
+            .map(|allocation| {
+                let items: Vec<_> = self.ingredients.iter().cloned().zip(allocation).collect();
+                Solver::score_function(&items, None)
+            })
+            .max()
+            .unwrap_or(0)
+    }
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
+    fn solve_part_one(&self) -> Answer {
         let (max_score, _best_combination) = self.maximize_score(None);
         //println!("{max_score:?}, {best_combination:?}");
-        max_score.to_string()
+        max_score.into()
     }
 
-    fn solve_part_two(&self) -> String {
-        let (max_score, _best_combination) = self.maximize_score(Some(500));
-        //println!("{max_score:?}, {best_combination:?}");
-        max_score.to_string()
+    fn solve_part_two(&self) -> Answer {
+        self.maximize_score_with_calories(500).into()
     }
 
     fn day_number(&self) -> usize {
@@ -156,6 +209,7 @@ mod part1_tests {
             r"Butterscotch: capacity -1, durability -2, flavor 6, texture 3, calories 8
 Cinnamon: capacity 2, durability 3, flavor -2, texture -1, calories 3",
         )
+        .unwrap()
         .solve_part_one();
         assert_eq!(result, "62842880");
     }
@@ -171,7 +225,24 @@ mod part2_tests {
             r"Butterscotch: capacity -1, durability -2, flavor 6, texture 3, calories 8
 Cinnamon: capacity 2, durability 3, flavor -2, texture -1, calories 3",
         )
+        .unwrap()
         .solve_part_two();
         assert_eq!(result, "57600000");
     }
+
+    #[test]
+    fn test_sat_matches_search() {
+        let solver = Solver::new(
+            r"Butterscotch: capacity -1, durability -2, flavor 6, texture 3, calories 8
+Cinnamon: capacity 2, durability 3, flavor -2, texture -1, calories 3",
+        )
+        .unwrap();
+        assert_eq!(solver.allocations_with_calories(500), [vec![40, 60]]);
+        for calories in [300, 420, 500, 800] {
+            assert_eq!(
+                solver.maximize_score_with_calories(calories),
+                solver.maximize_score(Some(calories as i64)).0
+            );
+        }
+    }
 }
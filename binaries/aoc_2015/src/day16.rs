@@ -1,11 +1,11 @@
 use std::collections::HashMap;
 
-use solver::SolverBase;
+use solver::{Answer, ParseError, SolverBase, parse_field, parse_lines};
 
 #[derive(Debug)]
 struct Sue {
     number: usize,
-    things: HashMap<&'static str, u8>,
+    things: HashMap<String, u8>,
 }
 
 pub struct Solver {
@@ -13,25 +13,32 @@ pub struct Solver {
 }
 
 impl Solver {
-    pub fn new(input: &'static str) -> Self {
-        let mut sues = Vec::new();
-        for line in input.lines() {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let sues = parse_lines(input, |line| {
             // Sue 1: goldfish: 6, trees: 9, akitas: 0
             let tokens: Vec<_> = line
                 .split([' ', ':', ','])
                 .filter(|x| !x.is_empty())
                 .collect();
-            assert!(tokens[0] == "Sue");
-            let number: usize = tokens[1].parse().unwrap();
-            let mut things = HashMap::new();
-            for i in (2..tokens.len()).step_by(2) {
-                let thing = tokens[i];
-                let value = tokens[i + 1].parse().unwrap();
-                things.insert(thing, value);
+            let ["Sue", number, things @ ..] = &tokens[..] else {
+                return Err(ParseError::new(
+                    "expected `Sue <number>: <thing>: <count>, ...`",
+                ));
+            };
+            let number: usize = parse_field(number)?;
+            let mut sue_things = HashMap::new();
+            for pair in things.chunks(2) {
+                let [thing, value] = pair else {
+                    return Err(ParseError::expected("`<thing>: <count>`", pair[0]));
+                };
+                sue_things.insert(thing.to_string(), parse_field(value)?);
             }
-            sues.push(Sue { number, things });
-        }
-        Solver { sues }
+            Ok(Sue {
+                number,
+                things: sue_things,
+            })
+        })?;
+        Ok(Solver { sues })
     }
 
     fn find_best_fit(
@@ -40,13 +47,13 @@ impl Solver {
         compensate_for_outdated_retroencabulator: bool,
     ) -> Vec<&Sue> {
         fn get_similarity_index(
-            a: &HashMap<&'static str, u8>,
+            a: &HashMap<String, u8>,
             b: &HashMap<&'static str, u8>,
             compensate_for_outdated_retroencabulator: bool,
         ) -> i32 {
             let mut total_diff = 0;
             for (name, value) in a {
-                let test_value = b.get(name).unwrap();
+                let test_value = b.get(name.as_str()).unwrap();
                 let mut diff = value.abs_diff(*test_value);
                 if compensate_for_outdated_retroencabulator {
                     if *name == "cats" || *name == "trees" {
@@ -111,20 +118,20 @@ impl Solver {
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
+    fn solve_part_one(&self) -> Answer {
         let detector_result = Solver::get_detector_result();
         let best_fit_sues = self.find_best_fit(&detector_result, false);
         //println!("{best_fit_sues:?}");
         assert!(best_fit_sues.len() == 1);
-        best_fit_sues[0].number.to_string()
+        best_fit_sues[0].number.into()
     }
 
-    fn solve_part_two(&self) -> String {
+    fn solve_part_two(&self) -> Answer {
         let detector_result = Solver::get_detector_result();
         let best_fit_sues = self.find_best_fit(&detector_result, true);
         //println!("{best_fit_sues:?}");
         assert!(best_fit_sues.len() == 1);
-        best_fit_sues[0].number.to_string()
+        best_fit_sues[0].number.into()
     }
 
     fn day_number(&self) -> usize {
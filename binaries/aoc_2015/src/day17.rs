@@ -1,4 +1,4 @@
-use solver::SolverBase;
+use solver::{Answer, ParseError, SolverBase, parse_field, parse_lines};
 
 pub struct Solver {
     containers: Vec<i32>,
@@ -6,12 +6,12 @@ pub struct Solver {
 }
 
 impl Solver {
-    pub fn new(input: &'static str, volume: i32) -> Self {
-        let buckets: Vec<_> = input.lines().map(|line| line.parse().unwrap()).collect();
-        Solver {
+    pub fn new(input: &str, volume: i32) -> Result<Self, ParseError> {
+        let buckets = parse_lines(input, parse_field)?;
+        Ok(Solver {
             containers: buckets,
             volume,
-        }
+        })
     }
 
     fn count_combinations(
@@ -28,10 +28,10 @@ impl Solver {
             container_count_limit: Option<usize>,
             min_countainer_count: &mut usize,
         ) -> i32 {
-            if let Some(container_count_limit) = container_count_limit {
-                if container_count > container_count_limit {
-                    return 0;
-                }
+            if let Some(container_count_limit) = container_count_limit
+                && container_count > container_count_limit
+            {
+                return 0;
             }
             // Base case: current_sum equals target
             if current_sum == target {
@@ -83,12 +83,12 @@ impl Solver {
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
+    fn solve_part_one(&self) -> Answer {
         let (count, _) = Solver::count_combinations(self.containers.clone(), self.volume, None);
-        count.to_string()
+        count.into()
     }
 
-    fn solve_part_two(&self) -> String {
+    fn solve_part_two(&self) -> Answer {
         let (_, min_container_count) =
             Solver::count_combinations(self.containers.clone(), self.volume, None);
         let (count, _) = Solver::count_combinations(
@@ -96,7 +96,7 @@ impl SolverBase for Solver {
             self.volume,
             Some(min_container_count),
         );
-        count.to_string()
+        count.into()
     }
 
     fn day_number(&self) -> usize {
@@ -122,6 +122,7 @@ mod part1_tests {
 5",
             25,
         )
+        .unwrap()
         .solve_part_one();
         assert_eq!(result, "4");
     }
@@ -141,6 +142,7 @@ mod part2_tests {
 5",
             25,
         )
+        .unwrap()
         .solve_part_two();
         assert_eq!(result, "3");
     }
@@ -1,102 +1,56 @@
-use solver::SolverBase;
+use solver::{Answer, Grid, ParseError, SolverBase, fast_forward, parse_char_map};
 
 pub struct Solver {
-    lights: Vec<Vec<bool>>,
+    lights: Grid<bool>,
     steps: usize,
 }
 
 impl Solver {
-    pub fn new(input: &'static str, steps: usize) -> Self {
-        let lights = input
-            .lines()
-            .map(|line| line.chars().map(|c| c == '#').collect())
-            .collect();
-        Solver { lights, steps }
+    pub fn new(input: &str, steps: usize) -> Result<Self, ParseError> {
+        let lights = parse_char_map(input, &[('#', true), ('.', false)])?;
+        Ok(Solver { lights, steps })
     }
 
-    fn move_to_next_state(current_state: &[Vec<bool>]) -> Vec<Vec<bool>> {
-        let mut new_state = vec![vec![false; current_state[0].len()]; current_state.len()];
-        for r in 0..current_state.len() {
-            for c in 0..current_state[0].len() {
-                let count = Solver::get_count_of_turned_on_neighbors(current_state, r, c);
-                // A light which is on stays on when 2 or 3 neighbors are on, and turns off otherwise.
-                if current_state[r][c] {
-                    new_state[r][c] = count == 2 || count == 3;
-                }
-                // A light which is off turns on if exactly 3 neighbors are on, and stays off otherwise.
-                else {
-                    new_state[r][c] = count == 3;
-                }
+    fn move_to_next_state(current_state: &Grid<bool>) -> Grid<bool> {
+        let mut new_state = Grid::new(current_state.width(), current_state.height(), false);
+        for position in current_state.positions() {
+            let count = Solver::get_count_of_turned_on_neighbors(current_state, position);
+            // A light which is on stays on when 2 or 3 neighbors are on, and turns off otherwise.
+            if current_state[position] {
+                new_state[position] = count == 2 || count == 3;
+            }
+            // A light which is off turns on if exactly 3 neighbors are on, and stays off otherwise.
+            else {
+                new_state[position] = count == 3;
             }
         }
         new_state
     }
 
-    fn turn_corners_on(state: &mut [Vec<bool>]) {
-        let rows = state.len();
-        let columns = state[0].len();
-        state[0][0] = true;
-        state[0][columns - 1] = true;
-        state[rows - 1][0] = true;
-        state[rows - 1][columns - 1] = true;
+    fn turn_corners_on(state: &mut Grid<bool>) {
+        let rows = state.height();
+        let columns = state.width();
+        state[(0, 0)] = true;
+        state[(0, columns - 1)] = true;
+        state[(rows - 1, 0)] = true;
+        state[(rows - 1, columns - 1)] = true;
     }
 
-    fn get_count_of_turned_on_neighbors(lights: &[Vec<bool>], row: usize, column: usize) -> usize {
-        let rows = lights.len();
-        let columns = lights[0].len();
-        let mut neighbors = 0;
-        let mut add_neighbor = |r: usize, c: usize| {
-            if lights[r][c] {
-                neighbors += 1;
-            }
-        };
-        if row > 0 && column > 0 {
-            add_neighbor(row - 1, column - 1);
-        }
-        if row > 0 {
-            add_neighbor(row - 1, column);
-        }
-        if row > 0 && column < columns - 1 {
-            add_neighbor(row - 1, column + 1);
-        }
-        if column < columns - 1 {
-            add_neighbor(row, column + 1);
-        }
-        if row < rows - 1 && column < columns - 1 {
-            add_neighbor(row + 1, column + 1);
-        }
-        if row < rows - 1 {
-            add_neighbor(row + 1, column);
-        }
-        if row < rows - 1 && column > 0 {
-            add_neighbor(row + 1, column - 1);
-        }
-        if column > 0 {
-            add_neighbor(row, column - 1);
-        }
-        neighbors
+    fn get_count_of_turned_on_neighbors(lights: &Grid<bool>, position: (usize, usize)) -> usize {
+        lights
+            .neighbors8(position)
+            .filter(|&neighbor| lights[neighbor])
+            .count()
     }
 
     #[allow(dead_code)]
-    fn print_lights(lights: &[Vec<bool>]) {
-        for row in lights.iter() {
-            for light in row {
-                if *light {
-                    print!("#");
-                } else {
-                    print!(".");
-                }
-            }
-            println!();
-        }
+    fn print_lights(lights: &Grid<bool>) {
+        println!("{}", lights.map(|&light| if light { '#' } else { '.' }));
         println!();
     }
 
-    fn get_count_oflights(lights: &[Vec<bool>]) -> usize {
-        lights
-            .iter()
-            .map(|line| line.iter().filter(|light| **light).count())
-            .sum()
+    fn get_count_oflights(lights: &Grid<bool>) -> usize {
+        lights.count(|light| *light)
     }
 
     fn animate(&self, corners_are_stuck_on: bool) -> usize {
@@ -104,28 +58,31 @@ impl Solver {
         if corners_are_stuck_on {
             Solver::turn_corners_on(&mut lights);
         }
-        //Solver::print_lights(&lights);
-        for _i in 0..self.steps {
-            //println!("step {}:", i + 1);
-            lights = Solver::move_to_next_state(&lights);
-            if corners_are_stuck_on {
-                Solver::turn_corners_on(&mut lights);
-            }
-            //Solver::print_lights(&lights);
-        }
+        // Skips ahead when the lights go back to an earlier state, e.g. when they all end up off
+        let lights = fast_forward(
+            lights,
+            |lights| {
+                let mut lights = Solver::move_to_next_state(lights);
+                if corners_are_stuck_on {
+                    Solver::turn_corners_on(&mut lights);
+                }
+                lights
+            },
+            self.steps,
+        );
         Solver::get_count_oflights(&lights)
     }
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
+    fn solve_part_one(&self) -> Answer {
         let count = self.animate(false);
-        count.to_string()
+        count.into()
     }
 
-    fn solve_part_two(&self) -> String {
+    fn solve_part_two(&self) -> Answer {
         let count = self.animate(true);
-        count.to_string()
+        count.into()
     }
 
     fn day_number(&self) -> usize {
@@ -152,6 +109,7 @@ mod part1_tests {
 ####..",
             4,
         )
+        .unwrap()
         .solve_part_one();
         assert_eq!(result, "4");
     }
@@ -172,6 +130,7 @@ mod part2_tests {
 ####..",
             5,
         )
+        .unwrap()
         .solve_part_two();
         assert_eq!(result, "17");
     }
@@ -1,27 +1,35 @@
-use solver::SolverBase;
+use solver::{Answer, ParseError, SolverBase};
 use std::collections::{HashMap, HashSet};
 
 pub struct Solver {
-    rewrite_rules: HashMap<&'static str, Vec<&'static str>>,
-    molecule: &'static str,
+    rewrite_rules: HashMap<String, Vec<String>>,
+    molecule: String,
 }
 
 impl Solver {
-    pub fn new(input: &'static str) -> Self {
-        let mut rewrite_rules: HashMap<&str, Vec<&str>> = HashMap::new();
-        let mut molecule = "";
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let mut rewrite_rules: HashMap<String, Vec<String>> = HashMap::new();
+        let mut molecule = String::new();
         for line in input.lines() {
             let parts: Vec<_> = line.split(" => ").collect();
             if let &[from, to] = &parts[..] {
-                rewrite_rules.entry(from).or_default().push(to);
+                rewrite_rules
+                    .entry(from.to_owned())
+                    .or_default()
+                    .push(to.to_owned());
             } else if parts.len() == 1 {
-                molecule = parts[0];
+                molecule = parts[0].to_owned();
             }
         }
-        Solver {
+        if molecule.is_empty() {
+            return Err(ParseError::new(
+                "expected the molecule after the replacements",
+            ));
+        }
+        Ok(Solver {
             rewrite_rules,
             molecule,
-        }
+        })
     }
 
     fn find_all_positions(haystack: &str, needle: &str) -> Vec<usize> {
@@ -55,16 +63,14 @@ impl Solver {
         }
     }
 
-    fn _invert_rules(
-        rules: &HashMap<&'static str, Vec<&'static str>>,
-    ) -> HashMap<&'static str, Vec<&'static str>> {
+    fn _invert_rules(rules: &HashMap<String, Vec<String>>) -> HashMap<String, Vec<String>> {
         let mut result = HashMap::new();
         for (rule_target, replacements) in rules.iter() {
             for replacement in replacements {
                 result
-                    .entry(*replacement)
+                    .entry(replacement.clone())
                     .or_insert(Vec::new())
-                    .push(*rule_target);
+                    .push(rule_target.clone());
             }
         }
         result
@@ -72,7 +78,7 @@ impl Solver {
 
     fn find_all_descendants(
         molecule: String,
-        rules: &HashMap<&'static str, Vec<&'static str>>,
+        rules: &HashMap<String, Vec<String>>,
     ) -> HashSet<String> {
         let mut distinct_molecules = HashSet::new();
         for (rule_target, replacements) in rules.iter() {
@@ -90,14 +96,14 @@ impl Solver {
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
+    fn solve_part_one(&self) -> Answer {
         let distinct_molecules =
-            Solver::find_all_descendants(self.molecule.to_owned(), &self.rewrite_rules);
-        distinct_molecules.len().to_string()
+            Solver::find_all_descendants(self.molecule.clone(), &self.rewrite_rules);
+        distinct_molecules.len().into()
     }
 
-    fn solve_part_two(&self) -> String {
-        "0".to_string()
+    fn solve_part_two(&self) -> Answer {
+        Answer::Unsolved
     }
 
     fn day_number(&self) -> usize {
@@ -122,6 +128,7 @@ O => HH
 
 HOH",
         )
+        .unwrap()
         .solve_part_one();
         assert_eq!(result, "4");
     }
@@ -142,6 +149,7 @@ O => HH
 
 HOH",
         )
+        .unwrap()
         .solve_part_two();
         assert_eq!(result, "3");
     }
@@ -157,6 +165,7 @@ O => HH
 
 HOHOHO",
         )
+        .unwrap()
         .solve_part_two();
         assert_eq!(result, "6");
     }
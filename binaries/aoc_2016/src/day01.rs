@@ -1,50 +1,63 @@
 use std::collections::HashSet;
 
-use solver::SolverBase;
+use solver::{Answer, Direction, ParseError, Point2, SolverBase, parse_field};
+
+enum Turn {
+    Left,
+    Right,
+}
 
 pub struct Solver {
-    input: &'static str,
+    moves: Vec<(Turn, i32)>,
 }
 
 impl Solver {
-    pub fn new(input: &'static str) -> Self {
-        Solver { input }
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let moves = input
+            .split(", ")
+            .map(|m| {
+                let turn = match m.get(..1) {
+                    Some("L") => Turn::Left,
+                    Some("R") => Turn::Right,
+                    _ => return Err(ParseError::expected("`L` or `R` turn", m)),
+                };
+                Ok((turn, parse_field(&m[1..])?))
+            })
+            .collect::<Result<_, _>>()
+            .map_err(|error| error.on_line(1, input))?;
+        Ok(Solver { moves })
     }
 
-    fn walk(&self, stop_at_intersection: bool) -> i32 {
-        let mut position: (i32, i32) = (0, 0);
-        let mut direction = 0;
+    fn walk(&self, stop_at_intersection: bool) -> u32 {
+        let start = Point2::new(0i32, 0);
+        let mut position = start;
+        let mut direction = Direction::Up;
         let mut visited = HashSet::new();
         visited.insert(position);
-        'outer: for m in self.input.split(", ") {
-            let (dir, dist) = m.split_at(1);
-            let dist = dist.parse::<i32>().unwrap();
-            direction = (direction + if dir == "R" { 1 } else { 3 }) % 4;
-            for _ in 0..dist {
-                match direction {
-                    0 => position.1 += 1,
-                    1 => position.0 += 1,
-                    2 => position.1 -= 1,
-                    3 => position.0 -= 1,
-                    _ => unreachable!(),
-                }
+        'outer: for (turn, dist) in &self.moves {
+            direction = match turn {
+                Turn::Right => direction.turn_right(),
+                Turn::Left => direction.turn_left(),
+            };
+            for _ in 0..*dist {
+                position = position.step(direction);
                 if stop_at_intersection && visited.contains(&position) {
                     break 'outer;
                 }
                 visited.insert(position);
             }
         }
-        position.0.abs() + position.1.abs()
+        position.manhattan_distance(start)
     }
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
-        self.walk(false).to_string()
+    fn solve_part_one(&self) -> Answer {
+        self.walk(false).into()
     }
 
-    fn solve_part_two(&self) -> String {
-        self.walk(true).to_string()
+    fn solve_part_two(&self) -> Answer {
+        self.walk(true).into()
     }
 
     fn day_number(&self) -> usize {
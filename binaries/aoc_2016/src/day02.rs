@@ -1,4 +1,4 @@
-use solver::SolverBase;
+use solver::{Answer, ParseError, SolverBase, parse_lines};
 
 #[derive(Debug, PartialEq, Eq)]
 enum Instruction {
@@ -33,22 +33,22 @@ impl Solver {
         keypad
     }
 
-    pub fn new(input: &'static str) -> Self {
-        let instructions = input
-            .lines()
-            .map(|line| {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let instructions =
+            parse_lines(input, |line| {
                 line.chars()
-                    .map(|c| match c {
-                        'U' => Instruction::Up,
-                        'D' => Instruction::Down,
-                        'L' => Instruction::Left,
-                        'R' => Instruction::Right,
-                        _ => panic!("Invalid instruction"),
+                    .enumerate()
+                    .map(|(index, c)| match c {
+                        'U' => Ok(Instruction::Up),
+                        'D' => Ok(Instruction::Down),
+                        'L' => Ok(Instruction::Left),
+                        'R' => Ok(Instruction::Right),
+                        _ => Err(ParseError::expected("one of `UDLR`", &c.to_string())
+                            .at_column(index + 1)),
                     })
                     .collect()
-            })
-            .collect();
-        Solver { instructions }
+            })?;
+        Ok(Solver { instructions })
     }
 
     fn get_next_position_on_keypad(
@@ -136,7 +136,7 @@ impl Solver {
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
+    fn solve_part_one(&self) -> Answer {
         let mut code = String::new();
         let keypad = Solver::get_standard_keypad();
         let mut position = (1, 1);
@@ -146,10 +146,10 @@ impl SolverBase for Solver {
             }
             code.push(Solver::get_key_on_standard_keypad(position));
         }
-        code
+        code.into()
     }
 
-    fn solve_part_two(&self) -> String {
+    fn solve_part_two(&self) -> Answer {
         let mut code = String::new();
         let keypad = Solver::get_star_keypad();
         let mut position = (0, 2);
@@ -159,7 +159,7 @@ impl SolverBase for Solver {
             }
             code.push(Solver::get_key_on_star_keypad(position));
         }
-        code
+        code.into()
     }
 
     fn day_number(&self) -> usize {
@@ -183,6 +183,7 @@ RRDDD
 LURDL
 UUUUD",
         )
+        .unwrap()
         .solve_part_one();
         assert_eq!(result, "1985");
     }
@@ -200,6 +201,7 @@ RRDDD
 LURDL
 UUUUD",
         )
+        .unwrap()
         .solve_part_two();
         assert_eq!(result, "5DB3");
     }
@@ -1,5 +1,4 @@
-use itertools::Itertools;
-use solver::SolverBase;
+use solver::{Answer, ParseError, SolverBase, parse_field, parse_lines};
 
 pub struct Solver {
     triangles: Vec<(u32, u32, u32)>,
@@ -7,30 +6,31 @@ pub struct Solver {
 }
 
 impl Solver {
-    pub fn new(input: &'static str) -> Self {
-        fn get_sides(line: &str) -> (u32, u32, u32) {
-            let mut sides = line.split_whitespace().map(|s| s.parse().unwrap());
-            (
-                sides.next().unwrap(),
-                sides.next().unwrap(),
-                sides.next().unwrap(),
-            )
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        fn get_sides(line: &str) -> Result<(u32, u32, u32), ParseError> {
+            let sides = line
+                .split_whitespace()
+                .map(parse_field)
+                .collect::<Result<Vec<_>, _>>()?;
+            match sides[..] {
+                [a, b, c] => Ok((a, b, c)),
+                _ => Err(ParseError::new("expected three sides")),
+            }
         }
 
-        let triangles = input.lines().map(get_sides).collect();
+        let triangles: Vec<_> = parse_lines(input, get_sides)?;
         let mut triangles_vertically = Vec::new();
-        for triplet in input.lines().chunks(3).into_iter() {
-            let sides: Vec<_> = triplet.map(get_sides).collect();
+        for sides in triangles.chunks(3) {
             if sides.len() == 3 {
                 triangles_vertically.push((sides[0].0, sides[1].0, sides[2].0));
                 triangles_vertically.push((sides[0].1, sides[1].1, sides[2].1));
                 triangles_vertically.push((sides[0].2, sides[1].2, sides[2].2));
             }
         }
-        Solver {
+        Ok(Solver {
             triangles,
             triangles_vertically,
-        }
+        })
     }
 
     fn get_count_of_valid_triangles(triangles: &[(u32, u32, u32)]) -> usize {
@@ -42,12 +42,12 @@ impl Solver {
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
-        Solver::get_count_of_valid_triangles(&self.triangles).to_string()
+    fn solve_part_one(&self) -> Answer {
+        Solver::get_count_of_valid_triangles(&self.triangles).into()
     }
 
-    fn solve_part_two(&self) -> String {
-        Solver::get_count_of_valid_triangles(&self.triangles_vertically).to_string()
+    fn solve_part_two(&self) -> Answer {
+        Solver::get_count_of_valid_triangles(&self.triangles_vertically).into()
     }
 
     fn day_number(&self) -> usize {
@@ -65,13 +65,13 @@ mod part1_tests {
 
     #[test]
     fn test_1() {
-        let result = Solver::new("5 10 25").solve_part_one();
+        let result = Solver::new("5 10 25").unwrap().solve_part_one();
         assert_eq!(result, "0");
     }
 
     #[test]
     fn test_2() {
-        let result = Solver::new("18 10 25").solve_part_one();
+        let result = Solver::new("18 10 25").unwrap().solve_part_one();
         assert_eq!(result, "1");
     }
 }
@@ -82,7 +82,7 @@ mod part1_tests {
 
 //     #[test]
 //     fn test_1() {
-//         let result = Solver::new("abc").solve_part_two();
+//         let result = Solver::new("abc").unwrap().solve_part_two();
 //         assert_eq!(result, "0");
 //     }
 // }
@@ -1,13 +1,13 @@
 use std::{cmp::Ordering, collections::HashMap};
 
 use itertools::Itertools;
-use solver::SolverBase;
+use solver::{Answer, ParseError, SolverBase, parse_field, parse_lines};
 
 #[derive(Debug)]
 struct Room {
-    codes: Vec<&'static str>,
+    codes: Vec<String>,
     id: u32,
-    checksum: &'static str,
+    checksum: String,
 }
 
 pub struct Solver {
@@ -15,22 +15,22 @@ pub struct Solver {
 }
 
 impl Solver {
-    pub fn new(input: &'static str) -> Self {
-        let mut rooms = Vec::new();
-        for line in input.lines() {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let rooms = parse_lines(input, |line| {
             let parts = line.split(['[', ']']).collect::<Vec<_>>();
-            if let [code_strings, checksum, ..] = &parts[..] {
-                let tokens = code_strings.split('-').collect::<Vec<_>>();
-                let codes = tokens[..tokens.len() - 1].to_vec();
-                let id = tokens[tokens.len() - 1].parse().unwrap();
-                rooms.push(Room {
-                    codes,
-                    id,
-                    checksum,
-                });
-            }
-        }
-        Solver { rooms }
+            let [code_strings, checksum, ..] = &parts[..] else {
+                return Err(ParseError::new("expected `<name>-<sector id>[<checksum>]`"));
+            };
+            let (codes, id) = code_strings
+                .rsplit_once('-')
+                .ok_or_else(|| ParseError::expected("`<name>-<sector id>`", code_strings))?;
+            Ok(Room {
+                codes: codes.split('-').map(|code| code.to_string()).collect(),
+                id: parse_field(id)?,
+                checksum: checksum.to_string(),
+            })
+        })?;
+        Ok(Solver { rooms })
     }
 
     fn is_real(room: &Room) -> bool {
@@ -88,25 +88,25 @@ impl Solver {
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
+    fn solve_part_one(&self) -> Answer {
         let result: u32 = self
             .rooms
             .iter()
             .filter(|room| Solver::is_real(room))
             .map(|room| room.id)
             .sum();
-        result.to_string()
+        result.into()
     }
 
-    fn solve_part_two(&self) -> String {
+    fn solve_part_two(&self) -> Answer {
         for room in self.rooms.iter() {
             let decoded_room = Solver::shift(room);
             //println!("{decoded_room}");
             if decoded_room == "northpole object storage" {
-                return room.id.to_string();
+                return room.id.into();
             }
         }
-        "".to_string()
+        Answer::Unsolved
     }
 
     fn day_number(&self) -> usize {
@@ -124,25 +124,33 @@ mod part1_tests {
 
     #[test]
     fn test_1() {
-        let result = Solver::new("aaaaa-bbb-z-y-x-123[abxyz]").solve_part_one();
+        let result = Solver::new("aaaaa-bbb-z-y-x-123[abxyz]")
+            .unwrap()
+            .solve_part_one();
         assert_eq!(result, "123");
     }
 
     #[test]
     fn test_2() {
-        let result = Solver::new("a-b-c-d-e-f-g-h-987[abcde]").solve_part_one();
+        let result = Solver::new("a-b-c-d-e-f-g-h-987[abcde]")
+            .unwrap()
+            .solve_part_one();
         assert_eq!(result, "987");
     }
 
     #[test]
     fn test_3() {
-        let result = Solver::new("not-a-real-room-404[oarel]").solve_part_one();
+        let result = Solver::new("not-a-real-room-404[oarel]")
+            .unwrap()
+            .solve_part_one();
         assert_eq!(result, "404");
     }
 
     #[test]
     fn test_4() {
-        let result = Solver::new("totally-real-room-200[decoy]").solve_part_one();
+        let result = Solver::new("totally-real-room-200[decoy]")
+            .unwrap()
+            .solve_part_one();
         assert_eq!(result, "0");
     }
 }
@@ -153,7 +161,7 @@ mod part2_tests {
 
     #[test]
     fn test_1() {
-        let result = Solver::shift(&Solver::new("qzmt-zixmtkozy-ivhz-343[x]").rooms[0]);
+        let result = Solver::shift(&Solver::new("qzmt-zixmtkozy-ivhz-343[x]").unwrap().rooms[0]);
         assert_eq!(result, "very encrypted name");
     }
 }
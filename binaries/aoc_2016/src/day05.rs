@@ -1,85 +1,53 @@
-use solver::SolverBase;
+use solver::{Answer, NonceMatch, NonceMiner, ParseError, SolverBase, leading_zero_nibbles};
 
 pub struct Solver {
-    input: &'static str,
+    miner: NonceMiner,
 }
 
 impl Solver {
-    pub fn new(input: &'static str) -> Self {
-        Solver { input }
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        Ok(Solver {
+            miner: NonceMiner::new(input),
+        })
     }
 
-    fn get_first_char_of_hashed_word_with_index(
-        word: &str,
-        start_index: u32,
-    ) -> (char, char, Option<usize>, u32) {
-        let mut index = start_index;
-        loop {
-            let test = format!("{word}{index}");
-            let hash = md5::compute(test);
-            let hash_hex = format!("{:x}", hash);
-            if hash_hex.starts_with("00000") {
-                let c1 = hash_hex.chars().nth(5).unwrap();
-                let c2 = hash_hex.chars().nth(6).unwrap();
-                let mut position = None;
-                if let Ok(x) = hash_hex[5..6].parse::<usize>() {
-                    if x <= 7 {
-                        position = Some(x);
-                    }
-                }
-                return (c1, c2, position, index);
-            }
-            index += 1;
-        }
-    }
-
-    fn set_char_at_index(s: &str, index: usize, new_char: char) -> String {
-        // Convert the string into a vector of characters
-        let mut chars: Vec<char> = s.chars().collect();
-
-        // Replace the character at the specified index
-        if index < chars.len() {
-            chars[index] = new_char;
-        } else {
-            panic!("Index out of bounds");
-        }
-
-        // Reconstruct the string from the characters
-        chars.into_iter().collect()
+    /// Hashes of the door id and an index which start with five zeros, in index order
+    fn interesting_hashes(&self) -> impl Iterator<Item = NonceMatch> + '_ {
+        self.miner
+            .matches(|digest| leading_zero_nibbles(digest) >= 5)
     }
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
-        let mut index = 0;
-        let mut password = String::new();
-        for _ in 0..8 {
-            let (c, _c2, _position, current_index) =
-                Solver::get_first_char_of_hashed_word_with_index(self.input, index);
-            password.push(c);
-            index = current_index + 1;
+    fn solve_part_one(&self) -> Answer {
+        let password: String = self
+            .interesting_hashes()
+            .take(8)
+            .map(|hash| hash.hex_digit(5))
+            .collect();
+        match password.len() {
+            8 => password.into(),
+            _ => Answer::NoSolution,
         }
-        password
     }
 
-    fn solve_part_two(&self) -> String {
-        let mut index = 0;
-        let mut password = "________".to_owned();
-        loop {
-            let (_c1, c2, position, current_index) =
-                Solver::get_first_char_of_hashed_word_with_index(self.input, index);
-            if let Some(position) = position {
-                if password.chars().nth(position).unwrap() == '_' {
-                    password = Solver::set_char_at_index(&password, position, c2);
-                    println!("{password}");
-                    if password.chars().filter(|x| *x != '_').count() == 8 {
-                        break;
-                    }
+    fn solve_part_two(&self) -> Answer {
+        // The sixth digit is the position, the seventh the character
+        let mut password = [None; 8];
+        for hash in self.interesting_hashes() {
+            if let Some(slot) = password.get_mut(hash.nibble(5) as usize)
+                && slot.is_none()
+            {
+                *slot = Some(hash.hex_digit(6));
+                if password.iter().all(Option::is_some) {
+                    break;
                 }
             }
-            index = current_index + 1;
         }
-        password
+        match password.into_iter().collect::<Option<String>>() {
+            Some(password) => password.into(),
+            None => Answer::NoSolution,
+        }
     }
 
     fn day_number(&self) -> usize {
@@ -89,10 +57,6 @@ impl SolverBase for Solver {
     fn description(&self) -> &'static str {
         "MD5 hashed password"
     }
-
-    fn skip_run(&self) -> bool {
-        true
-    }
 }
 
 #[cfg(test)]
@@ -102,28 +66,18 @@ mod part1_tests {
 
     #[test]
     fn test_1() {
-        let (c, _, _, index) = Solver::get_first_char_of_hashed_word_with_index("abc", 0);
-        assert_eq!(index, 3231929);
-        assert_eq!(c, '1');
+        let solver = Solver::new("abc").unwrap();
+        let hashes: Vec<_> = solver
+            .interesting_hashes()
+            .take(3)
+            .map(|hash| (hash.nonce, hash.hex_digit(5)))
+            .collect();
+        assert_eq!(hashes, [(3231929, '1'), (5017308, '8'), (5278568, 'f')]);
     }
 
     #[test]
     fn test_2() {
-        let (c, _, _, index) = Solver::get_first_char_of_hashed_word_with_index("abc", 5017308);
-        assert_eq!(index, 5017308);
-        assert_eq!(c, '8');
-    }
-
-    #[test]
-    fn test_3() {
-        let (c, _, _, index) = Solver::get_first_char_of_hashed_word_with_index("abc", 5017308 + 1);
-        assert_eq!(index, 5278568);
-        assert_eq!(c, 'f');
-    }
-
-    #[test]
-    fn test_4() {
-        let result = Solver::new("abc").solve_part_one();
+        let result = Solver::new("abc").unwrap().solve_part_one();
         assert_eq!(result, "18f47a30");
     }
 }
@@ -134,7 +88,7 @@ mod part2_tests {
 
     #[test]
     fn test_1() {
-        let result = Solver::new("abc").solve_part_two();
+        let result = Solver::new("abc").unwrap().solve_part_two();
         assert_eq!(result, "05ace8e3");
     }
 }
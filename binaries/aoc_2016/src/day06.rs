@@ -1,9 +1,9 @@
 use std::{cmp::Ordering, collections::HashMap};
 
-use solver::SolverBase;
+use solver::{Answer, ParseError, SolverBase};
 
 pub struct Solver {
-    lines: Vec<&'static str>,
+    lines: Vec<String>,
 }
 
 enum ErrorCorrection {
@@ -12,10 +12,10 @@ enum ErrorCorrection {
 }
 
 impl Solver {
-    pub fn new(input: &'static str) -> Self {
-        Solver {
-            lines: input.lines().collect(),
-        }
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        Ok(Solver {
+            lines: input.lines().map(str::to_owned).collect(),
+        })
     }
 
     fn error_correct(&self, error_correction: ErrorCorrection) -> String {
@@ -40,12 +40,13 @@ impl Solver {
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
-        self.error_correct(ErrorCorrection::MostFrequentChar)
+    fn solve_part_one(&self) -> Answer {
+        self.error_correct(ErrorCorrection::MostFrequentChar).into()
     }
 
-    fn solve_part_two(&self) -> String {
+    fn solve_part_two(&self) -> Answer {
         self.error_correct(ErrorCorrection::LeastFrequentChar)
+            .into()
     }
 
     fn day_number(&self) -> usize {
@@ -81,6 +82,7 @@ vrdear
 dvrsen
 enarar",
         )
+        .unwrap()
         .solve_part_one();
         assert_eq!(result, "easter");
     }
@@ -110,6 +112,7 @@ vrdear
 dvrsen
 enarar",
         )
+        .unwrap()
         .solve_part_two();
         assert_eq!(result, "advent");
     }
@@ -1,9 +1,9 @@
 use itertools::Itertools;
-use solver::SolverBase;
+use solver::{Answer, ParseError, SolverBase};
 
 struct Ip {
-    segments: Vec<&'static str>,
-    hypernet_sequences: Vec<&'static str>,
+    segments: Vec<String>,
+    hypernet_sequences: Vec<String>,
 }
 
 pub struct Solver {
@@ -11,7 +11,7 @@ pub struct Solver {
 }
 
 impl Solver {
-    pub fn new(input: &'static str) -> Self {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
         let mut ips = Vec::new();
         for line in input.lines() {
             let parts: Vec<_> = line.split(['[', ']']).collect();
@@ -24,14 +24,14 @@ impl Solver {
                 } else {
                     &mut hypernet_sequences
                 };
-                current_vec.push(*part);
+                current_vec.push(part.to_string());
             }
             ips.push(Ip {
                 segments,
                 hypernet_sequences,
             });
         }
-        Solver { ips }
+        Ok(Solver { ips })
     }
 
     fn supports_tls(ip: &Ip) -> bool {
@@ -39,7 +39,7 @@ impl Solver {
             && ip.hypernet_sequences.iter().all(|x| !Solver::has_abba(x))
     }
 
-    fn has_abba(segment: &'static str) -> bool {
+    fn has_abba(segment: &str) -> bool {
         for (a, b, c, d) in segment.chars().tuple_windows() {
             if a == d && b == c && a != b {
                 return true;
@@ -63,7 +63,7 @@ impl Solver {
         }
     }
 
-    fn get_all_aba(segment: &'static str) -> Vec<(char, char, char)> {
+    fn get_all_aba(segment: &str) -> Vec<(char, char, char)> {
         let mut aba_list = Vec::new();
         for tuple @ (a, b, c) in segment.chars().tuple_windows() {
             if a == c && a != b {
@@ -84,22 +84,22 @@ impl Solver {
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
+    fn solve_part_one(&self) -> Answer {
         let count = self
             .ips
             .iter()
             .filter(|ip| Solver::supports_tls(ip))
             .count();
-        count.to_string()
+        count.into()
     }
 
-    fn solve_part_two(&self) -> String {
+    fn solve_part_two(&self) -> Answer {
         let count = self
             .ips
             .iter()
             .filter(|ip| Solver::supports_ssl(ip))
             .count();
-        count.to_string()
+        count.into()
     }
 
     fn day_number(&self) -> usize {
@@ -117,25 +117,27 @@ mod part1_tests {
 
     #[test]
     fn test_1() {
-        let result = Solver::new("abba[mnop]qrst").solve_part_one();
+        let result = Solver::new("abba[mnop]qrst").unwrap().solve_part_one();
         assert_eq!(result, "1");
     }
 
     #[test]
     fn test_2() {
-        let result = Solver::new("abcd[bddb]xyyx").solve_part_one();
+        let result = Solver::new("abcd[bddb]xyyx").unwrap().solve_part_one();
         assert_eq!(result, "0");
     }
 
     #[test]
     fn test_3() {
-        let result = Solver::new("aaaa[qwer]tyui").solve_part_one();
+        let result = Solver::new("aaaa[qwer]tyui").unwrap().solve_part_one();
         assert_eq!(result, "0");
     }
 
     #[test]
     fn test_4() {
-        let result = Solver::new("ioxxoj[asdfgh]zxcvbn").solve_part_one();
+        let result = Solver::new("ioxxoj[asdfgh]zxcvbn")
+            .unwrap()
+            .solve_part_one();
         assert_eq!(result, "1");
     }
 }
@@ -146,25 +148,25 @@ mod part2_tests {
 
     #[test]
     fn test_1() {
-        let result = Solver::new("aba[bab]xyz").solve_part_two();
+        let result = Solver::new("aba[bab]xyz").unwrap().solve_part_two();
         assert_eq!(result, "1");
     }
 
     #[test]
     fn test_2() {
-        let result = Solver::new("xyx[xyx]xyx").solve_part_two();
+        let result = Solver::new("xyx[xyx]xyx").unwrap().solve_part_two();
         assert_eq!(result, "0");
     }
 
     #[test]
     fn test_3() {
-        let result = Solver::new("aaa[kek]eke").solve_part_two();
+        let result = Solver::new("aaa[kek]eke").unwrap().solve_part_two();
         assert_eq!(result, "1");
     }
 
     #[test]
     fn test_4() {
-        let result = Solver::new("zazbz[bzb]cdb").solve_part_two();
+        let result = Solver::new("zazbz[bzb]cdb").unwrap().solve_part_two();
         assert_eq!(result, "1");
     }
 }
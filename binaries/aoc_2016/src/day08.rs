@@ -1,4 +1,4 @@
-use solver::SolverBase;
+use solver::{Answer, ParseError, SolverBase, parse_field, parse_lines, read_letters};
 
 #[derive(Debug)]
 enum Instruction {
@@ -12,37 +12,37 @@ pub struct Solver<const T_WIDTH: usize, const T_HEIGHT: usize> {
 }
 
 impl<const T_WIDTH: usize, const T_HEIGHT: usize> Solver<T_WIDTH, T_HEIGHT> {
-    pub fn new(input: &'static str) -> Self {
-        let instructions = input
-            .lines()
-            .map(|line| {
-                let tokens: Vec<_> = line.split_whitespace().collect();
-                match &tokens[..] {
-                    ["rect", dim_str] => {
-                        let mut dim_tokens = dim_str.split('x');
-                        let dim_x = dim_tokens.next().unwrap().parse().unwrap();
-                        let dim_y = dim_tokens.next().unwrap().parse().unwrap();
-                        Instruction::Rect(dim_x, dim_y)
-                    }
-                    ["rotate", "column", column_str, "by", distance_str] => {
-                        let mut column_tokens = column_str.split('=');
-                        _ = column_tokens.next().unwrap();
-                        let column_index = column_tokens.next().unwrap().parse().unwrap();
-                        let distance = distance_str.parse().unwrap();
-                        Instruction::RotateColumn(column_index, distance)
-                    }
-                    ["rotate", "row", row_str, "by", distance_str] => {
-                        let mut row_tokens = row_str.split('=');
-                        _ = row_tokens.next().unwrap();
-                        let row_index = row_tokens.next().unwrap().parse().unwrap();
-                        let distance = distance_str.parse().unwrap();
-                        Instruction::RotateRow(row_index, distance)
-                    }
-                    _ => panic!("unrecognized instruction"),
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        fn parse_assignment(text: &str, name: &str) -> Result<usize, ParseError> {
+            match text.split_once('=') {
+                Some((key, value)) if key == name => parse_field(value),
+                _ => Err(ParseError::expected(&format!("`{name}=<index>`"), text)),
+            }
+        }
+
+        let instructions = parse_lines(input, |line| {
+            let tokens: Vec<_> = line.split_whitespace().collect();
+            match &tokens[..] {
+                ["rect", dim_str] => {
+                    let (dim_x, dim_y) = dim_str
+                        .split_once('x')
+                        .ok_or_else(|| ParseError::expected("`<width>x<height>`", dim_str))?;
+                    Ok(Instruction::Rect(parse_field(dim_x)?, parse_field(dim_y)?))
                 }
-            })
-            .collect();
-        Solver { instructions }
+                ["rotate", "column", column_str, "by", distance_str] => {
+                    Ok(Instruction::RotateColumn(
+                        parse_assignment(column_str, "x")?,
+                        parse_field(distance_str)?,
+                    ))
+                }
+                ["rotate", "row", row_str, "by", distance_str] => Ok(Instruction::RotateRow(
+                    parse_assignment(row_str, "y")?,
+                    parse_field(distance_str)?,
+                )),
+                _ => Err(ParseError::new("expected `rect` or `rotate` instruction")),
+            }
+        })?;
+        Ok(Solver { instructions })
     }
 
     fn apply_instruction(screen: &mut [[bool; T_WIDTH]; T_HEIGHT], instruction: &Instruction) {
@@ -83,10 +83,7 @@ impl<const T_WIDTH: usize, const T_HEIGHT: usize> Solver<T_WIDTH, T_HEIGHT> {
     }
 
     fn rotate_row(screen: &mut [[bool; T_WIDTH]; T_HEIGHT], row_index: usize, distance: usize) {
-        let mut row = Vec::new();
-        for column_index in 0..T_WIDTH {
-            row.push(screen[row_index][column_index]);
-        }
+        let mut row = screen[row_index].to_vec();
         row.rotate_right(distance);
         for (column_index, pixel) in row.iter().enumerate() {
             screen[row_index][column_index] = *pixel;
@@ -122,22 +119,22 @@ impl<const T_WIDTH: usize, const T_HEIGHT: usize> Solver<T_WIDTH, T_HEIGHT> {
 }
 
 impl<const T_WIDTH: usize, const T_HEIGHT: usize> SolverBase for Solver<T_WIDTH, T_HEIGHT> {
-    fn solve_part_one(&self) -> String {
+    fn solve_part_one(&self) -> Answer {
         let mut screen = [[false; T_WIDTH]; T_HEIGHT];
         for instruction in self.instructions.iter() {
             Solver::<T_WIDTH, T_HEIGHT>::apply_instruction(&mut screen, instruction);
             //Solver::<T_WIDTH, T_HEIGHT>::print_screen(&screen);
         }
-        Solver::<T_WIDTH, T_HEIGHT>::count_of_pixels_lit(&screen).to_string()
+        Solver::<T_WIDTH, T_HEIGHT>::count_of_pixels_lit(&screen).into()
     }
 
-    fn solve_part_two(&self) -> String {
+    fn solve_part_two(&self) -> Answer {
         let mut screen = [[false; T_WIDTH]; T_HEIGHT];
         for instruction in self.instructions.iter() {
             Solver::<T_WIDTH, T_HEIGHT>::apply_instruction(&mut screen, instruction);
         }
-        // Solver::<T_WIDTH, T_HEIGHT>::print_screen(&screen); // answer is in printed screen
-        "EFEYKFRFIJ".to_owned()
+        // A screen which does not spell letters is shown as it is
+        read_letters(&screen).map_or_else(|| Answer::from_pixels(&screen), Answer::from)
     }
 
     fn day_number(&self) -> usize {
@@ -155,7 +152,7 @@ mod part1_tests {
 
     #[test]
     fn test_1() {
-        let result = Solver::<7, 3>::new("rect 3x2").solve_part_one();
+        let result = Solver::<7, 3>::new("rect 3x2").unwrap().solve_part_one();
         assert_eq!(result, "6");
     }
 
@@ -167,18 +164,26 @@ rotate column x=1 by 1
 rotate row y=0 by 4
 rotate column x=1 by 1",
         )
+        .unwrap()
         .solve_part_one();
         assert_eq!(result, "6");
     }
 }
 
-// #[cfg(test)]
-// mod part2_tests {
-//     use super::*;
+#[cfg(test)]
+mod part2_tests {
+    use super::*;
 
-//     #[test]
-//     fn test_1() {
-//         let result = Solver::new("abc").solve_part_two();
-//         assert_eq!(result, "0");
-//     }
-// }
+    #[test]
+    fn test_1() {
+        let result = Solver::<7, 3>::new(
+            r"rect 3x2
+rotate column x=1 by 1
+rotate row y=0 by 4
+rotate column x=1 by 1",
+        )
+        .unwrap()
+        .solve_part_two();
+        assert_eq!(result, ".#..#.#\n#.#....\n.#.....");
+    }
+}
@@ -1,12 +1,14 @@
-use solver::SolverBase;
+use solver::{Answer, ParseError, SolverBase};
 
 pub struct Solver {
-    input: &'static str,
+    input: String,
 }
 
 impl Solver {
-    pub fn new(input: &'static str) -> Self {
-        Solver { input }
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        Ok(Solver {
+            input: input.to_owned(),
+        })
     }
 
     fn consume_chars(iter: &mut impl Iterator<Item = char>, length: usize) {
@@ -82,7 +84,7 @@ impl Solver {
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
+    fn solve_part_one(&self) -> Answer {
         let mut char_iter = self.input.chars();
         let mut decompressed_length = 0;
         while let Some(c) = char_iter.next() {
@@ -95,11 +97,11 @@ impl SolverBase for Solver {
                 _ => decompressed_length += 1,
             }
         }
-        decompressed_length.to_string()
+        decompressed_length.into()
     }
 
-    fn solve_part_two(&self) -> String {
-        Solver::decompress_length(&mut self.input.chars()).to_string()
+    fn solve_part_two(&self) -> Answer {
+        Solver::decompress_length(&mut self.input.chars()).into()
     }
 
     fn day_number(&self) -> usize {
@@ -117,37 +119,37 @@ mod part1_tests {
 
     #[test]
     fn test_1() {
-        let result = Solver::new("ADVENT").solve_part_one();
+        let result = Solver::new("ADVENT").unwrap().solve_part_one();
         assert_eq!(result, "6");
     }
 
     #[test]
     fn test_2() {
-        let result = Solver::new("A(1x5)BC").solve_part_one();
+        let result = Solver::new("A(1x5)BC").unwrap().solve_part_one();
         assert_eq!(result, "7");
     }
 
     #[test]
     fn test_3() {
-        let result = Solver::new("(3x3)XYZ").solve_part_one();
+        let result = Solver::new("(3x3)XYZ").unwrap().solve_part_one();
         assert_eq!(result, "9");
     }
 
     #[test]
     fn test_4() {
-        let result = Solver::new("A(2x2)BCD(2x2)EFG").solve_part_one();
+        let result = Solver::new("A(2x2)BCD(2x2)EFG").unwrap().solve_part_one();
         assert_eq!(result, "11");
     }
 
     #[test]
     fn test_5() {
-        let result = Solver::new("(6x1)(1x3)A").solve_part_one();
+        let result = Solver::new("(6x1)(1x3)A").unwrap().solve_part_one();
         assert_eq!(result, "6");
     }
 
     #[test]
     fn test_6() {
-        let result = Solver::new("X(8x2)(3x3)ABCY").solve_part_one();
+        let result = Solver::new("X(8x2)(3x3)ABCY").unwrap().solve_part_one();
         assert_eq!(result, "18");
     }
 }
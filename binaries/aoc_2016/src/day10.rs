@@ -1,6 +1,6 @@
 use std::collections::{HashMap, HashSet};
 
-use solver::SolverBase;
+use solver::{Answer, ParseError, SolverBase, parse_field, parse_lines};
 
 enum Target {
     Output(u8),
@@ -31,23 +31,26 @@ enum RequiredResult {
 }
 
 impl Solver {
-    pub fn new(input: &'static str, low_value_microchip: u8, high_value_microchip: u8) -> Self {
-        fn parse_target(target_type: &str, target_number: &str) -> Target {
-            let target_number = target_number.parse().unwrap();
+    pub fn new(
+        input: &str,
+        low_value_microchip: u8,
+        high_value_microchip: u8,
+    ) -> Result<Self, ParseError> {
+        fn parse_target(target_type: &str, target_number: &str) -> Result<Target, ParseError> {
+            let target_number = parse_field(target_number)?;
             match target_type {
-                "bot" => Target::Bot(target_number),
-                "output" => Target::Output(target_number),
-                _ => panic!("unrecognized target type"),
+                "bot" => Ok(Target::Bot(target_number)),
+                "output" => Ok(Target::Output(target_number)),
+                _ => Err(ParseError::expected("`bot` or `output`", target_type)),
             }
         }
 
-        let mut actions = Vec::new();
-        for line in input.lines() {
+        let actions = parse_lines(input, |line| {
             let tokens: Vec<_> = line.split_whitespace().collect();
             let action = match &tokens[..] {
                 ["value", value_number, "goes", "to", "bot", bot_number] => Action::ValueGoesTo {
-                    value: value_number.parse().unwrap(),
-                    bot_number: bot_number.parse().unwrap(),
+                    value: parse_field(value_number)?,
+                    bot_number: parse_field(bot_number)?,
                 },
                 [
                     "bot",
@@ -63,20 +66,20 @@ impl Solver {
                     high_target_type,
                     high_target_number,
                 ] => Action::BotGives {
-                    bot_number: bot_number.parse().unwrap(),
-                    low_target: parse_target(low_target_type, low_target_number),
-                    high_target: parse_target(high_target_type, high_target_number),
+                    bot_number: parse_field(bot_number)?,
+                    low_target: parse_target(low_target_type, low_target_number)?,
+                    high_target: parse_target(high_target_type, high_target_number)?,
                 },
 
-                _ => panic!("unrecognized action"),
+                _ => return Err(ParseError::new("expected `value` or `bot` instruction")),
             };
-            actions.push(action);
-        }
-        Solver {
+            Ok(action)
+        })?;
+        Ok(Solver {
             actions,
             low_value_microchip,
             high_value_microchip,
-        }
+        })
     }
 
     fn solve(&self, result_type: RequiredResult) -> String {
@@ -87,12 +90,12 @@ impl Solver {
             bot_values: &HashMap<u8, HashSet<u8>>,
             bot_number: u8,
         ) -> Option<(u8, u8)> {
-            if let Some(values) = bot_values.get(&bot_number) {
-                if values.len() == 2 {
-                    let low_value = values.iter().min().unwrap();
-                    let high_value = values.iter().max().unwrap();
-                    return Some((*low_value, *high_value));
-                }
+            if let Some(values) = bot_values.get(&bot_number)
+                && values.len() == 2
+            {
+                let low_value = values.iter().min().unwrap();
+                let high_value = values.iter().max().unwrap();
+                return Some((*low_value, *high_value));
             }
             None
         }
@@ -202,12 +205,12 @@ impl Solver {
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
-        self.solve(RequiredResult::BotValue)
+    fn solve_part_one(&self) -> Answer {
+        self.solve(RequiredResult::BotValue).into()
     }
 
-    fn solve_part_two(&self) -> String {
-        self.solve(RequiredResult::ValueFactor)
+    fn solve_part_two(&self) -> Answer {
+        self.solve(RequiredResult::ValueFactor).into()
     }
 
     fn day_number(&self) -> usize {
@@ -235,6 +238,7 @@ value 2 goes to bot 2",
             2,
             5,
         )
+        .unwrap()
         .solve_part_one();
         assert_eq!(result, "2");
     }
@@ -246,7 +250,7 @@ value 2 goes to bot 2",
 
 //     #[test]
 //     fn test_1() {
-//         let result = Solver::new("abc").solve_part_two();
+//         let result = Solver::new("abc").unwrap().solve_part_two();
 //         assert_eq!(result, "0");
 //     }
 // }
@@ -1,11 +1,12 @@
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::{HashMap, HashSet};
 
-use solver::SolverBase;
+use regex::Regex;
+use solver::{Answer, ParseError, SolverBase, bfs};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 enum Item {
-    Microchip(&'static str),
-    Generator(&'static str),
+    Microchip(String),
+    Generator(String),
 }
 
 impl std::fmt::Display for Item {
@@ -109,20 +110,23 @@ impl State {
     fn is_valid(&self) -> bool {
         let mut floor_items: HashMap<FloorIndex, Vec<Item>> = HashMap::new();
         for (item, floor_index) in &self.item_floors {
-            floor_items.entry(*floor_index).or_default().push(*item);
+            floor_items
+                .entry(*floor_index)
+                .or_default()
+                .push(item.clone());
         }
         for items in floor_items.values() {
             let microchips: HashSet<&str> = items
                 .iter()
                 .filter_map(|item| match item {
-                    Item::Microchip(name) => Some(*name),
+                    Item::Microchip(name) => Some(name.as_str()),
                     _ => None,
                 })
                 .collect();
             let generators: HashSet<&str> = items
                 .iter()
                 .filter_map(|item| match item {
-                    Item::Generator(name) => Some(*name),
+                    Item::Generator(name) => Some(name.as_str()),
                     _ => None,
                 })
                 .collect();
@@ -135,26 +139,6 @@ impl State {
         }
         true
     }
-
-    fn print(&self, floor_count: usize) {
-        for floor_index in (0..floor_count).rev() {
-            print!("F{} ", floor_index + 1);
-            if self.elevator_floor == floor_index {
-                print!("E ");
-            } else {
-                print!(". ");
-            }
-            for (item, item_floor) in &self.item_floors {
-                if *item_floor == floor_index {
-                    print!("{} ", item);
-                } else {
-                    print!("... ");
-                }
-            }
-            println!();
-        }
-        println!();
-    }
 }
 
 pub struct Solver {
@@ -174,55 +158,36 @@ impl Solver {
         }
     }
 
-    pub fn new_from_input() -> Self {
-        Solver {
+    pub fn new_from_input(input: &str) -> Result<Self, ParseError> {
+        let re = Regex::new(r"(\w+)(?:-compatible)? (microchip|generator)").unwrap();
+        let mut item_floors = Vec::new();
+        for (floor_index, line) in input.lines().enumerate() {
+            for caps in re.captures_iter(line) {
+                let name = caps[1].to_owned();
+                let item = match &caps[2] {
+                    "microchip" => Item::Microchip(name),
+                    _ => Item::Generator(name),
+                };
+                item_floors.push((item, floor_index));
+            }
+        }
+        Ok(Solver {
             init_state: State {
                 elevator_floor: 0,
-                item_floors: vec![
-                    (Item::Generator("polonium"), 0),
-                    (Item::Generator("thulium"), 0),
-                    (Item::Microchip("thulium"), 0),
-                    (Item::Generator("promethium"), 0),
-                    (Item::Generator("ruthenium"), 0),
-                    (Item::Microchip("ruthenium"), 0),
-                    (Item::Generator("cobalt"), 0),
-                    (Item::Microchip("cobalt"), 0),
-                    (Item::Microchip("polonium"), 1),
-                    (Item::Microchip("promethium"), 1),
-                ],
+                item_floors,
             },
-            floor_count: 4,
-        }
+            floor_count: input.lines().count(),
+        })
     }
 
-    fn bfs(init_state: State, end_state: State, floor_count: usize) -> Vec<State> {
-        let mut states: VecDeque<State> = VecDeque::new();
-        let mut visited_states: HashSet<State> = HashSet::new();
-        let mut parent_states: HashMap<State, State> = HashMap::new();
-        states.push_back(init_state.clone());
-        while let Some(state) = states.pop_front() {
-            visited_states.insert(state.clone());
-
-            if state == end_state {
-                let mut path = Vec::new();
-                path.push(state.clone());
-                let mut step_tracker = state;
-                while let Some(parent) = parent_states.get(&step_tracker) {
-                    path.push(parent.clone());
-                    step_tracker = parent.clone();
-                }
-                path.reverse();
-                return path;
-            }
-
-            for next_state in state.generate_next_valid_states(floor_count) {
-                if !visited_states.contains(&next_state) {
-                    states.push_back(next_state.clone());
-                    parent_states.insert(next_state, state.clone());
-                }
-            }
-        }
-        vec![init_state]
+    /// None when the items cannot all be brought up to `end_state`
+    fn bfs(init_state: State, end_state: State, floor_count: usize) -> Option<Vec<State>> {
+        bfs(
+            init_state,
+            |state| state.generate_next_valid_states(floor_count),
+            |state| *state == end_state,
+        )
+        .path()
     }
 
     fn get_final_state(&self) -> State {
@@ -233,36 +198,28 @@ impl Solver {
                 .init_state
                 .item_floors
                 .iter()
-                .map(|(item, _floor_index)| (*item, last_floor))
+                .map(|(item, _floor_index)| (item.clone(), last_floor))
                 .collect(),
         }
     }
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
-        // println!("Initial state:");
-        // self.init_state.print(self.floor_count);
-        // println!("Final state:");
-        // self.get_final_state().print(self.floor_count);
-        // println!("Floor count: {}", self.floor_count);
-
+    fn solve_part_one(&self) -> Answer {
         let path = Solver::bfs(
             self.init_state.clone(),
             self.get_final_state(),
             self.floor_count,
         );
 
-        for state in &path {
-            state.print(self.floor_count);
-            println!();
+        match path {
+            Some(path) => (path.len() - 1).into(),
+            None => Answer::NoSolution,
         }
-
-        (path.len() - 1).to_string()
     }
 
-    fn solve_part_two(&self) -> String {
-        "".to_string()
+    fn solve_part_two(&self) -> Answer {
+        Answer::Unsolved
     }
 
     fn day_number(&self) -> usize {
@@ -287,10 +244,10 @@ mod part1_tests {
         let result = Solver::new(
             0,
             vec![
-                (Item::Generator("hydrogen"), 1),
-                (Item::Microchip("hydrogen"), 0),
-                (Item::Generator("lithium"), 2),
-                (Item::Microchip("lithium"), 0),
+                (Item::Generator("hydrogen".to_owned()), 1),
+                (Item::Microchip("hydrogen".to_owned()), 0),
+                (Item::Generator("lithium".to_owned()), 2),
+                (Item::Microchip("lithium".to_owned()), 0),
             ],
             4,
         )
@@ -298,15 +255,53 @@ mod part1_tests {
         assert_eq!(result, "11");
     }
 
+    #[test]
+    fn test_no_path() {
+        // The microchip cannot join the other generator on the top floor without its own
+        let result = Solver::new(
+            0,
+            vec![
+                (Item::Microchip("hydrogen".to_owned()), 0),
+                (Item::Generator("lithium".to_owned()), 0),
+            ],
+            2,
+        )
+        .solve_part_one();
+        assert_eq!(result, Answer::NoSolution);
+    }
+
+    #[test]
+    fn test_new_from_input() {
+        let solver = Solver::new_from_input(
+            r"The first floor contains a hydrogen-compatible microchip and a lithium-compatible microchip.
+The second floor contains a hydrogen generator.
+The third floor contains a lithium generator.
+The fourth floor contains nothing relevant.",
+        ).unwrap();
+        assert_eq!(solver.floor_count, 4);
+        assert_eq!(
+            solver.init_state,
+            State {
+                elevator_floor: 0,
+                item_floors: vec![
+                    (Item::Microchip("hydrogen".to_owned()), 0),
+                    (Item::Microchip("lithium".to_owned()), 0),
+                    (Item::Generator("hydrogen".to_owned()), 1),
+                    (Item::Generator("lithium".to_owned()), 2),
+                ]
+            }
+        );
+    }
+
     #[test]
     fn test_final_state_1() {
         let result = Solver::new(
             0,
             vec![
-                (Item::Microchip("hydrogen"), 0),
-                (Item::Microchip("lithium"), 0),
-                (Item::Generator("hydrogen"), 1),
-                (Item::Generator("lithium"), 2),
+                (Item::Microchip("hydrogen".to_owned()), 0),
+                (Item::Microchip("lithium".to_owned()), 0),
+                (Item::Generator("hydrogen".to_owned()), 1),
+                (Item::Generator("lithium".to_owned()), 2),
             ],
             4,
         )
@@ -316,10 +311,10 @@ mod part1_tests {
             State {
                 elevator_floor: 3,
                 item_floors: vec![
-                    (Item::Microchip("hydrogen"), 3),
-                    (Item::Microchip("lithium"), 3),
-                    (Item::Generator("hydrogen"), 3),
-                    (Item::Generator("lithium"), 3),
+                    (Item::Microchip("hydrogen".to_owned()), 3),
+                    (Item::Microchip("lithium".to_owned()), 3),
+                    (Item::Generator("hydrogen".to_owned()), 3),
+                    (Item::Generator("lithium".to_owned()), 3),
                 ]
             }
         );
@@ -330,16 +325,16 @@ mod part1_tests {
         let result = Solver::new(
             0,
             vec![
-                (Item::Microchip("hydrogen"), 0),
-                (Item::Microchip("lithium"), 0),
-                (Item::Generator("hydrogen"), 1),
-                (Item::Generator("lithium"), 2),
+                (Item::Microchip("hydrogen".to_owned()), 0),
+                (Item::Microchip("lithium".to_owned()), 0),
+                (Item::Generator("hydrogen".to_owned()), 1),
+                (Item::Generator("lithium".to_owned()), 2),
             ],
             4,
         )
         .init_state
         .is_valid();
-        assert_eq!(result, true);
+        assert!(result);
     }
 
     #[test]
@@ -347,16 +342,16 @@ mod part1_tests {
         let result = Solver::new(
             0,
             vec![
-                (Item::Microchip("hydrogen"), 1),
-                (Item::Microchip("lithium"), 1),
-                (Item::Generator("hydrogen"), 1),
-                (Item::Generator("lithium"), 1),
+                (Item::Microchip("hydrogen".to_owned()), 1),
+                (Item::Microchip("lithium".to_owned()), 1),
+                (Item::Generator("hydrogen".to_owned()), 1),
+                (Item::Generator("lithium".to_owned()), 1),
             ],
             4,
         )
         .init_state
         .is_valid();
-        assert_eq!(result, true);
+        assert!(result);
     }
 
     #[test]
@@ -364,16 +359,16 @@ mod part1_tests {
         let result = Solver::new(
             0,
             vec![
-                (Item::Microchip("hydrogen"), 1),
-                (Item::Microchip("lithium"), 0),
-                (Item::Generator("hydrogen"), 1),
-                (Item::Generator("lithium"), 1),
+                (Item::Microchip("hydrogen".to_owned()), 1),
+                (Item::Microchip("lithium".to_owned()), 0),
+                (Item::Generator("hydrogen".to_owned()), 1),
+                (Item::Generator("lithium".to_owned()), 1),
             ],
             4,
         )
         .init_state
         .is_valid();
-        assert_eq!(result, true);
+        assert!(result);
     }
 
     #[test]
@@ -381,10 +376,10 @@ mod part1_tests {
         let result = State {
             elevator_floor: 0,
             item_floors: vec![
-                (Item::Microchip("hydrogen"), 0),
-                (Item::Microchip("lithium"), 0),
-                (Item::Generator("hydrogen"), 1),
-                (Item::Generator("lithium"), 2),
+                (Item::Microchip("hydrogen".to_owned()), 0),
+                (Item::Microchip("lithium".to_owned()), 0),
+                (Item::Generator("hydrogen".to_owned()), 1),
+                (Item::Generator("lithium".to_owned()), 2),
             ],
         }
         .generate_next_valid_states(4);
@@ -394,10 +389,10 @@ mod part1_tests {
             vec![State {
                 elevator_floor: 1,
                 item_floors: vec![
-                    (Item::Microchip("hydrogen"), 1),
-                    (Item::Microchip("lithium"), 0),
-                    (Item::Generator("hydrogen"), 1),
-                    (Item::Generator("lithium"), 2),
+                    (Item::Microchip("hydrogen".to_owned()), 1),
+                    (Item::Microchip("lithium".to_owned()), 0),
+                    (Item::Generator("hydrogen".to_owned()), 1),
+                    (Item::Generator("lithium".to_owned()), 2),
                 ]
             }]
         );
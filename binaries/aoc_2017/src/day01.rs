@@ -1,17 +1,23 @@
-use solver::SolverBase;
+use solver::{Answer, ParseError, SolverBase};
 
 pub struct Solver {
     input: Vec<u8>,
 }
 
 impl Solver {
-    pub fn new(input: &'static str) -> Self {
-        Solver {
-            input: input
-                .chars()
-                .map(|c| c.to_digit(10).unwrap() as u8)
-                .collect(),
-        }
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let digits = input
+            .chars()
+            .enumerate()
+            .map(|(index, c)| {
+                c.to_digit(10).map(|digit| digit as u8).ok_or_else(|| {
+                    ParseError::expected("a digit", &c.to_string())
+                        .at_column(index + 1)
+                        .on_line(1, input)
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Solver { input: digits })
     }
 
     fn get_next_index(&self, index: usize, offset: usize) -> usize {
@@ -36,14 +42,14 @@ impl Solver {
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
+    fn solve_part_one(&self) -> Answer {
         let sum = self.solve(1);
-        sum.to_string()
+        sum.into()
     }
 
-    fn solve_part_two(&self) -> String {
+    fn solve_part_two(&self) -> Answer {
         let sum = self.solve(self.input.len() / 2);
-        sum.to_string()
+        sum.into()
     }
 
     fn day_number(&self) -> usize {
@@ -61,25 +67,25 @@ mod part1_tests {
 
     #[test]
     fn test_1() {
-        let result = Solver::new("1122").solve_part_one();
+        let result = Solver::new("1122").unwrap().solve_part_one();
         assert_eq!(result, "3");
     }
 
     #[test]
     fn test_2() {
-        let result = Solver::new("1111").solve_part_one();
+        let result = Solver::new("1111").unwrap().solve_part_one();
         assert_eq!(result, "4");
     }
 
     #[test]
     fn test_3() {
-        let result = Solver::new("1234").solve_part_one();
+        let result = Solver::new("1234").unwrap().solve_part_one();
         assert_eq!(result, "0");
     }
 
     #[test]
     fn test_4() {
-        let result = Solver::new("91212129").solve_part_one();
+        let result = Solver::new("91212129").unwrap().solve_part_one();
         assert_eq!(result, "9");
     }
 }
@@ -90,31 +96,31 @@ mod part2_tests {
 
     #[test]
     fn test_1() {
-        let result = Solver::new("1212").solve_part_two();
+        let result = Solver::new("1212").unwrap().solve_part_two();
         assert_eq!(result, "6");
     }
 
     #[test]
     fn test_2() {
-        let result = Solver::new("1221").solve_part_two();
+        let result = Solver::new("1221").unwrap().solve_part_two();
         assert_eq!(result, "0");
     }
 
     #[test]
     fn test_3() {
-        let result = Solver::new("123425").solve_part_two();
+        let result = Solver::new("123425").unwrap().solve_part_two();
         assert_eq!(result, "4");
     }
 
     #[test]
     fn test_4() {
-        let result = Solver::new("123123").solve_part_two();
+        let result = Solver::new("123123").unwrap().solve_part_two();
         assert_eq!(result, "12");
     }
 
     #[test]
     fn test_5() {
-        let result = Solver::new("12131415").solve_part_two();
+        let result = Solver::new("12131415").unwrap().solve_part_two();
         assert_eq!(result, "4");
     }
 }
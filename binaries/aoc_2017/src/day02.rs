@@ -1,21 +1,16 @@
 use itertools::Itertools;
-use solver::SolverBase;
+use solver::{Answer, ParseError, SolverBase, parse_field, parse_lines};
 
 pub struct Solver {
     spreadsheet: Vec<Vec<i32>>,
 }
 
 impl Solver {
-    pub fn new(input: &'static str) -> Self {
-        let spreadsheet = input
-            .lines()
-            .map(|line| {
-                line.split_whitespace()
-                    .map(|token| token.parse().unwrap())
-                    .collect()
-            })
-            .collect();
-        Solver { spreadsheet }
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let spreadsheet = parse_lines(input, |line| {
+            line.split_whitespace().map(parse_field).collect()
+        })?;
+        Ok(Solver { spreadsheet })
     }
 
     fn get_even_division(numbers: &[i32]) -> Option<i32> {
@@ -32,7 +27,7 @@ impl Solver {
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
+    fn solve_part_one(&self) -> Answer {
         let mut checksum = 0;
         for line in self.spreadsheet.iter() {
             let minmax = line.iter().minmax();
@@ -40,16 +35,16 @@ impl SolverBase for Solver {
             let diff = max - min;
             checksum += diff;
         }
-        checksum.to_string()
+        checksum.into()
     }
 
-    fn solve_part_two(&self) -> String {
+    fn solve_part_two(&self) -> Answer {
         let mut checksum = 0;
         for line in self.spreadsheet.iter() {
             let div_result = Solver::get_even_division(line).unwrap();
             checksum += div_result;
         }
-        checksum.to_string()
+        checksum.into()
     }
 
     fn day_number(&self) -> usize {
@@ -72,6 +67,7 @@ mod part1_tests {
 7 5 3
 2 4 6 8",
         )
+        .unwrap()
         .solve_part_one();
         assert_eq!(result, "18");
     }
@@ -88,6 +84,7 @@ mod part2_tests {
 9 4 7 3
 3 8 6 5",
         )
+        .unwrap()
         .solve_part_two();
         assert_eq!(result, "9");
     }
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use solver::SolverBase;
+use solver::{Answer, ParseError, SolverBase, parse_field};
 
 pub struct Solver {
     square_number: i32,
@@ -14,10 +14,10 @@ enum Direction {
 }
 
 impl Solver {
-    pub fn new(input: &'static str) -> Self {
-        Solver {
-            square_number: input.parse().unwrap(),
-        }
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        Ok(Solver {
+            square_number: parse_field(input).map_err(|error| error.on_line(1, input))?,
+        })
     }
 
     fn get_coordinates(square_number: i32) -> (i32, i32) {
@@ -144,15 +144,15 @@ impl Solver {
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
+    fn solve_part_one(&self) -> Answer {
         let (x, y) = Solver::get_coordinates(self.square_number);
         let manhattan_distance = x.abs() + y.abs();
-        manhattan_distance.to_string()
+        manhattan_distance.into()
     }
 
-    fn solve_part_two(&self) -> String {
+    fn solve_part_two(&self) -> Answer {
         let value = Solver::get_first_value_larger_than(self.square_number);
-        value.to_string()
+        value.into()
     }
 
     fn day_number(&self) -> usize {
@@ -170,25 +170,25 @@ mod part1_tests {
 
     #[test]
     fn test_1() {
-        let result = Solver::new("1").solve_part_one();
+        let result = Solver::new("1").unwrap().solve_part_one();
         assert_eq!(result, "0");
     }
 
     #[test]
     fn test_2() {
-        let result = Solver::new("12").solve_part_one();
+        let result = Solver::new("12").unwrap().solve_part_one();
         assert_eq!(result, "3");
     }
 
     #[test]
     fn test_3() {
-        let result = Solver::new("23").solve_part_one();
+        let result = Solver::new("23").unwrap().solve_part_one();
         assert_eq!(result, "2");
     }
 
     #[test]
     fn test_4() {
-        let result = Solver::new("1024").solve_part_one();
+        let result = Solver::new("1024").unwrap().solve_part_one();
         assert_eq!(result, "31");
     }
 }
@@ -1,19 +1,21 @@
 use std::collections::HashSet;
 
-use solver::SolverBase;
+use solver::{Answer, ParseError, SolverBase};
 
 pub struct Solver {
-    input: &'static str,
+    input: String,
 }
 
 impl Solver {
-    pub fn new(input: &'static str) -> Self {
-        Solver { input }
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        Ok(Solver {
+            input: input.to_owned(),
+        })
     }
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
+    fn solve_part_one(&self) -> Answer {
         let mut counter = 0;
         for line in self.input.lines() {
             let mut dictionary = HashSet::new();
@@ -28,10 +30,10 @@ impl SolverBase for Solver {
                 counter += 1;
             }
         }
-        counter.to_string()
+        counter.into()
     }
 
-    fn solve_part_two(&self) -> String {
+    fn solve_part_two(&self) -> Answer {
         let mut counter = 0;
         for line in self.input.lines() {
             let mut dictionary = HashSet::new();
@@ -49,7 +51,7 @@ impl SolverBase for Solver {
                 counter += 1;
             }
         }
-        counter.to_string()
+        counter.into()
     }
 
     fn day_number(&self) -> usize {
@@ -67,25 +69,25 @@ mod part1_tests {
 
     #[test]
     fn test_1() {
-        let result = Solver::new("aa bb cc dd ee").solve_part_one();
+        let result = Solver::new("aa bb cc dd ee").unwrap().solve_part_one();
         assert_eq!(result, "1");
     }
 
     #[test]
     fn test_2() {
-        let result = Solver::new("aa bb cc dd aa").solve_part_one();
+        let result = Solver::new("aa bb cc dd aa").unwrap().solve_part_one();
         assert_eq!(result, "0");
     }
 
     #[test]
     fn test_3() {
-        let result = Solver::new("aa bb cc dd ee").solve_part_one();
+        let result = Solver::new("aa bb cc dd ee").unwrap().solve_part_one();
         assert_eq!(result, "1");
     }
 
     #[test]
     fn test_4() {
-        let result = Solver::new("aa bb cc dd aaa").solve_part_one();
+        let result = Solver::new("aa bb cc dd aaa").unwrap().solve_part_one();
         assert_eq!(result, "1");
     }
 }
@@ -96,31 +98,35 @@ mod part2_tests {
 
     #[test]
     fn test_1() {
-        let result = Solver::new("abcde fghij").solve_part_two();
+        let result = Solver::new("abcde fghij").unwrap().solve_part_two();
         assert_eq!(result, "1");
     }
 
     #[test]
     fn test_2() {
-        let result = Solver::new("abcde xyz ecdab").solve_part_two();
+        let result = Solver::new("abcde xyz ecdab").unwrap().solve_part_two();
         assert_eq!(result, "0");
     }
 
     #[test]
     fn test_3() {
-        let result = Solver::new("a ab abc abd abf abj").solve_part_two();
+        let result = Solver::new("a ab abc abd abf abj")
+            .unwrap()
+            .solve_part_two();
         assert_eq!(result, "1");
     }
 
     #[test]
     fn test_4() {
-        let result = Solver::new("iiii oiii ooii oooi oooo").solve_part_two();
+        let result = Solver::new("iiii oiii ooii oooi oooo")
+            .unwrap()
+            .solve_part_two();
         assert_eq!(result, "1");
     }
 
     #[test]
     fn test_5() {
-        let result = Solver::new("oiii ioii iioi iiio").solve_part_two();
+        let result = Solver::new("oiii ioii iioi iiio").unwrap().solve_part_two();
         assert_eq!(result, "0");
     }
 }
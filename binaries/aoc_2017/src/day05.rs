@@ -1,14 +1,14 @@
-use solver::SolverBase;
+use solver::{Answer, ParseError, SolverBase, parse_field, parse_lines};
 
 pub struct Solver {
     jump_offsets: Vec<i32>,
 }
 
 impl Solver {
-    pub fn new(input: &'static str) -> Self {
-        Solver {
-            jump_offsets: input.lines().map(|line| line.parse().unwrap()).collect(),
-        }
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        Ok(Solver {
+            jump_offsets: parse_lines(input, parse_field)?,
+        })
     }
 
     fn escape_from_maze(&self, offset_updater: impl Fn(i32) -> i32) -> u32 {
@@ -30,13 +30,13 @@ impl Solver {
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
-        self.escape_from_maze(|x| x + 1).to_string()
+    fn solve_part_one(&self) -> Answer {
+        self.escape_from_maze(|x| x + 1).into()
     }
 
-    fn solve_part_two(&self) -> String {
+    fn solve_part_two(&self) -> Answer {
         self.escape_from_maze(|x| if x >= 3 { x - 1 } else { x + 1 })
-            .to_string()
+            .into()
     }
 
     fn day_number(&self) -> usize {
@@ -61,6 +61,7 @@ mod part1_tests {
 1
 -3",
         )
+        .unwrap()
         .solve_part_one();
         assert_eq!(result, "5");
     }
@@ -79,6 +80,7 @@ mod part2_tests {
 1
 -3",
         )
+        .unwrap()
         .solve_part_two();
         assert_eq!(result, "10");
     }
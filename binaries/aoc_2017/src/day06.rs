@@ -1,50 +1,46 @@
-use solver::SolverBase;
-use std::collections::HashMap;
+use solver::{Answer, ParseError, SolverBase, find_cycle, parse_field};
 
 pub struct Solver {
     memory_banks: Vec<u32>,
 }
 
 impl Solver {
-    pub fn new(input: &'static str) -> Self {
-        Solver {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        Ok(Solver {
             memory_banks: input
                 .split_whitespace()
-                .map(|word| word.parse().unwrap())
-                .collect(),
-        }
+                .map(parse_field)
+                .collect::<Result<_, _>>()
+                .map_err(|error| error.on_line(1, input))?,
+        })
     }
 
-    fn get_cycle_start_and_end(&self) -> (u32, u32) {
-        let mut memory = self.memory_banks.clone();
-        let mut states = HashMap::new();
-        let mut cycle_counter = 0;
-        while !states.contains_key(&memory) {
-            //println!("{:?}", memory);
-            states.insert(memory.clone(), cycle_counter);
-            let max_value = *memory.iter().max().unwrap();
-            let max_index = memory.iter().position(|x| *x == max_value).unwrap();
-            memory[max_index] = 0;
-            for i in 0..max_value {
-                let index = (max_index + 1 + i as usize) % memory.len();
-                memory[index] += 1;
-            }
-            cycle_counter += 1;
+    fn redistribute(memory: &[u32]) -> Vec<u32> {
+        let mut memory = memory.to_vec();
+        let max_value = *memory.iter().max().unwrap();
+        let max_index = memory.iter().position(|x| *x == max_value).unwrap();
+        memory[max_index] = 0;
+        for i in 0..max_value {
+            let index = (max_index + 1 + i as usize) % memory.len();
+            memory[index] += 1;
         }
-        (*states.get(&memory).unwrap(), cycle_counter)
+        memory
     }
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
-        let (_, cycle_end) = self.get_cycle_start_and_end();
-        cycle_end.to_string()
+    fn solve_part_one(&self) -> Answer {
+        let cycle = find_cycle(self.memory_banks.clone(), |memory| {
+            Solver::redistribute(memory)
+        });
+        (cycle.start + cycle.length).into()
     }
 
-    fn solve_part_two(&self) -> String {
-        let (cycle_start, cycle_end) = self.get_cycle_start_and_end();
-        let cycle_size = cycle_end - cycle_start;
-        cycle_size.to_string()
+    fn solve_part_two(&self) -> Answer {
+        let cycle = find_cycle(self.memory_banks.clone(), |memory| {
+            Solver::redistribute(memory)
+        });
+        cycle.length.into()
     }
 
     fn day_number(&self) -> usize {
@@ -62,7 +58,7 @@ mod part1_tests {
 
     #[test]
     fn test_1() {
-        let result = Solver::new("0 2 7 0").solve_part_one();
+        let result = Solver::new("0 2 7 0").unwrap().solve_part_one();
         assert_eq!(result, "5");
     }
 }
@@ -73,7 +69,7 @@ mod part2_tests {
 
     #[test]
     fn test_1() {
-        let result = Solver::new("0 2 7 0").solve_part_two();
+        let result = Solver::new("0 2 7 0").unwrap().solve_part_two();
         assert_eq!(result, "4");
     }
 }
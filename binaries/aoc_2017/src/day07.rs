@@ -5,13 +5,13 @@ use std::{
 };
 
 use regex::Regex;
-use solver::SolverBase;
+use solver::{Answer, ParseError, SolverBase, parse_field, parse_lines};
 
 type NodeRef = Rc<RefCell<Node>>;
 type WeakNodeRef = Weak<RefCell<Node>>; // Weak reference to avoid cycles
 
 struct Node {
-    name: &'static str,
+    name: String,
     #[allow(dead_code)]
     weight: i32,
     parent: Option<WeakNodeRef>, // Weak reference to parent
@@ -19,7 +19,7 @@ struct Node {
 }
 
 impl Node {
-    fn new(name: &'static str, weight: i32) -> NodeRef {
+    fn new(name: String, weight: i32) -> NodeRef {
         Rc::new(RefCell::new(Node {
             name,
             weight,
@@ -41,9 +41,9 @@ impl Node {
 
 #[derive(Debug)]
 struct TowerInfo {
-    name: &'static str,
+    name: String,
     weight: i32,
-    children: Vec<&'static str>,
+    children: Vec<String>,
 }
 
 struct BalanceSum(i32);
@@ -54,39 +54,37 @@ pub struct Solver {
 }
 
 impl Solver {
-    pub fn new(input: &'static str) -> Self {
-        let re =
-            Regex::new(r"(?P<name>\w+) \((?P<weight>\d+)\)(?: -> (?P<children>[\w, ]+))?").unwrap();
-        let mut tower_info = Vec::new();
-        for line in input.lines() {
-            if let Some(caps) = re.captures(line) {
-                let name = caps.name("name").unwrap().as_str();
-                let weight: i32 = caps.name("weight").unwrap().as_str().parse().unwrap();
-                let children = caps
-                    .name("children")
-                    .map(|m| m.as_str().split(", ").collect())
-                    .unwrap_or_else(Vec::new);
-
-                tower_info.push(TowerInfo {
-                    name,
-                    weight,
-                    children,
-                });
-            }
-        }
-        Solver { input: tower_info }
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let re = Regex::new(r"^(?P<name>\w+) \((?P<weight>\d+)\)(?: -> (?P<children>[\w, ]+))?$")
+            .unwrap();
+        let tower_info = parse_lines(input, |line| {
+            let caps = re.captures(line).ok_or_else(|| {
+                ParseError::new("expected `<name> (<weight>)` with optional `-> <children>`")
+            })?;
+            let children = caps
+                .name("children")
+                .map(|m| m.as_str().split(", ").map(str::to_owned).collect())
+                .unwrap_or_else(Vec::new);
+
+            Ok(TowerInfo {
+                name: caps["name"].to_owned(),
+                weight: parse_field(&caps["weight"])?,
+                children,
+            })
+        })?;
+        Ok(Solver { input: tower_info })
     }
 
     fn build_tree(&self) -> NodeRef {
         let mut map = HashMap::new();
         for info in self.input.iter() {
-            let node = Node::new(info.name, info.weight);
-            map.insert(info.name, node);
+            let node = Node::new(info.name.clone(), info.weight);
+            map.insert(info.name.as_str(), node);
         }
         for info in self.input.iter() {
-            let parent = map.get(info.name).unwrap();
+            let parent = map.get(info.name.as_str()).unwrap();
             for child_name in info.children.iter() {
-                let child = map.get(child_name).unwrap();
+                let child = map.get(child_name.as_str()).unwrap();
                 Node::add_child(parent, child.clone());
             }
         }
@@ -142,15 +140,15 @@ impl Solver {
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
+    fn solve_part_one(&self) -> Answer {
         let root = self.build_tree();
-        root.borrow().name.to_owned()
+        root.borrow().name.as_str().into()
     }
 
-    fn solve_part_two(&self) -> String {
+    fn solve_part_two(&self) -> Answer {
         let root = self.build_tree();
         let BalanceError(_node_name, new_weight) = Solver::balance_tree(root).err().unwrap();
-        new_weight.to_string()
+        new_weight.into()
     }
 
     fn day_number(&self) -> usize {
@@ -183,6 +181,7 @@ ugml (68) -> gyxo, ebii, jptl
 gyxo (61)
 cntj (57)",
         )
+        .unwrap()
         .solve_part_one();
         assert_eq!(result, "tknk");
     }
@@ -208,7 +207,8 @@ jptl (61)
 ugml (68) -> gyxo, ebii, jptl
 gyxo (61)
 cntj (57)",
-        );
+        )
+        .unwrap();
         let root = solver.build_tree();
         let BalanceError(node, desired_weight) = Solver::balance_tree(root).err().unwrap();
         assert_eq!(node.borrow().name, "ugml");
@@ -1,150 +1,145 @@
-use std::collections::HashMap;
-
-use solver::SolverBase;
+use solver::{
+    Answer, Cpu, Machine, ParseError, RegisterNames, SolverBase, Step, parse_field, parse_lines,
+};
 
 pub struct Solver {
     instructions: Vec<Instruction>,
+    register_count: usize,
 }
 
+/// Adds `amount` to `register` when the condition holds, `dec` is parsed as a negative amount
 #[derive(Debug)]
-enum Instruction {
-    Increment(&'static str, i32, Condition),
-    Decrement(&'static str, i32, Condition),
+struct Instruction {
+    register: usize,
+    amount: i32,
+    condition: Condition,
 }
 
 #[derive(Debug)]
-enum Condition {
-    GreaterThan(&'static str, i32),
-    GreaterThanEqualTo(&'static str, i32),
-    LessThan(&'static str, i32),
-    LessThanEqualTo(&'static str, i32),
-    EqualTo(&'static str, i32),
-    NotEqualTo(&'static str, i32),
+struct Condition {
+    register: usize,
+    comparison: Comparison,
+    value: i32,
 }
 
-impl Solver {
-    pub fn new(input: &'static str) -> Self {
-        let mut instructions = Vec::new();
-
-        fn parse_condition(tokens: &[&'static str]) -> Condition {
-            match tokens {
-                [condition_register, ">", condition_value] => {
-                    Condition::GreaterThan(condition_register, condition_value.parse().unwrap())
-                }
-                [condition_register, ">=", condition_value] => Condition::GreaterThanEqualTo(
-                    condition_register,
-                    condition_value.parse().unwrap(),
-                ),
-                [condition_register, "<", condition_value] => {
-                    Condition::LessThan(condition_register, condition_value.parse().unwrap())
-                }
-                [condition_register, "<=", condition_value] => {
-                    Condition::LessThanEqualTo(condition_register, condition_value.parse().unwrap())
-                }
-                [condition_register, "==", condition_value] => {
-                    Condition::EqualTo(condition_register, condition_value.parse().unwrap())
-                }
-                [condition_register, "!=", condition_value] => {
-                    Condition::NotEqualTo(condition_register, condition_value.parse().unwrap())
-                }
-                _ => panic!("wrong format"),
-            }
-        }
+#[derive(Debug, Clone, Copy)]
+enum Comparison {
+    GreaterThan,
+    GreaterThanEqualTo,
+    LessThan,
+    LessThanEqualTo,
+    EqualTo,
+    NotEqualTo,
+}
 
-        for line in input.lines() {
-            let tokens: Vec<_> = line.split_whitespace().collect();
-            let instruction = match &tokens[0..3] {
-                [instruction_register, "inc", instruction_value] => Instruction::Increment(
-                    instruction_register,
-                    instruction_value.parse().unwrap(),
-                    parse_condition(&tokens[4..]),
-                ),
-                [instruction_register, "dec", instruction_value] => Instruction::Decrement(
-                    instruction_register,
-                    instruction_value.parse().unwrap(),
-                    parse_condition(&tokens[4..]),
-                ),
-                _ => panic!("wrong format"),
-            };
-            instructions.push(instruction);
+impl Condition {
+    fn holds(&self, registers: &[i32]) -> bool {
+        let register_value = registers[self.register];
+        match self.comparison {
+            Comparison::GreaterThan => register_value > self.value,
+            Comparison::GreaterThanEqualTo => register_value >= self.value,
+            Comparison::LessThan => register_value < self.value,
+            Comparison::LessThanEqualTo => register_value <= self.value,
+            Comparison::EqualTo => register_value == self.value,
+            Comparison::NotEqualTo => register_value != self.value,
         }
-        Solver { instructions }
     }
+}
+
+impl solver::Instruction for Instruction {
+    type Value = i32;
 
-    fn check_condition(condition: &Condition, registers: &HashMap<&'static str, i32>) -> bool {
-        match condition {
-            Condition::GreaterThan(register, condition_value) => {
-                let register_value = *registers.get(*register).unwrap_or(&0);
-                register_value > *condition_value
-            }
-            Condition::GreaterThanEqualTo(register, condition_value) => {
-                let register_value = *registers.get(*register).unwrap_or(&0);
-                register_value >= *condition_value
-            }
-            Condition::LessThan(register, condition_value) => {
-                let register_value = *registers.get(*register).unwrap_or(&0);
-                register_value < *condition_value
-            }
-            Condition::LessThanEqualTo(register, condition_value) => {
-                let register_value = *registers.get(*register).unwrap_or(&0);
-                register_value <= *condition_value
-            }
-            Condition::EqualTo(register, condition_value) => {
-                let register_value = *registers.get(*register).unwrap_or(&0);
-                register_value == *condition_value
-            }
-            Condition::NotEqualTo(register, condition_value) => {
-                let register_value = *registers.get(*register).unwrap_or(&0);
-                register_value != *condition_value
-            }
+    fn execute(&self, cpu: &mut Cpu<i32>) -> Step {
+        if self.condition.holds(&cpu.registers) {
+            cpu.registers[self.register] += self.amount;
         }
+        Step::Next
     }
+}
 
-    fn process_instruction(
-        instruction: &Instruction,
-        registers: &mut HashMap<&'static str, i32>,
-    ) -> Option<i32> {
-        match instruction {
-            Instruction::Increment(register, value, condition)
-                if Solver::check_condition(condition, registers) =>
-            {
-                let register = registers.entry(register).or_default();
-                *register += value;
-                Some(*register)
-            }
-            Instruction::Decrement(register, value, condition)
-                if Solver::check_condition(condition, registers) =>
-            {
-                let register = registers.entry(register).or_default();
-                *register -= value;
-                Some(*register)
-            }
-            _ => None,
+impl Solver {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        fn parse_condition(
+            tokens: &[&str],
+            names: &mut RegisterNames,
+        ) -> Result<Condition, ParseError> {
+            let [condition_register, operator, condition_value] = tokens else {
+                return Err(ParseError::new(
+                    "expected condition `<register> <operator> <value>`",
+                ));
+            };
+            let comparison = match *operator {
+                ">" => Comparison::GreaterThan,
+                ">=" => Comparison::GreaterThanEqualTo,
+                "<" => Comparison::LessThan,
+                "<=" => Comparison::LessThanEqualTo,
+                "==" => Comparison::EqualTo,
+                "!=" => Comparison::NotEqualTo,
+                _ => return Err(ParseError::expected("a comparison operator", operator)),
+            };
+            Ok(Condition {
+                register: names.register(condition_register),
+                comparison,
+                value: parse_field(condition_value)?,
+            })
         }
+
+        let mut names = RegisterNames::new();
+        let instructions = parse_lines(input, |line| {
+            let tokens: Vec<_> = line.split_whitespace().collect();
+            let [
+                instruction_register,
+                operation,
+                instruction_value,
+                "if",
+                condition @ ..,
+            ] = &tokens[..]
+            else {
+                return Err(ParseError::new(
+                    "expected `<register> <inc|dec> <value> if <condition>`",
+                ));
+            };
+            let value: i32 = parse_field(instruction_value)?;
+            let amount = match *operation {
+                "inc" => value,
+                "dec" => -value,
+                _ => return Err(ParseError::expected("`inc` or `dec`", operation)),
+            };
+            Ok(Instruction {
+                register: names.register(instruction_register),
+                amount,
+                condition: parse_condition(condition, &mut names)?,
+            })
+        })?;
+        Ok(Solver {
+            instructions,
+            register_count: names.len(),
+        })
+    }
+
+    fn machine(&self) -> Machine<'_, Instruction> {
+        Machine::new(&self.instructions, self.register_count)
     }
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
-        let mut registers = HashMap::new();
-        for instruction in self.instructions.iter() {
-            _ = Solver::process_instruction(instruction, &mut registers);
-        }
-        let max_value = registers.values().max().unwrap();
-        max_value.to_string()
+    fn solve_part_one(&self) -> Answer {
+        let mut machine = self.machine();
+        machine.run();
+        let max_value = machine.registers().iter().max().unwrap();
+        (*max_value).into()
     }
 
-    fn solve_part_two(&self) -> String {
-        let mut registers = HashMap::new();
+    fn solve_part_two(&self) -> Answer {
+        let mut machine = self.machine();
         let mut running_max = 0;
-        for instruction in self.instructions.iter() {
-            if let Some(register_value) = Solver::process_instruction(instruction, &mut registers) {
-                if register_value > running_max {
-                    running_max = register_value;
-                }
-            }
+        while machine.step().is_none() {
+            running_max = machine
+                .registers()
+                .iter()
+                .fold(running_max, |max, &value| max.max(value));
         }
-        running_max.to_string()
+        running_max.into()
     }
 
     fn day_number(&self) -> usize {
@@ -168,6 +163,7 @@ a inc 1 if b < 5
 c dec -10 if a >= 1
 c inc -20 if c == 10",
         )
+        .unwrap()
         .solve_part_one();
         assert_eq!(result, "1");
     }
@@ -185,6 +181,7 @@ a inc 1 if b < 5
 c dec -10 if a >= 1
 c inc -20 if c == 10",
         )
+        .unwrap()
         .solve_part_two();
         assert_eq!(result, "10");
     }
@@ -1,12 +1,14 @@
-use solver::SolverBase;
+use solver::{Answer, ParseError, SolverBase};
 
 pub struct Solver {
-    input: &'static str,
+    input: String,
 }
 
 impl Solver {
-    pub fn new(input: &'static str) -> Self {
-        Solver { input }
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        Ok(Solver {
+            input: input.to_owned(),
+        })
     }
 
     fn get_group_score(&self) -> (u32, u32) {
@@ -57,14 +59,14 @@ impl Solver {
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
+    fn solve_part_one(&self) -> Answer {
         let (score, _) = self.get_group_score();
-        score.to_string()
+        score.into()
     }
 
-    fn solve_part_two(&self) -> String {
+    fn solve_part_two(&self) -> Answer {
         let (_, garbage_counter) = self.get_group_score();
-        garbage_counter.to_string()
+        garbage_counter.into()
     }
 
     fn day_number(&self) -> usize {
@@ -82,49 +84,55 @@ mod part1_tests {
 
     #[test]
     fn test_1() {
-        let result = Solver::new("{}").solve_part_one();
+        let result = Solver::new("{}").unwrap().solve_part_one();
         assert_eq!(result, "1");
     }
 
     #[test]
     fn test_2() {
-        let result = Solver::new("{{{}}}").solve_part_one();
+        let result = Solver::new("{{{}}}").unwrap().solve_part_one();
         assert_eq!(result, "6");
     }
 
     #[test]
     fn test_3() {
-        let result = Solver::new("{{},{}}").solve_part_one();
+        let result = Solver::new("{{},{}}").unwrap().solve_part_one();
         assert_eq!(result, "5");
     }
 
     #[test]
     fn test_4() {
-        let result = Solver::new("{{{},{},{{}}}}").solve_part_one();
+        let result = Solver::new("{{{},{},{{}}}}").unwrap().solve_part_one();
         assert_eq!(result, "16");
     }
 
     #[test]
     fn test_5() {
-        let result = Solver::new("{<a>,<a>,<a>,<a>}").solve_part_one();
+        let result = Solver::new("{<a>,<a>,<a>,<a>}").unwrap().solve_part_one();
         assert_eq!(result, "1");
     }
 
     #[test]
     fn test_6() {
-        let result = Solver::new("{{<ab>},{<ab>},{<ab>},{<ab>}}").solve_part_one();
+        let result = Solver::new("{{<ab>},{<ab>},{<ab>},{<ab>}}")
+            .unwrap()
+            .solve_part_one();
         assert_eq!(result, "9");
     }
 
     #[test]
     fn test_7() {
-        let result = Solver::new("{{<!!>},{<!!>},{<!!>},{<!!>}}").solve_part_one();
+        let result = Solver::new("{{<!!>},{<!!>},{<!!>},{<!!>}}")
+            .unwrap()
+            .solve_part_one();
         assert_eq!(result, "9");
     }
 
     #[test]
     fn test_8() {
-        let result = Solver::new("{{<a!>},{<a!>},{<a!>},{<ab>}}").solve_part_one();
+        let result = Solver::new("{{<a!>},{<a!>},{<a!>},{<ab>}}")
+            .unwrap()
+            .solve_part_one();
         assert_eq!(result, "3");
     }
 }
@@ -135,43 +143,43 @@ mod part2_tests {
 
     #[test]
     fn test_1() {
-        let result = Solver::new("<>").solve_part_two();
+        let result = Solver::new("<>").unwrap().solve_part_two();
         assert_eq!(result, "0");
     }
 
     #[test]
     fn test_2() {
-        let result = Solver::new("<random characters>").solve_part_two();
+        let result = Solver::new("<random characters>").unwrap().solve_part_two();
         assert_eq!(result, "17");
     }
 
     #[test]
     fn test_3() {
-        let result = Solver::new("<<<<>").solve_part_two();
+        let result = Solver::new("<<<<>").unwrap().solve_part_two();
         assert_eq!(result, "3");
     }
 
     #[test]
     fn test_4() {
-        let result = Solver::new("<{!>}>").solve_part_two();
+        let result = Solver::new("<{!>}>").unwrap().solve_part_two();
         assert_eq!(result, "2");
     }
 
     #[test]
     fn test_5() {
-        let result = Solver::new("<!!>").solve_part_two();
+        let result = Solver::new("<!!>").unwrap().solve_part_two();
         assert_eq!(result, "0");
     }
 
     #[test]
     fn test_6() {
-        let result = Solver::new("<!!!>>").solve_part_two();
+        let result = Solver::new("<!!!>>").unwrap().solve_part_two();
         assert_eq!(result, "0");
     }
 
     #[test]
     fn test_7() {
-        let result = Solver::new(r#"<{o"i!a,<{i<a>"#).solve_part_two();
+        let result = Solver::new(r#"<{o"i!a,<{i<a>"#).unwrap().solve_part_two();
         assert_eq!(result, "10");
     }
 }
@@ -1,4 +1,4 @@
-use solver::SolverBase;
+use solver::{Answer, ParseError, SolverBase};
 
 pub struct Solver {
     directions: Vec<Direction>,
@@ -15,7 +15,7 @@ enum Direction {
 }
 
 impl Solver {
-    pub fn new(input: &'static str) -> Self {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
         let mut directions = Vec::new();
         for token in input.split(',') {
             let direction = match token {
@@ -25,11 +25,17 @@ impl Solver {
                 "s" => Direction::South,
                 "sw" => Direction::Southwest,
                 "nw" => Direction::Northwest,
-                _ => panic!("unsupported direction"),
+                _ => {
+                    return Err(ParseError::expected(
+                        "one of `n`, `ne`, `se`, `s`, `sw`, `nw`",
+                        token,
+                    )
+                    .on_line(1, input));
+                }
             };
             directions.push(direction);
         }
-        Solver { directions }
+        Ok(Solver { directions })
     }
 
     fn get_distance(dir_table: &mut [u32; 6]) -> u32 {
@@ -82,21 +88,20 @@ impl Solver {
             dir_table[other_index] -= min_value;
         }
 
-        let distance = dir_table.iter().sum::<u32>();
-        distance
+        dir_table.iter().sum::<u32>()
     }
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
+    fn solve_part_one(&self) -> Answer {
         let mut dir_table = [0u32; 6];
         for direction in self.directions.iter() {
             dir_table[*direction as usize] += 1;
         }
-        Solver::get_distance(&mut dir_table).to_string()
+        Solver::get_distance(&mut dir_table).into()
     }
 
-    fn solve_part_two(&self) -> String {
+    fn solve_part_two(&self) -> Answer {
         let mut dir_table = [0u32; 6];
         let mut max_distance = None;
         for direction in self.directions.iter() {
@@ -106,7 +111,7 @@ impl SolverBase for Solver {
                 max_distance = Some(distance);
             }
         }
-        max_distance.unwrap().to_string()
+        max_distance.unwrap().into()
     }
 
     fn day_number(&self) -> usize {
@@ -124,25 +129,25 @@ mod part1_tests {
 
     #[test]
     fn test_1() {
-        let result = Solver::new("ne,ne,ne").solve_part_one();
+        let result = Solver::new("ne,ne,ne").unwrap().solve_part_one();
         assert_eq!(result, "3");
     }
 
     #[test]
     fn test_2() {
-        let result = Solver::new("ne,ne,sw,sw").solve_part_one();
+        let result = Solver::new("ne,ne,sw,sw").unwrap().solve_part_one();
         assert_eq!(result, "0");
     }
 
     #[test]
     fn test_3() {
-        let result = Solver::new("ne,ne,s,s").solve_part_one();
+        let result = Solver::new("ne,ne,s,s").unwrap().solve_part_one();
         assert_eq!(result, "2");
     }
 
     #[test]
     fn test_4() {
-        let result = Solver::new("se,sw,se,sw,sw").solve_part_one();
+        let result = Solver::new("se,sw,se,sw,sw").unwrap().solve_part_one();
         assert_eq!(result, "3");
     }
 }
@@ -153,7 +158,7 @@ mod part2_tests {
 
     #[test]
     fn test_1() {
-        let result = Solver::new("ne,ne,sw,sw").solve_part_two();
+        let result = Solver::new("ne,ne,sw,sw").unwrap().solve_part_two();
         assert_eq!(result, "2");
     }
 }
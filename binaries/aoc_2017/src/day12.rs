@@ -1,65 +1,48 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::HashMap;
 
-use regex::Regex;
-use solver::SolverBase;
+use solver::{Answer, ParseError, SolverBase, UnionFind, parse_field, parse_lines};
 
 pub struct Solver {
     connections: HashMap<u32, Vec<u32>>,
 }
 
 impl Solver {
-    pub fn new(input: &'static str) -> Self {
-        let re = Regex::new(r"(\d+) <-> ([\d, ]+)").unwrap();
-        let mut connections: HashMap<u32, Vec<u32>> = HashMap::new();
-        for capture in re.captures_iter(input) {
-            let first = capture[1].parse::<u32>().unwrap();
-            let second = capture[2]
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let connections = parse_lines(input, |line| {
+            let (first, second) = line
+                .split_once(" <-> ")
+                .ok_or_else(|| ParseError::new("expected `<program> <-> <programs>`"))?;
+            let second = second
                 .split(", ")
-                .map(|s| s.parse::<u32>().unwrap())
-                .collect();
-            connections.insert(first, second);
-        }
-        Solver { connections }
+                .map(parse_field)
+                .collect::<Result<_, _>>()?;
+            Ok((parse_field(first)?, second))
+        })?
+        .into_iter()
+        .collect();
+        Ok(Solver { connections })
     }
 
-    fn get_set(&self, root: u32) -> HashSet<u32> {
-        let mut set = HashSet::new();
-        let mut current_set = vec![root];
-        while !current_set.is_empty() {
-            while let Some(current) = current_set.pop() {
-                if !set.contains(&current) {
-                    set.insert(current);
-                    for neighbor in self.connections.get(&current).unwrap().iter() {
-                        current_set.push(*neighbor);
-                    }
-                }
+    fn get_sets(&self) -> UnionFind<u32> {
+        let mut sets = UnionFind::with_capacity(self.connections.len());
+        for (&program, neighbors) in &self.connections {
+            sets.insert(program);
+            for &neighbor in neighbors {
+                sets.union(program, neighbor);
             }
         }
-        set
-    }
-
-    fn get_all_sets(&self) -> HashMap<u32, HashSet<u32>> {
-        let mut all_roots: HashSet<u32> = self.connections.keys().copied().collect();
-        let mut result = HashMap::new();
-        while !all_roots.is_empty() {
-            let root = *all_roots.iter().next().unwrap();
-            let set = self.get_set(root);
-            all_roots.retain(|x| !set.contains(x));
-            result.insert(root, set);
-        }
-        result
+        sets
     }
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
-        let set = self.get_set(0);
-        set.len().to_string()
+    fn solve_part_one(&self) -> Answer {
+        let size = self.get_sets().size(&0).unwrap_or(1);
+        size.into()
     }
 
-    fn solve_part_two(&self) -> String {
-        let all_sets = self.get_all_sets();
-        all_sets.len().to_string()
+    fn solve_part_two(&self) -> Answer {
+        self.get_sets().count().into()
     }
 
     fn day_number(&self) -> usize {
@@ -86,6 +69,7 @@ mod part1_tests {
 5 <-> 6
 6 <-> 4, 5",
         )
+        .unwrap()
         .solve_part_one();
         assert_eq!(result, "6");
     }
@@ -106,6 +90,7 @@ mod part2_tests {
 5 <-> 6
 6 <-> 4, 5",
         )
+        .unwrap()
         .solve_part_two();
         assert_eq!(result, "2");
     }
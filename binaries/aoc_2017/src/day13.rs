@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use solver::SolverBase;
+use solver::{Answer, ParseError, SolverBase, parse_field, parse_lines, smallest_avoiding};
 
 pub struct Solver {
     layers: Vec<Layer>,
@@ -13,15 +13,17 @@ struct Layer {
 }
 
 impl Solver {
-    pub fn new(input: &'static str) -> Self {
-        let mut layers = Vec::new();
-        for line in input.lines() {
-            let mut tokens = line.split(": ");
-            let depth = tokens.next().unwrap().parse().unwrap();
-            let range = tokens.next().unwrap().parse().unwrap();
-            layers.push(Layer { depth, range });
-        }
-        Solver { layers }
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let layers = parse_lines(input, |line| {
+            let (depth, range) = line
+                .split_once(": ")
+                .ok_or_else(|| ParseError::new("expected `<depth>: <range>`"))?;
+            Ok(Layer {
+                depth: parse_field(depth)?,
+                range: parse_field(range)?,
+            })
+        })?;
+        Ok(Solver { layers })
     }
 
     #[allow(dead_code)]
@@ -122,19 +124,26 @@ impl Solver {
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
+    fn solve_part_one(&self) -> Answer {
         let (_was_caught, severity) = self.send_packet(0, false);
-        severity.to_string()
+        severity.into()
     }
 
-    fn solve_part_two(&self) -> String {
-        for delay in 0..u32::MAX {
-            let (was_caught, _) = self.send_packet(delay, true);
-            if !was_caught {
-                return delay.to_string();
-            }
+    fn solve_part_two(&self) -> Answer {
+        // The scanner of a layer is at the top every 2 * (range - 1) picoseconds, so a packet delayed by t is caught
+        // there when (t + depth) is a multiple of that period
+        let excluded: Vec<_> = self
+            .layers
+            .iter()
+            .map(|layer| {
+                let period = (2 * (layer.range as u64).saturating_sub(1)).max(1);
+                ((period - layer.depth as u64 % period) % period, period)
+            })
+            .collect();
+        match smallest_avoiding(&excluded) {
+            Some(delay) => delay.into(),
+            None => Answer::NoSolution,
         }
-        panic!("no solution")
     }
 
     fn day_number(&self) -> usize {
@@ -144,10 +153,6 @@ impl SolverBase for Solver {
     fn description(&self) -> &'static str {
         "Packet scaners"
     }
-
-    fn skip_run(&self) -> bool {
-        true
-    }
 }
 
 #[cfg(test)]
@@ -162,6 +167,7 @@ mod part1_tests {
 4: 4
 6: 4",
         )
+        .unwrap()
         .solve_part_one();
         assert_eq!(result, "24");
     }
@@ -214,6 +220,7 @@ mod part2_tests {
 4: 4
 6: 4",
         )
+        .unwrap()
         .solve_part_two();
         assert_eq!(result, "10");
     }
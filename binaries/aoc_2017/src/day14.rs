@@ -1,35 +1,31 @@
-use solver::SolverBase;
-use std::{collections::HashSet, fmt::Write};
-
-use crate::day10;
+use solver::{Answer, ParseError, SolverBase, knot_hashes};
+use std::collections::HashSet;
 
 type Disk = [[bool; 128]; 128];
 
 pub struct Solver {
-    input: &'static str,
+    input: String,
 }
 
 impl Solver {
-    pub fn new(input: &'static str) -> Self {
-        Solver { input }
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        Ok(Solver {
+            input: input.to_owned(),
+        })
     }
 
-    fn get_disk(&self) -> Disk {
-        fn hex_to_binary(hex: &str, disk_partition: &mut [bool]) {
-            let ones_and_zeroes = hex.chars().fold(String::new(), |mut acc, c| {
-                _ = write!(&mut acc, "{:04b}", c.to_digit(16).unwrap());
-                acc
-            });
+    /// Knot hash of every row, each bit is a square which is used when set
+    fn row_hashes(&self) -> Vec<[u8; 16]> {
+        let keys: Vec<String> = (0..128).map(|i| format!("{}-{}", self.input, i)).collect();
+        knot_hashes(&keys)
+    }
 
-            for (i, c) in ones_and_zeroes.chars().enumerate() {
-                disk_partition[i] = c == '1';
-            }
-        }
+    fn get_disk(&self) -> Disk {
         let mut disk: Disk = [[false; 128]; 128];
-        for (i, row) in disk.iter_mut().enumerate() {
-            let hasher_input = format!("{}-{}", self.input, i);
-            let knot_hash = day10::Solver::new(hasher_input, 256).full_knot_hash();
-            hex_to_binary(&knot_hash, row);
+        for (row, hash) in disk.iter_mut().zip(self.row_hashes()) {
+            for (i, square) in row.iter_mut().enumerate() {
+                *square = hash[i / 8] >> (7 - i % 8) & 1 == 1;
+            }
         }
         disk
     }
@@ -68,45 +64,20 @@ impl Solver {
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
-        #[allow(dead_code)]
-        fn hex_to_binary(hex: &str) -> String {
-            // hex.chars()
-            //     .map(|c| format!("{:04b}", c.to_digit(16).unwrap()))
-            //     .collect()
-
-            hex.chars().fold(String::new(), |mut acc, c| {
-                _ = write!(&mut acc, "{:04b}", c.to_digit(16).unwrap());
-                acc
-            })
-        }
-
-        fn count_ones_in_hex(hex: &str) -> usize {
-            hex.chars()
-                .map(|c| c.to_digit(16).unwrap().count_ones() as usize)
-                .sum()
-        }
-
-        let mut count_of_used_squares = 0;
-
-        for i in 0..128 {
-            let hasher_input = format!("{}-{}", self.input, i);
-            //println!("{hasher_input}");
-            let knot_hash = day10::Solver::new(hasher_input, 256).full_knot_hash();
-            //println!("{knot_hash}");
-            //let binary_string = hex_to_binary(&knot_hash);
-            //println!("{binary_string}");
-            let sum = count_ones_in_hex(&knot_hash);
-            count_of_used_squares += sum;
-        }
-
-        count_of_used_squares.to_string()
+    fn solve_part_one(&self) -> Answer {
+        let count_of_used_squares: u32 = self
+            .row_hashes()
+            .iter()
+            .flatten()
+            .map(|byte| byte.count_ones())
+            .sum();
+        count_of_used_squares.into()
     }
 
-    fn solve_part_two(&self) -> String {
+    fn solve_part_two(&self) -> Answer {
         let disk = self.get_disk();
         let number_of_regions = Solver::get_number_of_regions(&disk);
-        number_of_regions.to_string()
+        number_of_regions.into()
     }
 
     fn day_number(&self) -> usize {
@@ -124,7 +95,7 @@ mod part1_tests {
 
     #[test]
     fn test_1() {
-        let result = Solver::new("flqrgnkx").solve_part_one();
+        let result = Solver::new("flqrgnkx").unwrap().solve_part_one();
         assert_eq!(result, "8108");
     }
 }
@@ -135,7 +106,7 @@ mod part2_tests {
 
     #[test]
     fn test_1() {
-        let result = Solver::new("flqrgnkx").solve_part_two();
+        let result = Solver::new("flqrgnkx").unwrap().solve_part_two();
         assert_eq!(result, "1242");
     }
 }
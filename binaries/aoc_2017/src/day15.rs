@@ -1,4 +1,4 @@
-use solver::SolverBase;
+use solver::{Answer, ParseError, SolverBase, parse_field, parse_lines};
 
 pub struct Solver {
     start_value_a: u64,
@@ -13,6 +13,22 @@ impl Solver {
         }
     }
 
+    pub fn new_from_input(input: &str) -> Result<Self, ParseError> {
+        // Generator A starts with 289
+        let start_values = parse_lines(input, |line| match line.rsplit_once(' ') {
+            Some((_, value)) => parse_field(value),
+            None => Err(ParseError::new(
+                "expected `Generator <name> starts with <value>`",
+            )),
+        })?;
+        match start_values[..] {
+            [start_value_a, start_value_b] => Ok(Solver::new(start_value_a, start_value_b)),
+            _ => Err(ParseError::new("expected two generators")),
+        }
+    }
+
+    // The generators could jump ahead with `mod_pow`, but the low 16 bits of every pair still have to be compared,
+    // so there is no shortcut over generating the values one by one
     fn get_matches(&self, count: usize) -> usize {
         let mut match_count = 0;
         let mut a = self.start_value_a;
@@ -42,7 +58,7 @@ impl Solver {
         let mut get_next_a = move || {
             loop {
                 a = (a * factor_a) % mod_n;
-                if a % 4 == 0 {
+                if a.is_multiple_of(4) {
                     break;
                 }
             }
@@ -51,7 +67,7 @@ impl Solver {
         let mut get_next_b = move || {
             loop {
                 b = (b * factor_b) % mod_n;
-                if b % 8 == 0 {
+                if b.is_multiple_of(8) {
                     break;
                 }
             }
@@ -73,14 +89,14 @@ impl Solver {
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
+    fn solve_part_one(&self) -> Answer {
         let match_count = self.get_matches(40_000_000);
-        match_count.to_string()
+        match_count.into()
     }
 
-    fn solve_part_two(&self) -> String {
+    fn solve_part_two(&self) -> Answer {
         let match_count = self.get_matches_2(5_000_000);
-        match_count.to_string()
+        match_count.into()
     }
 
     fn day_number(&self) -> usize {
@@ -101,6 +117,16 @@ mod part1_tests {
         let result = Solver::new(65, 8921).solve_part_one();
         assert_eq!(result, "588");
     }
+
+    #[test]
+    fn test_new_from_input() {
+        let solver = Solver::new_from_input(
+            r"Generator A starts with 65
+Generator B starts with 8921",
+        )
+        .unwrap();
+        assert_eq!((solver.start_value_a, solver.start_value_b), (65, 8921));
+    }
 }
 
 #[cfg(test)]
@@ -1,4 +1,4 @@
-use solver::SolverBase;
+use solver::{Answer, ParseError, SolverBase, fast_forward, parse_field};
 use std::str::FromStr;
 
 #[derive(Debug)]
@@ -9,48 +9,47 @@ enum DanceMove {
 }
 
 impl FromStr for DanceMove {
-    type Err = Box<dyn std::error::Error>;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match &s[0..1] {
-            "s" => Ok(DanceMove::Spin(s[1..].parse()?)),
-            "x" => {
-                let mut tokens = s[1..].split('/');
-                Ok(DanceMove::Exchange(
-                    tokens.next().unwrap().parse()?,
-                    tokens.next().unwrap().parse()?,
-                ))
+        let pair = || {
+            s[1..]
+                .split_once('/')
+                .ok_or_else(|| ParseError::expected("`<a>/<b>` after the move", s))
+        };
+        match s.get(0..1) {
+            Some("s") => Ok(DanceMove::Spin(parse_field(&s[1..])?)),
+            Some("x") => {
+                let (a, b) = pair()?;
+                Ok(DanceMove::Exchange(parse_field(a)?, parse_field(b)?))
             }
-            "p" => {
-                let mut tokens = s[1..].split('/');
-                Ok(DanceMove::Partner(
-                    tokens.next().unwrap().chars().next().unwrap(),
-                    tokens.next().unwrap().chars().next().unwrap(),
-                ))
+            Some("p") => {
+                let (a, b) = pair()?;
+                Ok(DanceMove::Partner(parse_field(a)?, parse_field(b)?))
             }
-            _ => Err("unrecognized dance move".into()),
+            _ => Err(ParseError::expected("`s`, `x` or `p` dance move", s)),
         }
     }
 }
 
 pub struct Solver {
-    init_state: &'static str,
+    init_state: String,
     dance_moves: Vec<DanceMove>,
 }
 
 impl Solver {
-    pub fn new(init_state: &'static str, dance_moves: &'static str) -> Self {
+    pub fn new(init_state: &str, dance_moves: &str) -> Result<Self, ParseError> {
         let dance_moves = dance_moves
             .split(',')
-            .map(|s| DanceMove::from_str(s).unwrap())
-            .collect();
-        Solver {
-            init_state,
+            .map(DanceMove::from_str)
+            .collect::<Result<_, _>>()
+            .map_err(|error| error.on_line(1, dance_moves))?;
+        Ok(Solver {
+            init_state: init_state.to_owned(),
             dance_moves,
-        }
+        })
     }
 
-    #[allow(dead_code)]
     fn dance(&self, programs: &mut [char]) {
         for dance_move in self.dance_moves.iter() {
             match *dance_move {
@@ -73,56 +72,28 @@ impl Solver {
         }
     }
 
-    fn dance_optimized(
-        program_count: usize,
-        dance_moves: &Vec<DanceMove>,
-        repeat: usize,
-    ) -> String {
-        let mut table: Vec<_> = (0..program_count).collect();
-        let start_point = table.clone();
-        let mut r = 0;
-        while r < repeat {
-            for dance_move in dance_moves {
-                match *dance_move {
-                    DanceMove::Spin(n) => {
-                        table.rotate_right(n);
-                    }
-                    DanceMove::Exchange(i, j) => {
-                        table.swap(i, j);
-                    }
-                    DanceMove::Partner(a, b) => {
-                        let idx1 = table
-                            .iter()
-                            .position(|&x| x == (a as u8 - b'a') as usize)
-                            .unwrap();
-                        let idx2 = table
-                            .iter()
-                            .position(|&x| x == (b as u8 - b'a') as usize)
-                            .unwrap();
-                        table.swap(idx1, idx2);
-                    }
-                }
-            }
-            if table == start_point {
-                let skip = (repeat / (r + 1) - 1) * (r + 1);
-                r += skip;
-            }
-            r += 1;
-        }
-        table
-            .iter()
-            .map(|x| (b'a' as usize + x) as u8 as char)
-            .collect()
+    fn dance_repeatedly(&self, repeat: usize) -> String {
+        let programs: Vec<char> = self.init_state.chars().collect();
+        let programs = fast_forward(
+            programs,
+            |programs| {
+                let mut programs = programs.clone();
+                self.dance(&mut programs);
+                programs
+            },
+            repeat,
+        );
+        programs.into_iter().collect()
     }
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
-        Solver::dance_optimized(self.init_state.len(), &self.dance_moves, 1)
+    fn solve_part_one(&self) -> Answer {
+        self.dance_repeatedly(1).into()
     }
 
-    fn solve_part_two(&self) -> String {
-        Solver::dance_optimized(self.init_state.len(), &self.dance_moves, 1_000_000_000)
+    fn solve_part_two(&self) -> Answer {
+        self.dance_repeatedly(1_000_000_000).into()
     }
 
     fn day_number(&self) -> usize {
@@ -140,25 +111,27 @@ mod part1_tests {
 
     #[test]
     fn test_1() {
-        let result = Solver::new("abcde", "s1").solve_part_one();
+        let result = Solver::new("abcde", "s1").unwrap().solve_part_one();
         assert_eq!(result, "eabcd");
     }
 
     #[test]
     fn test_2() {
-        let result = Solver::new("abcde", "x3/4").solve_part_one();
+        let result = Solver::new("abcde", "x3/4").unwrap().solve_part_one();
         assert_eq!(result, "abced");
     }
 
     #[test]
     fn test_3() {
-        let result = Solver::new("abcde", "pe/b").solve_part_one();
+        let result = Solver::new("abcde", "pe/b").unwrap().solve_part_one();
         assert_eq!(result, "aecdb");
     }
 
     #[test]
     fn test_4() {
-        let result = Solver::new("abcde", "s1,x3/4,pe/b").solve_part_one();
+        let result = Solver::new("abcde", "s1,x3/4,pe/b")
+            .unwrap()
+            .solve_part_one();
         assert_eq!(result, "baedc");
     }
 }
@@ -169,14 +142,9 @@ mod part2_tests {
 
     #[test]
     fn test_1() {
-        let result = Solver::dance_optimized(
-            5,
-            &"s1,x3/4,pe/b"
-                .split(',')
-                .map(|x| DanceMove::from_str(x).unwrap())
-                .collect::<Vec<_>>(),
-            2,
-        );
+        let result = Solver::new("abcde", "s1,x3/4,pe/b")
+            .unwrap()
+            .dance_repeatedly(2);
         assert_eq!(result, "ceadb");
     }
 }
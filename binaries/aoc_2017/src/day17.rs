@@ -1,4 +1,4 @@
-use solver::SolverBase;
+use solver::{Answer, CircularLinkedList, ParseError, SolverBase, parse_field};
 
 pub struct Solver {
     input: usize,
@@ -9,30 +9,25 @@ impl Solver {
         Solver { input }
     }
 
-    fn get_wrapped(buffer: &[u32], current: usize, offset: usize) -> (usize, u32) {
-        let next = (current + offset) % buffer.len();
-        (next, buffer[next])
+    pub fn new_from_input(input: &str) -> Result<Self, ParseError> {
+        let step_count = parse_field(input.trim()).map_err(|error| error.on_line(1, input))?;
+        Ok(Solver::new(step_count))
     }
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
-        let mut buffer = vec![0u32];
-        let mut position = 0usize;
-        let offset = self.input;
+    fn solve_part_one(&self) -> Answer {
+        let mut buffer: CircularLinkedList<u32> = [0].into_iter().collect();
         for i in 1..=2017 {
-            // println!("{}", buffer.iter().enumerate().map(|(i, x)| if i == position { format!("({x})") } else { x.to_string() }).collect::<Vec<_>>().join(","));
-            // println!();
-            let (next_position, _) = Solver::get_wrapped(&buffer, position, offset);
-            buffer.insert(next_position + 1, i);
-            position = next_position + 1;
+            buffer.move_forward(self.input);
+            buffer.insert_after(i);
         }
-        let (_, value) = Solver::get_wrapped(&buffer, position, 1);
-        value.to_string()
+        buffer.move_forward(1);
+        (*buffer.current().unwrap()).into()
     }
 
-    fn solve_part_two(&self) -> String {
-        "".to_string()
+    fn solve_part_two(&self) -> Answer {
+        Answer::Unsolved
     }
 
     fn day_number(&self) -> usize {
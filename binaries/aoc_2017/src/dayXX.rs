@@ -1,20 +1,20 @@
-use solver::SolverBase;
+use solver::{Answer, ParseError, SolverBase};
 
 pub struct Solver {}
 
 impl Solver {
-    pub fn new(input: &'static str) -> Self {
-        Solver {}
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        Ok(Solver {})
     }
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
-        "".to_string()
+    fn solve_part_one(&self) -> Answer {
+        Answer::Unsolved
     }
 
-    fn solve_part_two(&self) -> String {
-        "".to_string()
+    fn solve_part_two(&self) -> Answer {
+        Answer::Unsolved
     }
 
     fn day_number(&self) -> usize {
@@ -32,7 +32,7 @@ mod part1_tests {
 
     #[test]
     fn test_1() {
-        let result = Solver::new("abc").solve_part_one();
+        let result = Solver::new("abc").unwrap().solve_part_one();
         assert_eq!(result, "0");
     }
 }
@@ -43,7 +43,7 @@ mod part2_tests {
 
     #[test]
     fn test_1() {
-        let result = Solver::new("abc").solve_part_two();
+        let result = Solver::new("abc").unwrap().solve_part_two();
         assert_eq!(result, "0");
     }
 }
@@ -0,0 +1,102 @@
+use solver::{Answer, ParseError, SolverBase, parse_field, parse_lines};
+
+pub struct Solver {
+    left_numbers: Vec<u32>,
+    right_numbers: Vec<u32>,
+}
+
+impl Solver {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let numbers = parse_lines(input, |line| {
+            let row = line
+                .split_whitespace()
+                .map(parse_field)
+                .collect::<Result<Vec<u32>, _>>()?;
+            match row[..] {
+                [left, right] => Ok((left, right)),
+                _ => Err(ParseError::new("expected two location IDs")),
+            }
+        })?;
+        let (left_numbers, right_numbers) = numbers.into_iter().unzip();
+
+        Ok(Solver {
+            left_numbers,
+            right_numbers,
+        })
+    }
+}
+
+impl SolverBase for Solver {
+    fn solve_part_one(&self) -> Answer {
+        let mut left_numbers = self.left_numbers.clone();
+        let mut right_numbers = self.right_numbers.clone();
+
+        left_numbers.sort();
+        right_numbers.sort();
+
+        let result: u32 = left_numbers
+            .iter()
+            .zip(right_numbers)
+            .map(|(left, right)| left.abs_diff(right))
+            .sum();
+
+        result.into()
+    }
+
+    fn solve_part_two(&self) -> Answer {
+        let score: u32 = self
+            .left_numbers
+            .iter()
+            .map(|x| x * self.right_numbers.iter().filter(|&y| x == y).count() as u32)
+            .sum();
+        score.into()
+    }
+
+    fn day_number(&self) -> usize {
+        1
+    }
+
+    fn description(&self) -> &'static str {
+        "Historian Hysteria"
+    }
+}
+
+#[cfg(test)]
+mod part1_tests {
+    use super::*;
+
+    #[test]
+    fn test_1() {
+        let result = Solver::new(
+            r"3   4
+4   3
+2   5
+1   3
+3   9
+3   3",
+        )
+        .unwrap()
+        .solve_part_one();
+        assert_eq!(result, "11");
+    }
+}
+
+#[cfg(test)]
+mod part2_tests {
+    use super::*;
+
+    #[test]
+    fn test_1() {
+        let result = Solver::new(
+            r"3   4
+4   3
+2   5
+1   3
+3   9
+3   3",
+        )
+        .unwrap()
+        .solve_part_two();
+        assert_eq!(result, "31");
+    }
+}
@@ -1,26 +1,50 @@
 use itertools::Itertools;
+use solver::{Answer, ParseError, SolverBase, parse_field, parse_lines};
 use std::cmp::Ordering;
 
-pub fn safe_reports_count() -> u32 {
-    let levels = get_reports();
-    let count = levels
-        .iter()
-        .filter(|report| is_report_safe(report))
-        .count() as u32;
-    count
+pub struct Solver {
+    reports: Vec<Vec<u32>>,
 }
 
-pub fn safe_reports_count_with_problem_dampener() -> u32 {
-    let levels = get_reports();
-    let count = levels
-        .iter()
-        .filter(|report| {
-            get_report_variations(report)
-                .iter()
-                .any(|v| is_report_safe(v))
-        })
-        .count() as u32;
-    count
+impl Solver {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let reports = parse_lines(input, |line| {
+            line.split_whitespace().map(parse_field).collect()
+        })?;
+        Ok(Solver { reports })
+    }
+}
+
+impl SolverBase for Solver {
+    fn solve_part_one(&self) -> Answer {
+        let count = self
+            .reports
+            .iter()
+            .filter(|report| is_report_safe(report))
+            .count();
+        count.into()
+    }
+
+    fn solve_part_two(&self) -> Answer {
+        let count = self
+            .reports
+            .iter()
+            .filter(|report| {
+                get_report_variations(report)
+                    .iter()
+                    .any(|v| is_report_safe(v))
+            })
+            .count();
+        count.into()
+    }
+
+    fn day_number(&self) -> usize {
+        2
+    }
+
+    fn description(&self) -> &'static str {
+        "Red-Nosed Reports"
+    }
 }
 
 fn get_report_variations(report: &[u32]) -> Vec<Vec<u32>> {
@@ -36,25 +60,6 @@ fn get_report_variations(report: &[u32]) -> Vec<Vec<u32>> {
         .collect()
 }
 
-fn get_reports() -> Vec<Vec<u32>> {
-    // read input from file day1.txt
-    let input = include_str!("../input/day2.txt");
-
-    // split input by newlines
-    let lines = input.lines();
-
-    // convert each line to a u32
-    let reports: Vec<Vec<u32>> = lines
-        .map(|line| {
-            line.split_whitespace()
-                .map(|token| token.parse().unwrap())
-                .collect()
-        })
-        .collect();
-
-    reports
-}
-
 #[derive(Debug, PartialEq)]
 enum ReportBehaviour {
     Undetermined,
@@ -96,3 +101,43 @@ fn is_report_safe(report: &[u32]) -> bool {
     (behaviour == ReportBehaviour::Increasing || behaviour == ReportBehaviour::Decreasing)
         && max_diff <= 3
 }
+
+#[cfg(test)]
+mod part1_tests {
+    use super::*;
+
+    #[test]
+    fn test_1() {
+        let result = Solver::new(
+            r"7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9",
+        )
+        .unwrap()
+        .solve_part_one();
+        assert_eq!(result, "2");
+    }
+}
+
+#[cfg(test)]
+mod part2_tests {
+    use super::*;
+
+    #[test]
+    fn test_1() {
+        let result = Solver::new(
+            r"7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9",
+        )
+        .unwrap()
+        .solve_part_two();
+        assert_eq!(result, "4");
+    }
+}
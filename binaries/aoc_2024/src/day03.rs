@@ -0,0 +1,110 @@
+use regex::Regex;
+use solver::{Answer, ParseError, SolverBase, parse_field};
+
+pub struct Solver {
+    instructions: Vec<Instruction>,
+}
+
+impl Solver {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        Ok(Solver {
+            instructions: parse_instructions(input)?,
+        })
+    }
+}
+
+impl SolverBase for Solver {
+    fn solve_part_one(&self) -> Answer {
+        let result: u32 = self
+            .instructions
+            .iter()
+            .filter_map(|x| {
+                if let Instruction::Mul(a, b) = x {
+                    Some(a * b)
+                } else {
+                    None
+                }
+            })
+            .sum();
+        result.into()
+    }
+
+    fn solve_part_two(&self) -> Answer {
+        let mut enabled = true;
+        let result: u32 = self
+            .instructions
+            .iter()
+            .filter_map(|x| match x {
+                Instruction::Mul(a, b) => enabled.then(|| a * b),
+                Instruction::Do => {
+                    enabled = true;
+                    None
+                }
+                Instruction::Dont => {
+                    enabled = false;
+                    None
+                }
+            })
+            .sum();
+        result.into()
+    }
+
+    fn day_number(&self) -> usize {
+        3
+    }
+
+    fn description(&self) -> &'static str {
+        "Mull It Over"
+    }
+}
+
+#[derive(Debug)]
+enum Instruction {
+    Do,
+    Dont,
+    Mul(u32, u32),
+}
+
+fn parse_instructions(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    let regex = Regex::new(r"do\(\)|don't\(\)|mul\((\d+),(\d+)\)").unwrap();
+    regex
+        .captures_iter(input)
+        .map(|cap| match &cap[0] {
+            "do()" => Ok(Instruction::Do),
+            "don't()" => Ok(Instruction::Dont),
+            _ => Ok(Instruction::Mul(
+                parse_field(&cap[1])?,
+                parse_field(&cap[2])?,
+            )),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod part1_tests {
+    use super::*;
+
+    #[test]
+    fn test_1() {
+        let result =
+            Solver::new(r"xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))")
+                .unwrap()
+                .solve_part_one();
+        assert_eq!(result, "161");
+    }
+}
+
+#[cfg(test)]
+mod part2_tests {
+    use super::*;
+
+    #[test]
+    fn test_1() {
+        let result = Solver::new(
+            r"xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))",
+        )
+        .unwrap()
+        .solve_part_two();
+        assert_eq!(result, "48");
+    }
+}
@@ -1,13 +1,42 @@
-pub fn count_of_xmas_appearances() -> usize {
+use solver::{Answer, ParseError, SolverBase};
+
+pub struct Solver {
+    letters: Vec<Vec<u8>>,
+}
+
+impl Solver {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let letters = input.lines().map(|line| line.as_bytes().to_vec()).collect();
+        Ok(Solver { letters })
+    }
+}
+
+impl SolverBase for Solver {
+    fn solve_part_one(&self) -> Answer {
+        count_of_xmas_appearances(&self.letters).into()
+    }
+
+    fn solve_part_two(&self) -> Answer {
+        count_of_x_mas_appearances(&self.letters).into()
+    }
+
+    fn day_number(&self) -> usize {
+        4
+    }
+
+    fn description(&self) -> &'static str {
+        "Ceres Search"
+    }
+}
+
+fn count_of_xmas_appearances(input: &[Vec<u8>]) -> usize {
     const XMAS: &str = "XMAS";
-    let input = read_input();
-    //let input = read_test_input();
 
     let mut total_count = 0;
 
     // horizontal lines
     {
-        let mut horizontal_lines = read_all_horizontal_lines(&input);
+        let mut horizontal_lines = read_all_horizontal_lines(input);
         let horizontal_lines_count: usize = horizontal_lines
             .iter()
             .map(|line| line.matches(XMAS).count())
@@ -26,7 +55,7 @@ pub fn count_of_xmas_appearances() -> usize {
 
     // vertical lines
     {
-        let mut vertical_lines = read_all_vertical_lines(&input);
+        let mut vertical_lines = read_all_vertical_lines(input);
         let vertical_lines_count: usize = vertical_lines
             .iter()
             .map(|line| line.matches(XMAS).count())
@@ -45,7 +74,7 @@ pub fn count_of_xmas_appearances() -> usize {
 
     // diagonal lines
     {
-        let mut diagonal_lines = read_all_diagonal_lines(&input);
+        let mut diagonal_lines = read_all_diagonal_lines(input);
 
         let diagonal_lines_count: usize = diagonal_lines
             .iter()
@@ -66,10 +95,7 @@ pub fn count_of_xmas_appearances() -> usize {
     total_count
 }
 
-pub fn count_of_x_mas_appearances() -> usize {
-    let input = read_input();
-    //let input = read_test_input();
-
+fn count_of_x_mas_appearances(input: &[Vec<u8>]) -> usize {
     let check_mas_fn = |i: usize, j: usize| {
         const MAS: &str = "MAS";
         if i == 0 || j == 0 {
@@ -117,26 +143,7 @@ pub fn count_of_x_mas_appearances() -> usize {
     count
 }
 
-fn read_input() -> Vec<Vec<u8>> {
-    let input = include_str!("../input/day4.txt");
-    input.lines().map(|line| line.as_bytes().to_vec()).collect()
-}
-
-fn _read_test_input() -> Vec<Vec<u8>> {
-    let input = r"MMMSXXMASM
-MSAMXMSMSA
-AMXSXMAAMM
-MSAMASMSMX
-XMASAMXAMM
-XXAMMXXAMA
-SMSMSASXSS
-SAXAMASAAA
-MAMMMXMMMM
-MXMXAXMASX";
-    input.lines().map(|line| line.as_bytes().to_vec()).collect()
-}
-
-fn read_all_horizontal_lines(input: &Vec<Vec<u8>>) -> Vec<String> {
+fn read_all_horizontal_lines(input: &[Vec<u8>]) -> Vec<String> {
     let mut result = Vec::<String>::new();
     for line in input {
         let mut line_string = String::new();
@@ -148,7 +155,7 @@ fn read_all_horizontal_lines(input: &Vec<Vec<u8>>) -> Vec<String> {
     result
 }
 
-fn read_all_vertical_lines(input: &Vec<Vec<u8>>) -> Vec<String> {
+fn read_all_vertical_lines(input: &[Vec<u8>]) -> Vec<String> {
     let mut result = Vec::<String>::new();
     for i in 0..input[0].len() {
         let mut line_string = String::new();
@@ -160,15 +167,9 @@ fn read_all_vertical_lines(input: &Vec<Vec<u8>>) -> Vec<String> {
     result
 }
 
-fn reverse_strings(v: &mut Vec<String>) {
+fn reverse_strings(v: &mut [String]) {
     for s in v {
-        unsafe {
-            // Access the underlying bytes of the string
-            let bytes = s.as_mut_vec();
-
-            // Reverse the bytes
-            bytes.reverse();
-        }
+        *s = s.chars().rev().collect();
     }
 }
 
@@ -191,7 +192,6 @@ fn read_all_diagonal_lines(matrix: &[Vec<u8>]) -> Vec<String> {
             col += 1;
         }
         result.push(diagonal);
-        //println!("Diagonal from top-left: {:?}", diagonal);
     }
 
     for start_row in 1..rows {
@@ -204,7 +204,6 @@ fn read_all_diagonal_lines(matrix: &[Vec<u8>]) -> Vec<String> {
             col += 1;
         }
         result.push(diagonal);
-        //println!("Diagonal from left: {:?}", diagonal);
     }
 
     // Top-right to bottom-left diagonals
@@ -218,7 +217,6 @@ fn read_all_diagonal_lines(matrix: &[Vec<u8>]) -> Vec<String> {
             col = col.wrapping_sub(1); // Avoid underflow
         }
         result.push(diagonal);
-        //println!("Diagonal from top-right: {:?}", diagonal);
     }
 
     for start_row in 1..rows {
@@ -231,8 +229,55 @@ fn read_all_diagonal_lines(matrix: &[Vec<u8>]) -> Vec<String> {
             col = col.wrapping_sub(1); // Avoid underflow
         }
         result.push(diagonal);
-        //println!("Diagonal from right: {:?}", diagonal);
     }
 
     result
 }
+
+#[cfg(test)]
+mod part1_tests {
+    use super::*;
+
+    #[test]
+    fn test_1() {
+        let result = Solver::new(
+            r"MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX",
+        )
+        .unwrap()
+        .solve_part_one();
+        assert_eq!(result, "18");
+    }
+}
+
+#[cfg(test)]
+mod part2_tests {
+    use super::*;
+
+    #[test]
+    fn test_1() {
+        let result = Solver::new(
+            r"MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX",
+        )
+        .unwrap()
+        .solve_part_two();
+        assert_eq!(result, "9");
+    }
+}
@@ -0,0 +1,200 @@
+use std::collections::HashMap;
+
+use solver::{Answer, Graph, ParseError, SolverBase, parse_field, parse_lines, split_sections};
+
+pub struct Solver {
+    ordering: HashMap<u32, Vec<u32>>,
+    updates: Vec<Vec<u32>>,
+}
+
+impl Solver {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let (ordering_str, updates_str, updates_line) = split_sections(input)?;
+        Ok(Solver {
+            ordering: parse_ordering(ordering_str)?,
+            updates: parse_updates(updates_str).map_err(|error| error.below(updates_line - 1))?,
+        })
+    }
+}
+
+impl SolverBase for Solver {
+    fn solve_part_one(&self) -> Answer {
+        let result: u32 = self
+            .updates
+            .iter()
+            .filter(|x| check_update_is_in_correct_order(x, &self.ordering))
+            .map(|x| get_middle_page_number(x))
+            .sum();
+        result.into()
+    }
+
+    fn solve_part_two(&self) -> Answer {
+        let result: Option<u32> = self
+            .updates
+            .iter()
+            .filter(|x| !check_update_is_in_correct_order(x, &self.ordering))
+            .map(|x| sort_update(x, &self.ordering))
+            .map(|x| x.map(|x| get_middle_page_number(&x)))
+            .sum();
+        match result {
+            Some(result) => result.into(),
+            None => Answer::NoSolution,
+        }
+    }
+
+    fn day_number(&self) -> usize {
+        5
+    }
+
+    fn description(&self) -> &'static str {
+        "Print Queue"
+    }
+}
+
+/// None when the rules between the pages of the update form a cycle
+fn sort_update(update: &[u32], ordering: &HashMap<u32, Vec<u32>>) -> Option<Vec<u32>> {
+    // Only the rules between pages of the update apply, the whole set of rules has cycles
+    let mut rules = Graph::directed(update.iter().flat_map(|&page_before| {
+        ordering
+            .get(&page_before)
+            .into_iter()
+            .flatten()
+            .filter(|page_after| update.contains(page_after))
+            .map(move |&page_after| (page_before, page_after))
+    }));
+    for &page in update {
+        rules.add_node(page);
+    }
+    rules.topological_sort().ok()
+}
+
+fn parse_ordering(ordering_str: &str) -> Result<HashMap<u32, Vec<u32>>, ParseError> {
+    let mut ordering = HashMap::new();
+    let rules = parse_lines(ordering_str, |line| {
+        let (page_before, page_after) = line
+            .split_once('|')
+            .ok_or_else(|| ParseError::new("expected page ordering rule `<page>|<page>`"))?;
+        Ok((parse_field(page_before)?, parse_field(page_after)?))
+    })?;
+    for (page_before, page_after) in rules {
+        ordering
+            .entry(page_before)
+            .or_insert(Vec::new())
+            .push(page_after);
+    }
+
+    Ok(ordering)
+}
+
+fn parse_updates(updates_str: &str) -> Result<Vec<Vec<u32>>, ParseError> {
+    parse_lines(updates_str, |x| x.split(",").map(parse_field).collect())
+}
+
+fn check_update_is_in_correct_order(update: &[u32], ordering: &HashMap<u32, Vec<u32>>) -> bool {
+    for (page_index, page) in update.iter().enumerate() {
+        if let Some(pages_after) = ordering.get(page) {
+            for page_before in update.iter().take(page_index) {
+                if pages_after.contains(page_before) {
+                    return false;
+                }
+            }
+        }
+    }
+    true
+}
+
+fn get_middle_page_number(update: &[u32]) -> u32 {
+    update[update.len() / 2]
+}
+
+#[cfg(test)]
+mod part1_tests {
+    use super::*;
+
+    #[test]
+    fn test_1() {
+        let result = Solver::new(
+            r"47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47",
+        )
+        .unwrap()
+        .solve_part_one();
+        assert_eq!(result, "143");
+    }
+}
+
+#[cfg(test)]
+mod part2_tests {
+    use super::*;
+
+    #[test]
+    fn test_1() {
+        let result = Solver::new(
+            r"47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47",
+        )
+        .unwrap()
+        .solve_part_two();
+        assert_eq!(result, "123");
+    }
+
+    #[test]
+    fn test_cycle() {
+        let result = Solver::new("1|2\n2|3\n3|1\n\n1,2,3")
+            .unwrap()
+            .solve_part_two();
+        assert_eq!(result, Answer::NoSolution);
+    }
+}
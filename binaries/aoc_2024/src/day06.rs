@@ -0,0 +1,242 @@
+use solver::{Answer, Direction, ParseError, Point2, SolverBase, parse_lines};
+
+pub struct Solver {
+    map: Vec<Vec<Location>>,
+    position: Position,
+}
+
+impl Solver {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let (map, position) = parse_map_with_position(input)?;
+        Ok(Solver { map, position })
+    }
+}
+
+impl SolverBase for Solver {
+    fn solve_part_one(&self) -> Answer {
+        let mut map = self.map.clone();
+        _ = walk_through_map(&mut map, &self.position);
+        get_count_of_visited_locations(&map).into()
+    }
+
+    fn solve_part_two(&self) -> Answer {
+        let mut map = self.map.clone();
+        let mut count_of_obstacles = 0;
+        for row_index in 0..map.len() {
+            for column_index in 0..map[row_index].len() {
+                if map[row_index][column_index] == Location::Empty {
+                    map[row_index][column_index] = Location::Obstacle;
+                    let result = walk_through_map(&mut map, &self.position);
+                    if let WalkResult::LoopDetected = result {
+                        count_of_obstacles += 1;
+                    }
+                    map[row_index][column_index] = Location::Empty;
+                    remove_all_visits(&mut map);
+                }
+            }
+        }
+        count_of_obstacles.into()
+    }
+
+    fn day_number(&self) -> usize {
+        6
+    }
+
+    fn description(&self) -> &'static str {
+        "Guard Gallivant"
+    }
+}
+
+type Position = Point2<usize>;
+
+#[derive(Debug, PartialEq, Clone)]
+enum Location {
+    Empty,
+    Obstacle,
+    Visited(Vec<Direction>),
+}
+
+#[derive(Debug)]
+enum WalkResult {
+    Completed,
+    LoopDetected,
+}
+
+fn parse_map_with_position(input: &str) -> Result<(Vec<Vec<Location>>, Position), ParseError> {
+    let mut position: Option<Position> = None;
+    let mut row_index = 0;
+    let map: Vec<Vec<Location>> = parse_lines(input, |line| {
+        let row = line
+            .chars()
+            .enumerate()
+            .map(|(column_index, symbol)| match symbol {
+                '#' => Ok(Location::Obstacle),
+                '.' => Ok(Location::Empty),
+                '^' => {
+                    position = Some(Position::new(column_index, row_index));
+                    Ok(Location::Empty)
+                }
+                _ => Err(ParseError::expected("`#`, `.` or `^`", &symbol.to_string())
+                    .at_column(column_index + 1)),
+            })
+            .collect();
+        row_index += 1;
+        row
+    })?;
+    let position = position.ok_or_else(|| ParseError::new("expected the guard `^` on the map"))?;
+    Ok((map, position))
+}
+
+fn walk_through_map(map: &mut [Vec<Location>], position: &Position) -> WalkResult {
+    let mut current_position = *position;
+    let mut direction = Direction::Up;
+
+    map[current_position.y][current_position.x] = Location::Visited(vec![direction]); // initial position is also visited
+
+    //print_map_with_position(map, &current_position, &direction);
+
+    while current_position.y > 0
+        && current_position.x > 0
+        && current_position.y < map.len() - 1
+        && current_position.x < map[current_position.y].len() - 1
+    {
+        // Not on the border, so the step stays on the map
+        let next_position = current_position.checked_step(direction).unwrap();
+
+        if map[next_position.y][next_position.x] == Location::Obstacle {
+            // obstacle in front of me
+            //print_map_with_position(map, &current_position, &direction);
+
+            direction = direction.turn_right();
+        } else {
+            current_position = next_position; // move forward
+            let loop_detected = visit_location(map, &current_position, &direction);
+            if loop_detected {
+                return WalkResult::LoopDetected;
+            }
+        }
+    }
+    //print_map_with_position(map, &current_position, &direction);
+
+    WalkResult::Completed
+}
+
+fn visit_location(map: &mut [Vec<Location>], position: &Position, direction: &Direction) -> bool {
+    match map[position.y][position.x] {
+        Location::Empty => {
+            map[position.y][position.x] = Location::Visited(vec![*direction]);
+            false
+        }
+        Location::Obstacle => {
+            panic!("Cannot visit obstacle");
+        }
+        Location::Visited(ref mut directions) => {
+            if !directions.contains(direction) {
+                directions.push(*direction);
+                false
+            } else {
+                true // loop detected
+            }
+        }
+    }
+}
+
+fn remove_all_visits(map: &mut [Vec<Location>]) {
+    for row in map.iter_mut() {
+        for location in row.iter_mut() {
+            if let Location::Visited(_) = location {
+                *location = Location::Empty;
+            }
+        }
+    }
+}
+
+fn get_count_of_visited_locations(map: &[Vec<Location>]) -> u32 {
+    map.iter()
+        .map(|row| {
+            row.iter()
+                .filter(|location| matches!(location, Location::Visited(_)))
+                .count() as u32
+        })
+        .sum()
+}
+
+fn _print_map_with_position(map: &[Vec<Location>], position: &Position, direction: &Direction) {
+    let map_with_position: Vec<Vec<char>> = map
+        .iter()
+        .enumerate()
+        .map(|(row_index, row)| {
+            row.iter()
+                .enumerate()
+                .map(|(column_index, location)| {
+                    if Position::new(column_index, row_index) == *position {
+                        match direction {
+                            Direction::Up => '^',
+                            Direction::Right => '>',
+                            Direction::Down => 'v',
+                            Direction::Left => '<',
+                        }
+                    } else {
+                        match location {
+                            Location::Empty => '.',
+                            Location::Obstacle => '#',
+                            Location::Visited(_) => 'X',
+                        }
+                    }
+                })
+                .collect()
+        })
+        .collect();
+    for row in map_with_position {
+        println!("{}", row.iter().collect::<String>());
+    }
+    println!();
+}
+
+#[cfg(test)]
+mod part1_tests {
+    use super::*;
+
+    #[test]
+    fn test_1() {
+        let result = Solver::new(
+            r"....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...",
+        )
+        .unwrap()
+        .solve_part_one();
+        assert_eq!(result, "41");
+    }
+}
+
+#[cfg(test)]
+mod part2_tests {
+    use super::*;
+
+    #[test]
+    fn test_1() {
+        let result = Solver::new(
+            r"....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...",
+        )
+        .unwrap()
+        .solve_part_two();
+        assert_eq!(result, "6");
+    }
+}
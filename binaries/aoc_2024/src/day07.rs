@@ -0,0 +1,164 @@
+use solver::{Answer, ParseError, SolverBase, parse_field, parse_lines};
+
+pub struct Solver {
+    equations: Vec<Equation>,
+}
+
+impl Solver {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        Ok(Solver {
+            equations: parse_equations(input)?,
+        })
+    }
+}
+
+impl SolverBase for Solver {
+    fn solve_part_one(&self) -> Answer {
+        self.get_total_calibration_result(false).into()
+    }
+
+    fn solve_part_two(&self) -> Answer {
+        self.get_total_calibration_result(true).into()
+    }
+
+    fn day_number(&self) -> usize {
+        7
+    }
+
+    fn description(&self) -> &'static str {
+        "Bridge Repair"
+    }
+}
+
+impl Solver {
+    fn get_total_calibration_result(&self, with_concatanation: bool) -> u64 {
+        self.equations
+            .iter()
+            .filter(|equation| check_satisfiability(equation, with_concatanation))
+            .map(|equation| equation.result)
+            .sum()
+    }
+}
+
+#[derive(Debug)]
+struct Equation {
+    result: u64,
+    operands: Vec<u64>,
+}
+
+fn parse_equations(input: &str) -> Result<Vec<Equation>, ParseError> {
+    parse_lines(input, |line| {
+        let (result, operands) = line
+            .split_once(": ")
+            .ok_or_else(|| ParseError::new("expected `<result>: <operands>`"))?;
+        let operands: Vec<_> = operands
+            .split_whitespace()
+            .map(parse_field)
+            .collect::<Result<_, _>>()?;
+        if operands.is_empty() {
+            return Err(ParseError::new("expected at least one operand"));
+        }
+        Ok(Equation {
+            result: parse_field(result)?,
+            operands,
+        })
+    })
+}
+
+fn check_satisfiability(equation: &Equation, with_concatanation: bool) -> bool {
+    let Equation { result, operands } = equation;
+    if with_concatanation {
+        calculate_equation_result_with_concatanation(&operands[1..], operands[0], *result)
+    } else {
+        calculate_equation_result(&operands[1..], operands[0], *result)
+    }
+}
+
+fn calculate_equation_result(operands: &[u64], running_result: u64, total_result: u64) -> bool {
+    match operands {
+        [] => running_result == total_result,
+        [a, rest @ ..] => {
+            calculate_equation_result(rest, a + running_result, total_result)
+                || calculate_equation_result(rest, a * running_result, total_result)
+        }
+    }
+}
+
+fn calculate_equation_result_with_concatanation(
+    operands: &[u64],
+    running_result: u64,
+    total_result: u64,
+) -> bool {
+    match operands {
+        [] => running_result == total_result,
+        [a, rest @ ..] => {
+            calculate_equation_result_with_concatanation(rest, running_result + a, total_result)
+                || calculate_equation_result_with_concatanation(
+                    rest,
+                    running_result * a,
+                    total_result,
+                )
+                || calculate_equation_result_with_concatanation(
+                    rest,
+                    concetenate_numbers(running_result, *a),
+                    total_result,
+                )
+        }
+    }
+}
+
+fn concetenate_numbers(first: u64, second: u64) -> u64 {
+    let mut b = second;
+    let mut shift = 1;
+    while b > 0 {
+        shift *= 10;
+        b /= 10;
+    }
+    first * shift + second
+}
+
+#[cfg(test)]
+mod part1_tests {
+    use super::*;
+
+    #[test]
+    fn test_1() {
+        let result = Solver::new(
+            r"190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20",
+        )
+        .unwrap()
+        .solve_part_one();
+        assert_eq!(result, "3749");
+    }
+}
+
+#[cfg(test)]
+mod part2_tests {
+    use super::*;
+
+    #[test]
+    fn test_1() {
+        let result = Solver::new(
+            r"190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20",
+        )
+        .unwrap()
+        .solve_part_two();
+        assert_eq!(result, "11387");
+    }
+}
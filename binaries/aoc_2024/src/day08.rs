@@ -0,0 +1,206 @@
+use std::collections::{HashMap, HashSet};
+
+use solver::{Answer, ParseError, Point2, SolverBase};
+
+pub struct Solver {
+    map: Vec<Vec<char>>,
+}
+
+impl Solver {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        Ok(Solver {
+            map: input.lines().map(|line| line.chars().collect()).collect(),
+        })
+    }
+}
+
+impl SolverBase for Solver {
+    fn solve_part_one(&self) -> Answer {
+        self.count_of_antinodes(false).into()
+    }
+
+    fn solve_part_two(&self) -> Answer {
+        self.count_of_antinodes(true).into()
+    }
+
+    fn day_number(&self) -> usize {
+        8
+    }
+
+    fn description(&self) -> &'static str {
+        "Resonant Collinearity"
+    }
+}
+
+impl Solver {
+    fn count_of_antinodes(&self, use_resonant_harmonics: bool) -> usize {
+        let (map_height, map_width) = (self.map.len(), self.map[0].len());
+        let antenna_positions = get_antenna_positions(&self.map);
+        let mut all_antinode_positions = HashSet::new();
+        for positions in antenna_positions.values() {
+            let antinode_positions = if use_resonant_harmonics {
+                get_all_antinodes_positions_with_resonant_harmonics(
+                    positions, map_width, map_height,
+                )
+            } else {
+                get_all_antinodes_positions(positions, map_width, map_height)
+            };
+            all_antinode_positions.extend(antinode_positions);
+        }
+        all_antinode_positions.len()
+    }
+}
+
+type Position = Point2<isize>;
+
+fn get_antenna_positions(input: &[Vec<char>]) -> HashMap<char, Vec<Position>> {
+    let mut antenna_positions = HashMap::new();
+    for (row, line) in input.iter().enumerate() {
+        for (column, &character) in line.iter().enumerate() {
+            if character.is_alphanumeric() {
+                let positions = antenna_positions.entry(character).or_insert(vec![]);
+                positions.push(Position::new(column as isize, row as isize));
+            }
+        }
+    }
+    antenna_positions
+}
+
+fn find_all_antenna_pairs(antenna_positions: &[Position]) -> Vec<(Position, Position)> {
+    let mut antenna_pairs = vec![];
+    for (index, &position) in antenna_positions.iter().enumerate() {
+        for &other_position in &antenna_positions[index + 1..] {
+            antenna_pairs.push((position, other_position));
+        }
+    }
+    antenna_pairs
+}
+
+fn is_on_map(position: Position, map_width: usize, map_height: usize) -> bool {
+    (0..map_width as isize).contains(&position.x) && (0..map_height as isize).contains(&position.y)
+}
+
+fn get_all_antinodes_positions(
+    antenna_positions: &[Position],
+    map_width: usize,
+    map_height: usize,
+) -> Vec<Position> {
+    let antenna_pairs = find_all_antenna_pairs(antenna_positions);
+    let mut antinode_positions = vec![];
+    for (position, other_position) in antenna_pairs {
+        let difference = position - other_position;
+        for antinode_position in [position + difference, other_position - difference] {
+            if is_on_map(antinode_position, map_width, map_height) {
+                antinode_positions.push(antinode_position);
+            }
+        }
+    }
+    antinode_positions
+}
+
+fn get_all_antinodes_positions_with_resonant_harmonics(
+    antenna_positions: &[Position],
+    map_width: usize,
+    map_height: usize,
+) -> Vec<Position> {
+    let antenna_pairs = find_all_antenna_pairs(antenna_positions);
+    let mut antinode_positions = vec![];
+    for (position, other_position) in antenna_pairs {
+        for antinode in get_all_antinodes_in_line(position, other_position, map_width, map_height) {
+            antinode_positions.push(antinode);
+        }
+    }
+    antinode_positions
+}
+
+fn get_all_antinodes_in_line(
+    a: Position,
+    b: Position,
+    map_width: usize,
+    map_height: usize,
+) -> Vec<Position> {
+    let difference = a - b;
+    let mut antinode_positions = vec![];
+    let mut position = a;
+    while is_on_map(position, map_width, map_height) {
+        antinode_positions.push(position);
+        position += difference;
+    }
+    position = b;
+    while is_on_map(position, map_width, map_height) {
+        antinode_positions.push(position);
+        position -= difference;
+    }
+    antinode_positions
+}
+
+#[cfg(test)]
+mod part1_tests {
+    use super::*;
+
+    #[test]
+    fn test_1() {
+        let result = Solver::new(
+            r"............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............",
+        )
+        .unwrap()
+        .solve_part_one();
+        assert_eq!(result, "14");
+    }
+}
+
+#[cfg(test)]
+mod part2_tests {
+    use super::*;
+
+    #[test]
+    fn test_1() {
+        let result = Solver::new(
+            r"T.........
+...T......
+.T........
+..........
+..........
+..........
+..........
+..........
+..........
+..........",
+        )
+        .unwrap()
+        .solve_part_two();
+        assert_eq!(result, "9");
+    }
+
+    #[test]
+    fn test_2() {
+        let result = Solver::new(
+            r"............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............",
+        )
+        .unwrap()
+        .solve_part_two();
+        assert_eq!(result, "34");
+    }
+}
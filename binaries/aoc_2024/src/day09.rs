@@ -1,5 +1,50 @@
 use std::ops::Range;
 
+use solver::{Answer, ParseError, SolverBase};
+
+pub struct Solver {
+    disk_map: Vec<u8>,
+}
+
+impl Solver {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let disk_map = input
+            .chars()
+            .enumerate()
+            .map(|(index, c)| {
+                c.to_digit(10).map(|digit| digit as u8).ok_or_else(|| {
+                    ParseError::expected("a digit", &c.to_string())
+                        .at_column(index + 1)
+                        .on_line(1, input)
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Solver { disk_map })
+    }
+}
+
+impl SolverBase for Solver {
+    fn solve_part_one(&self) -> Answer {
+        let mut filesystem_blocks = get_filesystem_blocks(&self.disk_map);
+        while move_last_file_block_to_front(&mut filesystem_blocks) {}
+        filesystem_blocks.checksum().into()
+    }
+
+    fn solve_part_two(&self) -> Answer {
+        let mut filesystem_blocks = get_filesystem_blocks(&self.disk_map);
+        move_entire_files_to_front_empty_spaces(&mut filesystem_blocks);
+        filesystem_blocks.checksum().into()
+    }
+
+    fn day_number(&self) -> usize {
+        9
+    }
+
+    fn description(&self) -> &'static str {
+        "Disk Fragmenter"
+    }
+}
+
 enum FileBlock {
     FreeSpace,
     File(u32),
@@ -33,34 +78,6 @@ impl Checksum for [FileBlock] {
     }
 }
 
-pub fn get_filesystem_checksum() -> u64 {
-    let input = get_input();
-    let mut filesystem_blocks = get_filesystem_blocks(&input);
-    //print_filesystem(&filesystem_blocks);
-    while move_last_file_block_to_front(&mut filesystem_blocks) {
-        //print!("*");
-        //print_filesystem(&filesystem_blocks);
-    }
-    filesystem_blocks.checksum()
-}
-
-pub fn get_filesystem_checksum_without_fragmentation() -> u64 {
-    let input = get_input();
-    let mut filesystem_blocks = get_filesystem_blocks(&input);
-    //print_filesystem(&filesystem_blocks);
-    move_entire_files_to_front_empty_spaces(&mut filesystem_blocks);
-    filesystem_blocks.checksum()
-}
-
-fn get_input() -> Vec<u8> {
-    let input = include_str!("../input/day9.txt");
-    //let input = "2333133121414131402";
-    input
-        .chars()
-        .map(|c| c.to_digit(10).unwrap() as u8)
-        .collect()
-}
-
 #[allow(dead_code)]
 fn print_filesystem(filesystem: &[FileBlock]) {
     for value in filesystem {
@@ -69,7 +86,7 @@ fn print_filesystem(filesystem: &[FileBlock]) {
     println!()
 }
 
-fn get_filesystem_blocks(input: &Vec<u8>) -> Vec<FileBlock> {
+fn get_filesystem_blocks(input: &[u8]) -> Vec<FileBlock> {
     let mut is_free_space = false;
     let mut file_id = 0;
     let mut filesystem_blocks = Vec::new();
@@ -123,21 +140,19 @@ fn move_entire_files_to_front_empty_spaces(filesystem: &mut [FileBlock]) {
         .unwrap();
     for file_id in (0..=max_file_id).rev() {
         move_entire_file_to_first_fitting_empty_space(filesystem, file_id);
-        //print_filesystem(filesystem);
     }
 }
 
 fn move_entire_file_to_first_fitting_empty_space(filesystem: &mut [FileBlock], file_id: u32) {
     let file_slice = find_file_slice(filesystem, file_id);
     let empty_space_slice = find_first_matching_empty_space_slice(filesystem, file_slice.len());
-    //println!("empty_space_position_and_length: {:?}", empty_space_slice);
     // swap file blocks with empty space blocks
-    if let Some(empty_space_slice) = empty_space_slice {
-        if empty_space_slice.start < file_slice.start {
-            for i in 0..file_slice.len() {
-                filesystem[empty_space_slice.start + i] = FileBlock::File(file_id);
-                filesystem[file_slice.start + i] = FileBlock::FreeSpace;
-            }
+    if let Some(empty_space_slice) = empty_space_slice
+        && empty_space_slice.start < file_slice.start
+    {
+        for i in 0..file_slice.len() {
+            filesystem[empty_space_slice.start + i] = FileBlock::File(file_id);
+            filesystem[file_slice.start + i] = FileBlock::FreeSpace;
         }
     }
 }
@@ -194,3 +209,29 @@ fn find_first_matching_empty_space_slice(
     }
     None
 }
+
+#[cfg(test)]
+mod part1_tests {
+    use super::*;
+
+    #[test]
+    fn test_1() {
+        let result = Solver::new(r"2333133121414131402")
+            .unwrap()
+            .solve_part_one();
+        assert_eq!(result, "1928");
+    }
+}
+
+#[cfg(test)]
+mod part2_tests {
+    use super::*;
+
+    #[test]
+    fn test_1() {
+        let result = Solver::new(r"2333133121414131402")
+            .unwrap()
+            .solve_part_two();
+        assert_eq!(result, "2858");
+    }
+}
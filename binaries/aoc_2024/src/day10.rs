@@ -1,158 +1,116 @@
 use std::collections::HashSet;
 
-pub fn get_sum_of_all_trailhead_scores() -> (u32, u32) {
-    let map = get_topographic_map();
-    let trailheads = get_all_trailheads(&map);
-    let mut total_score = 0;
-    let mut total_rating = 0;
-    for trailhead in &trailheads {
-        println!("trailhead {:?}:", trailhead);
-        let mut summits = HashSet::new();
-        let rating = walk_trailhead(trailhead, &map, &mut summits);
-        println!("summits:");
-        for summit in &summits {
-            println!("{:?}", summit);
-        }
-        total_score += summits.len() as u32;
-        println!("rating: {}", rating);
-        total_rating += rating;
-    }
-    (total_score, total_rating)
+use solver::{Answer, Grid, ParseError, SolverBase};
+
+pub struct Solver {
+    map: Grid<u8>,
 }
 
-fn get_topographic_map() -> Vec<Vec<u8>> {
-    let input = include_str!("../input/day10.txt");
-    //     let input = r"0123
-    // 1234
-    // 8765
-    // 9876";
-    // let input = r"...0...
-    // ...1...
-    // ...2...
-    // 6543456
-    // 7.....7
-    // 8.....8
-    // 9.....9";
-    //     let input = r"..90..9
-    // ...1.98
-    // ...2..7
-    // 6543456
-    // 765.987
-    // 876....
-    // 987....";
-    // let input = r"10..9..
-    // 2...8..
-    // 3...7..
-    // 4567654
-    // ...8..3
-    // ...9..2
-    // .....01";
-    // let input = r"89010123
-    // 78121874
-    // 87430965
-    // 96549874
-    // 45678903
-    // 32019012
-    // 01329801
-    // 10456732";
-    //     let input = r".....0.
-    // ..4321.
-    // ..5..2.
-    // ..6543.
-    // ..7..4.
-    // ..8765.
-    // ..9....";
-    // let input = r"012345
-    // 123456
-    // 234567
-    // 345678
-    // 4.6789
-    // 56789.";
-    // let input = r"89010123
-    // 78121874
-    // 87430965
-    // 96549874
-    // 45678903
-    // 32019012
-    // 01329801
-    // 10456732";
-    input
-        .lines()
-        .map(|line| {
-            line.chars()
-                .map(|c| c.to_digit(10).unwrap_or(u8::MAX as u32) as u8)
-                .collect()
-        })
-        .collect()
+impl Solver {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let map = Grid::parse(input, |c| match c {
+            '.' => Ok(u8::MAX),
+            _ => c
+                .to_digit(10)
+                .map(|height| height as u8)
+                .ok_or_else(|| ParseError::expected("a height or `.`", &c.to_string())),
+        })?;
+        Ok(Solver { map })
+    }
 }
 
-#[derive(Debug, Clone, Eq, Hash, PartialEq)]
-struct Position {
-    row: usize,
-    column: usize,
+impl SolverBase for Solver {
+    fn solve_part_one(&self) -> Answer {
+        let (score, _) = get_sum_of_all_trailhead_scores(&self.map);
+        score.into()
+    }
+
+    fn solve_part_two(&self) -> Answer {
+        let (_, rating) = get_sum_of_all_trailhead_scores(&self.map);
+        rating.into()
+    }
+
+    fn day_number(&self) -> usize {
+        10
+    }
+
+    fn description(&self) -> &'static str {
+        "Hoof It"
+    }
 }
 
-fn get_all_trailheads(map: &[Vec<u8>]) -> Vec<Position> {
-    let mut trailheads = Vec::new();
-    for (row_index, row) in map.iter().enumerate() {
-        for (column_index, &value) in row.iter().enumerate() {
-            if value == 0 {
-                trailheads.push(Position {
-                    row: row_index,
-                    column: column_index,
-                });
-            }
-        }
+fn get_sum_of_all_trailhead_scores(map: &Grid<u8>) -> (u32, u32) {
+    let mut total_score = 0;
+    let mut total_rating = 0;
+    for (trailhead, _) in map.iter().filter(|(_, height)| **height == 0) {
+        let mut summits = HashSet::new();
+        let rating = walk_trailhead(trailhead, map, &mut summits);
+        total_score += summits.len() as u32;
+        total_rating += rating;
     }
-    trailheads
+    (total_score, total_rating)
 }
 
 fn walk_trailhead(
-    current_position: &Position,
-    map: &[Vec<u8>],
-    summits: &mut HashSet<Position>,
+    current_position: (usize, usize),
+    map: &Grid<u8>,
+    summits: &mut HashSet<(usize, usize)>,
 ) -> u32 {
-    let current_height = map[current_position.row][current_position.column];
+    let current_height = map[current_position];
     if current_height == 9 {
-        summits.insert(current_position.clone());
+        summits.insert(current_position);
         return 1;
     }
     let mut rating_sum = 0;
-    for neighbor in get_neighbors(current_position, map[0].len(), map.len()) {
-        let neighbor_height = map[neighbor.row][neighbor.column];
-        if neighbor_height == current_height + 1 {
-            let rating = walk_trailhead(&neighbor, map, summits);
+    for neighbor in map.neighbors4(current_position) {
+        if map[neighbor] == current_height + 1 {
+            let rating = walk_trailhead(neighbor, map, summits);
             rating_sum += rating;
         }
     }
     rating_sum
 }
 
-fn get_neighbors(position: &Position, map_width: usize, map_height: usize) -> Vec<Position> {
-    let mut neighbors = Vec::new();
-    if position.row > 0 {
-        neighbors.push(Position {
-            row: position.row - 1,
-            column: position.column,
-        });
-    }
-    if position.row < map_height - 1 {
-        neighbors.push(Position {
-            row: position.row + 1,
-            column: position.column,
-        });
-    }
-    if position.column > 0 {
-        neighbors.push(Position {
-            row: position.row,
-            column: position.column - 1,
-        });
+#[cfg(test)]
+mod part1_tests {
+    use super::*;
+
+    #[test]
+    fn test_1() {
+        let result = Solver::new(
+            r"89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732",
+        )
+        .unwrap()
+        .solve_part_one();
+        assert_eq!(result, "36");
     }
-    if position.column < map_width - 1 {
-        neighbors.push(Position {
-            row: position.row,
-            column: position.column + 1,
-        });
+}
+
+#[cfg(test)]
+mod part2_tests {
+    use super::*;
+
+    #[test]
+    fn test_1() {
+        let result = Solver::new(
+            r"89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732",
+        )
+        .unwrap()
+        .solve_part_two();
+        assert_eq!(result, "81");
     }
-    neighbors
 }
@@ -1,33 +1,55 @@
 use std::collections::HashMap;
 
-pub fn count_of_stones<const STEP_COUNT: usize>() -> u64 {
-    let stones = get_input();
+use solver::{Answer, ParseError, SolverBase, parse_field};
+
+pub struct Solver {
+    stones: Vec<u64>,
+}
+
+impl Solver {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let stones = input
+            .split_whitespace()
+            .map(parse_field)
+            .collect::<Result<_, _>>()
+            .map_err(|error| error.on_line(1, input.trim_end()))?;
+        Ok(Solver { stones })
+    }
+}
+
+impl SolverBase for Solver {
+    fn solve_part_one(&self) -> Answer {
+        count_of_stones::<25>(&self.stones).into()
+    }
+
+    fn solve_part_two(&self) -> Answer {
+        count_of_stones::<75>(&self.stones).into()
+    }
+
+    fn day_number(&self) -> usize {
+        11
+    }
+
+    fn description(&self) -> &'static str {
+        "Plutonian Pebbles"
+    }
+}
+
+fn count_of_stones<const STEP_COUNT: usize>(stones: &[u64]) -> u64 {
     let mut table: HashMap<u64, [u64; STEP_COUNT]> = HashMap::new();
-    //println!("{:?}", stones);
     let mut total_count = 0;
-    for stone in stones {
+    for &stone in stones {
         let count = blink(stone, 0, &mut table);
         total_count += count;
     }
     total_count
 }
 
-fn get_input() -> Vec<u64> {
-    //let input = "0 1 10 99 999";
-    //let input = "125 17";
-    let input = "112 1110 163902 0 7656027 83039 9 74";
-    input
-        .split_whitespace()
-        .map(|s| s.parse().unwrap())
-        .collect()
-}
-
 fn blink<const STEP_COUNT: usize>(
     stone: u64,
     depth: usize,
     table: &mut HashMap<u64, [u64; STEP_COUNT]>,
 ) -> u64 {
-    //println!("{:?} {:?}", stone, step_count);
     // stop the recursion
     if depth == STEP_COUNT {
         return 1;
@@ -38,7 +60,7 @@ fn blink<const STEP_COUNT: usize>(
         return table_lookup(new_stone_value, depth, table);
     }
     // If the stone is engraved with a number that has an even number of digits, it is replaced by two stones. The left half of the digits are engraved on the new left stone, and the right half of the digits are engraved on the new right stone. (The new numbers don't keep extra leading zeroes: 1000 would become stones 10 and 0.)
-    if stone.to_string().len() % 2 == 0 {
+    if stone.to_string().len().is_multiple_of(2) {
         let stone_str = stone.to_string();
         let half = stone_str.len() / 2;
         let left = stone_str[..half].parse().unwrap();
@@ -70,3 +92,25 @@ fn table_lookup<const STEP_COUNT: usize>(
     }
     value
 }
+
+#[cfg(test)]
+mod part1_tests {
+    use super::*;
+
+    #[test]
+    fn test_1() {
+        let result = Solver::new(r"125 17").unwrap().solve_part_one();
+        assert_eq!(result, "55312");
+    }
+}
+
+#[cfg(test)]
+mod part2_tests {
+    use super::*;
+
+    #[test]
+    fn test_1() {
+        let result = Solver::new(r"125 17").unwrap().solve_part_two();
+        assert_eq!(result, "65601038650482");
+    }
+}
@@ -1,8 +1,36 @@
 use itertools::Itertools;
+use solver::{Answer, ParseError, SolverBase};
 
-pub fn get_total_price_of_fencing() -> (u32, u32) {
-    let mut map = get_map();
-    visit_map(&mut map)
+pub struct Solver {
+    garden: Vec<Vec<char>>,
+}
+
+impl Solver {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        Ok(Solver {
+            garden: input.lines().map(|line| line.chars().collect()).collect(),
+        })
+    }
+}
+
+impl SolverBase for Solver {
+    fn solve_part_one(&self) -> Answer {
+        let (total_segment_price, _) = get_total_price_of_fencing(&self.garden);
+        total_segment_price.into()
+    }
+
+    fn solve_part_two(&self) -> Answer {
+        let (_, total_side_price) = get_total_price_of_fencing(&self.garden);
+        total_side_price.into()
+    }
+
+    fn day_number(&self) -> usize {
+        12
+    }
+
+    fn description(&self) -> &'static str {
+        "Garden Groups"
+    }
 }
 
 struct Plot {
@@ -19,52 +47,22 @@ struct Region {
     bottom_fences: Vec<(usize, usize)>,
 }
 
-fn get_map() -> Vec<Vec<Plot>> {
-    //     let input = r"AAAA
-    // BBCD
-    // BBCC
-    // EEEC";
-    // let input = r"OOOOO
-    // OXOXO
-    // OOOOO
-    // OXOXO
-    // OOOOO";
-    // let input = r"RRRRIICCFF
-    // RRRRIICCCF
-    // VVRRRCCFFF
-    // VVRCCCJFFF
-    // VVVVCJJCFE
-    // VVIVCCJJEE
-    // VVIIICJJEE
-    // MIIIIIJJEE
-    // MIIISIJEEE
-    // MMMISSJEEE";
-    //     let input = r"EEEEE
-    // EXXXX
-    // EEEEE
-    // EXXXX
-    // EEEEE";
-    // let input = r"AAAAAA
-    // AAABBA
-    // AAABBA
-    // ABBAAA
-    // ABBAAA
-    // AAAAAA";
-    let input = include_str!("../input/day12.txt");
-    input
-        .lines()
-        .map(|line| {
-            line.chars()
-                .map(|c| Plot {
-                    name: c,
+fn get_total_price_of_fencing(garden: &[Vec<char>]) -> (u32, u32) {
+    let mut map: Vec<Vec<Plot>> = garden
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|&name| Plot {
+                    name,
                     visited: false,
                 })
                 .collect()
         })
-        .collect()
+        .collect();
+    visit_map(&mut map)
 }
 
-fn visit_map(map: &mut Vec<Vec<Plot>>) -> (u32, u32) {
+fn visit_map(map: &mut [Vec<Plot>]) -> (u32, u32) {
     let mut total_segment_price = 0;
     let mut total_side_price = 0;
     for row_index in 0..map.len() {
@@ -73,13 +71,6 @@ fn visit_map(map: &mut Vec<Vec<Plot>>) -> (u32, u32) {
             if region.area > 0 {
                 let segment_price_of_region = region.fence_count * region.area;
                 total_segment_price += segment_price_of_region;
-                // println!(
-                //     "Region: {:?}, fence_count: {:?}, area: {:?}, price: {:?}",
-                //     map[row_index][column_index].name,
-                //     region.fence_count,
-                //     region.area,
-                //     segment_price_of_region,
-                // );
                 // calculate sides
 
                 let left_side_count = get_side_count_vertical(region.left_fences);
@@ -97,8 +88,6 @@ fn visit_map(map: &mut Vec<Vec<Plot>>) -> (u32, u32) {
                 let bottom_side_count = get_side_count_horizontal(region.bottom_fences);
                 let bottom_side_price_of_region = bottom_side_count as u32 * region.area;
                 total_side_price += bottom_side_price_of_region;
-
-                //println!("side prices: {:?}, {:?}, {:?}, {:?}", left_side_price_of_region, right_side_price_of_region, top_side_price_of_region, bottom_side_price_of_region);
             }
         }
     }
@@ -106,18 +95,18 @@ fn visit_map(map: &mut Vec<Vec<Plot>>) -> (u32, u32) {
 }
 
 fn get_side_count_horizontal(mut fences: Vec<(usize, usize)>) -> usize {
-    fences.sort_by(|a, b| a.0.cmp(&b.0));
+    fences.sort_by_key(|a| a.0);
     let mut sum_side_count = 0;
     for chunk in fences.into_iter().chunk_by(|a| a.0).into_iter() {
         let mut row_fences: Vec<(usize, usize)> = chunk.1.collect();
-        row_fences.sort_by(|a, b| a.1.cmp(&b.1));
+        row_fences.sort_by_key(|a| a.1);
         let mut side_count = 1;
         let mut previous_column_index: Option<usize> = None;
         for (_, column_index) in row_fences {
-            if let Some(previous_column_index) = previous_column_index {
-                if column_index - previous_column_index > 1 {
-                    side_count += 1;
-                }
+            if let Some(previous_column_index) = previous_column_index
+                && column_index - previous_column_index > 1
+            {
+                side_count += 1;
             }
             previous_column_index = Some(column_index);
         }
@@ -127,18 +116,18 @@ fn get_side_count_horizontal(mut fences: Vec<(usize, usize)>) -> usize {
 }
 
 fn get_side_count_vertical(mut fences: Vec<(usize, usize)>) -> usize {
-    fences.sort_by(|a, b| a.1.cmp(&b.1));
+    fences.sort_by_key(|a| a.1);
     let mut sum_side_count = 0;
     for chunk in fences.into_iter().chunk_by(|a| a.1).into_iter() {
         let mut column_fences: Vec<(usize, usize)> = chunk.1.collect();
-        column_fences.sort_by(|a, b| a.0.cmp(&b.0));
+        column_fences.sort_by_key(|a| a.0);
         let mut side_count = 1;
         let mut previous_row_index: Option<usize> = None;
         for (row_index, _) in column_fences {
-            if let Some(previous_column_index) = previous_row_index {
-                if row_index - previous_column_index > 1 {
-                    side_count += 1;
-                }
+            if let Some(previous_row_index) = previous_row_index
+                && row_index - previous_row_index > 1
+            {
+                side_count += 1;
             }
             previous_row_index = Some(row_index);
         }
@@ -147,7 +136,7 @@ fn get_side_count_vertical(mut fences: Vec<(usize, usize)>) -> usize {
     sum_side_count
 }
 
-fn visit_plot(row_index: usize, column_index: usize, map: &mut Vec<Vec<Plot>>) -> Region {
+fn visit_plot(row_index: usize, column_index: usize, map: &mut [Vec<Plot>]) -> Region {
     let name = map[row_index][column_index].name;
     if map[row_index][column_index].visited {
         return Region {
@@ -227,3 +216,51 @@ fn visit_plot(row_index: usize, column_index: usize, map: &mut Vec<Vec<Plot>>) -
 
     sum_region
 }
+
+#[cfg(test)]
+mod part1_tests {
+    use super::*;
+
+    #[test]
+    fn test_1() {
+        let result = Solver::new(
+            r"RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE",
+        )
+        .unwrap()
+        .solve_part_one();
+        assert_eq!(result, "1930");
+    }
+}
+
+#[cfg(test)]
+mod part2_tests {
+    use super::*;
+
+    #[test]
+    fn test_1() {
+        let result = Solver::new(
+            r"RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE",
+        )
+        .unwrap()
+        .solve_part_two();
+        assert_eq!(result, "1206");
+    }
+}
@@ -1,17 +1,50 @@
-pub fn fewest_tokens_to_win_all_possible_prices(use_units_correction: bool) -> i64 {
-    let mut machines = get_input();
-    if use_units_correction {
-        for machine in machines.iter_mut() {
-            machine.price_location_x += 10000000000000;
-            machine.price_location_y += 10000000000000;
+use solver::{Answer, ParseError, SolverBase, integer_array, parse_lines, sections};
+
+pub struct Solver {
+    machines: Vec<Machine>,
+}
+
+impl Solver {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        Ok(Solver {
+            machines: parse_machines(input)?,
+        })
+    }
+}
+
+impl SolverBase for Solver {
+    fn solve_part_one(&self) -> Answer {
+        self.fewest_tokens_to_win_all_possible_prices(false).into()
+    }
+
+    fn solve_part_two(&self) -> Answer {
+        self.fewest_tokens_to_win_all_possible_prices(true).into()
+    }
+
+    fn day_number(&self) -> usize {
+        13
+    }
+
+    fn description(&self) -> &'static str {
+        "Claw Contraption"
+    }
+}
+
+impl Solver {
+    fn fewest_tokens_to_win_all_possible_prices(&self, use_units_correction: bool) -> i64 {
+        let mut machines = self.machines.clone();
+        if use_units_correction {
+            for machine in machines.iter_mut() {
+                machine.price_location_x += 10000000000000;
+                machine.price_location_y += 10000000000000;
+            }
         }
+        machines
+            .iter()
+            .filter_map(calculate_pushes)
+            .map(calculate_price)
+            .sum()
     }
-    let total_price = machines
-        .iter()
-        .filter_map(calculate_pushes)
-        .map(calculate_price)
-        .sum();
-    total_price
 }
 
 #[derive(Debug, Default, Clone)]
@@ -25,50 +58,37 @@ struct Machine {
     price_location_y: i64,
 }
 
-fn get_input() -> Vec<Machine> {
-    //     let input = r"Button A: X+94, Y+34
-    // Button B: X+22, Y+67
-    // Prize: X=8400, Y=5400
-
-    // Button A: X+26, Y+66
-    // Button B: X+67, Y+21
-    // Prize: X=12748, Y=12176
-
-    // Button A: X+17, Y+86
-    // Button B: X+84, Y+37
-    // Prize: X=7870, Y=6450
-
-    // Button A: X+69, Y+23
-    // Button B: X+27, Y+71
-    // Prize: X=18641, Y=10279";
-    let input = include_str!("../input/day13.txt");
-
-    let mut machines = vec![];
-    let mut current_machine: Machine = Default::default();
-    for line in input.lines() {
-        let line_parts: Vec<_> = line
-            .split([':', ' ', '+', ',', '='])
-            .filter(|x| !x.is_empty())
-            .collect();
-        match &line_parts[..] {
-            ["Button", "A", "X", x_str, "Y", y_str] => {
-                current_machine.a_x_increment = x_str.parse().unwrap();
-                current_machine.a_y_increment = y_str.parse().unwrap();
-            }
-            ["Button", "B", "X", x_str, "Y", y_str] => {
-                current_machine.b_x_increment = x_str.parse().unwrap();
-                current_machine.b_y_increment = y_str.parse().unwrap();
-            }
-            ["Prize", "X", x_str, "Y", y_str] => {
-                current_machine.price_location_x = x_str.parse().unwrap();
-                current_machine.price_location_y = y_str.parse().unwrap();
-                machines.push(current_machine.clone());
-            }
-            _ => {}
-        }
-    }
-
-    machines
+fn parse_machines(input: &str) -> Result<Vec<Machine>, ParseError> {
+    sections(input)
+        .into_iter()
+        .map(|(section, first_line)| {
+            let lines = parse_lines(section, |line| {
+                let prefix = line.split(':').next().unwrap_or_default();
+                if !["Button A", "Button B", "Prize"].contains(&prefix) {
+                    return Err(ParseError::new("expected a button or a prize"));
+                }
+                integer_array::<i64, 2>(line).map(|numbers| (prefix, numbers))
+            })
+            .map_err(|error| error.below(first_line - 1))?;
+            let [
+                ("Button A", [a_x_increment, a_y_increment]),
+                ("Button B", [b_x_increment, b_y_increment]),
+                ("Prize", [price_location_x, price_location_y]),
+            ] = lines[..]
+            else {
+                return Err(ParseError::new("expected button A, button B and the prize")
+                    .on_line(first_line, section.lines().next().unwrap_or_default()));
+            };
+            Ok(Machine {
+                a_x_increment,
+                a_y_increment,
+                b_x_increment,
+                b_y_increment,
+                price_location_x,
+                price_location_y,
+            })
+        })
+        .collect()
 }
 
 fn calculate_price((a, b): (i64, i64)) -> i64 {
@@ -99,3 +119,32 @@ fn calculate_pushes(machine: &Machine) -> Option<(i64, i64)> {
 
     Some((x, y))
 }
+
+#[cfg(test)]
+mod part1_tests {
+    use super::*;
+
+    #[test]
+    fn test_1() {
+        let result = Solver::new(
+            r"Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279",
+        )
+        .unwrap()
+        .solve_part_one();
+        assert_eq!(result, "480");
+    }
+}
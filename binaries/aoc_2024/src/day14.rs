@@ -1,55 +1,67 @@
-use crossterm::event;
-use crossterm::{cursor, execute, style::Print, terminal};
-use std::collections::HashMap;
+use solver::{Answer, ParseError, SolverBase, crt, integer_array, parse_lines};
 
-pub fn calculate_safety_factor() -> i32 {
-    let mut robots = get_input();
-    let space_width = 11;
-    let space_height = 7;
-    let seconds = 100;
-    print_robots(&robots, space_width, space_height, 0);
-    println!("moving robots...");
-    move_robots(&mut robots, seconds, space_width, space_height);
-    print_robots(&robots, space_width, space_height, 0);
-
-    get_safety_factor(&robots, space_width, space_height)
+pub struct Solver {
+    robots: Vec<Robot>,
+    space_width: i32,
+    space_height: i32,
 }
 
-pub fn show_easter_egg() {
-    let mut robots = get_input();
-    let space_width = 101;
-    let space_height = 103;
-    let seconds = space_width * space_height;
-    print_robots(&robots, space_width, space_height, 0);
+impl Solver {
+    pub fn new(input: &str, space_width: i32, space_height: i32) -> Result<Self, ParseError> {
+        Ok(Solver {
+            robots: parse_robots(input)?,
+            space_width,
+            space_height,
+        })
+    }
+}
 
-    let mut safety_factors: Vec<(i32, i32)> = Vec::new();
+impl SolverBase for Solver {
+    fn solve_part_one(&self) -> Answer {
+        let mut robots = self.robots.clone();
+        move_robots(&mut robots, 100, self.space_width, self.space_height);
+        get_safety_factor(&robots, self.space_width, self.space_height).into()
+    }
 
-    for s in 1..seconds {
-        move_robots(&mut robots, 1, space_width, space_height);
-        let safety_factor = get_safety_factor(&robots, space_width, space_height);
-        safety_factors.push((s, safety_factor));
+    fn solve_part_two(&self) -> Answer {
+        // the robots form the easter egg picture when they are gathered together. Their x coordinates repeat every
+        // space_width seconds and their y coordinates every space_height seconds, so the most gathered x and y
+        // are found separately and the second when both happen is given by the chinese remainder theorem
+        let most_gathered = |size: i32, coordinate: fn(&Robot) -> (i32, i32)| {
+            (0..size)
+                .min_by_key(|&second| {
+                    spread(self.robots.iter().map(|robot| {
+                        let (position, velocity) = coordinate(robot);
+                        (position + velocity * second).rem_euclid(size)
+                    }))
+                })
+                .unwrap() as i64
+        };
+        let second_x = most_gathered(self.space_width, |robot| {
+            (robot.position_x, robot.velocity_x)
+        });
+        let second_y = most_gathered(self.space_height, |robot| {
+            (robot.position_y, robot.velocity_y)
+        });
+        match crt(&[
+            (second_x, self.space_width as i64),
+            (second_y, self.space_height as i64),
+        ]) {
+            Some((second, _)) => second.into(),
+            None => Answer::NoSolution,
+        }
     }
 
-    let mut robots = get_input();
-    // sort by lowest safety factor
-    safety_factors.sort_by(|a, b| a.1.cmp(&b.1));
-    for (second, factor) in safety_factors {
-        println!("second: {:?}, safety factor: {:?}", second, factor);
-        move_robots(&mut robots, second, space_width, space_height);
-        print_robots(&robots, space_width, space_height, second);
+    fn day_number(&self) -> usize {
+        14
+    }
 
-        loop {
-            if event::poll(std::time::Duration::from_millis(500)).unwrap() {
-                if let event::Event::Key(_key_event) = event::read().unwrap() {
-                    _ = event::read().unwrap();
-                    break; // Exit the loop on key press
-                }
-            }
-        }
+    fn description(&self) -> &'static str {
+        "Restroom Redoubt"
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct Robot {
     position_x: i32,
     position_y: i32,
@@ -57,36 +69,22 @@ struct Robot {
     velocity_y: i32,
 }
 
-fn get_input() -> Vec<Robot> {
-    //     let input = r"p=0,4 v=3,-3
-    // p=6,3 v=-1,-3
-    // p=10,3 v=-1,2
-    // p=2,0 v=2,-1
-    // p=0,0 v=1,3
-    // p=3,0 v=-2,-2
-    // p=7,6 v=-1,-3
-    // p=3,0 v=-1,-2
-    // p=9,3 v=2,3
-    // p=7,3 v=-1,2
-    // p=2,4 v=2,-3
-    // p=9,5 v=-3,-3";
-    let input = include_str!("../input/day14.txt");
-    let mut robots: Vec<Robot> = Vec::new();
-    for line in input.lines() {
-        let parts: Vec<&str> = line.split(['=', ',', ' ']).collect();
-        if let ["p", px, py, "v", vx, vy] = parts.as_slice() {
-            robots.push(Robot {
-                position_x: px.parse().unwrap(),
-                position_y: py.parse().unwrap(),
-                velocity_x: vx.parse().unwrap(),
-                velocity_y: vy.parse().unwrap(),
-            });
+fn parse_robots(input: &str) -> Result<Vec<Robot>, ParseError> {
+    parse_lines(input, |line| {
+        if !line.starts_with("p=") || !line.contains(" v=") {
+            return Err(ParseError::new("expected robot `p=<x>,<y> v=<x>,<y>`"));
         }
-    }
-    robots
+        let [position_x, position_y, velocity_x, velocity_y] = integer_array(line)?;
+        Ok(Robot {
+            position_x,
+            position_y,
+            velocity_x,
+            velocity_y,
+        })
+    })
 }
 
-fn move_robots(robots: &mut Vec<Robot>, seconds: i32, space_width: i32, space_height: i32) {
+fn move_robots(robots: &mut [Robot], seconds: i32, space_width: i32, space_height: i32) {
     for robot in robots {
         let new_position_x = (robot.position_x + robot.velocity_x * seconds) % space_width;
         if new_position_x < 0 {
@@ -104,91 +102,16 @@ fn move_robots(robots: &mut Vec<Robot>, seconds: i32, space_width: i32, space_he
     }
 }
 
-fn print_robots(
-    robots: &Vec<Robot>,
-    space_width: i32,
-    space_height: i32,
-    current_second: i32,
-) -> bool {
-    // Set terminal to raw mode so we can capture key presses directly
-    //terminal::enable_raw_mode().unwrap();
-
-    //let (width, height) = terminal::size()?; // Get terminal dimensions
-
-    let mut robot_positions: HashMap<(i32, i32), i32> = HashMap::new();
-
-    let mut stdout = std::io::stdout();
-    execute!(stdout, terminal::Clear(terminal::ClearType::All)).unwrap(); // Clear the terminal
-
-    for y in 0..space_height {
-        execute!(
-            stdout,
-            cursor::MoveTo(0, y as u16),
-            Print(".".repeat(space_width as usize))
-        )
-        .unwrap();
-    }
-
-    for robot in robots {
-        let count = robot_positions
-            .entry((robot.position_x, robot.position_y))
-            .or_insert(0);
-        *count += 1;
-        execute!(
-            stdout,
-            cursor::MoveTo(robot.position_x as u16, robot.position_y as u16),
-            if *count > 9 {
-                Print("#".to_string())
-            } else {
-                Print((*count).to_string())
-            }
-        )
-        .unwrap();
-
-        execute!(
-            stdout,
-            cursor::MoveTo(0, space_height as u16),
-            Print(format!("second: {current_second}"))
-        )
-        .unwrap();
-    }
-
-    let weird = robot_positions.values().any(|&x| x >= 6);
-    weird
-    // println!("Press any key to continue...");
-
-    // // Wait for a key press
-    // loop {
-    //     if event::poll(std::time::Duration::from_millis(500)).unwrap() {
-    //         if let event::Event::Key(key_event) = event::read().unwrap() {
-    //             println!("You pressed: {:?}", key_event);
-    //             break; // Exit the loop on key press
-    //         }
-    //     }
-    // }
-
-    // Reset the terminal to normal mode
-    //terminal::disable_raw_mode().unwrap();
-    //execute!(stdout, terminal::LeaveAlternateScreen).unwrap();
-
-    // for y in 0..height {
-    //     execute!(stdout, cursor::MoveTo(0, y), Print("#".repeat(width as usize)))?;
-    // }
-    // Ok(())
-
-    // let mut space = vec![vec!['.'; space_width as usize]; space_height as usize];
-    // for robot in robots {
-    //     space[robot.position_y as usize][robot.position_x as usize] = '#';
-    // }
-    // for row in space {
-    //     for cell in row {
-    //         print!("{}", cell);
-    //     }
-    //     println!();
-    // }
+/// Variance of the values, scaled by the square of their count to stay an integer
+fn spread(values: impl Iterator<Item = i32>) -> i64 {
+    let (count, sum, sum_of_squares) = values.fold((0, 0, 0), |(count, sum, squares), value| {
+        let value = value as i64;
+        (count + 1, sum + value, squares + value * value)
+    });
+    count * sum_of_squares - sum * sum
 }
 
-fn get_safety_factor(robots: &Vec<Robot>, space_width: i32, space_height: i32) -> i32 {
+fn get_safety_factor(robots: &[Robot], space_width: i32, space_height: i32) -> i32 {
     let mut robot_counts = [0, 0, 0, 0];
     let half_x = space_width / 2;
     let half_y = space_height / 2;
@@ -213,3 +136,31 @@ fn get_safety_factor(robots: &Vec<Robot>, space_width: i32, space_height: i32) -
     }
     robot_counts.iter().product()
 }
+
+#[cfg(test)]
+mod part1_tests {
+    use super::*;
+
+    #[test]
+    fn test_1() {
+        let result = Solver::new(
+            r"p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3",
+            11,
+            7,
+        )
+        .unwrap()
+        .solve_part_one();
+        assert_eq!(result, "12");
+    }
+}
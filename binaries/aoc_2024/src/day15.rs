@@ -1,11 +1,36 @@
-use crossterm::event;
+use solver::{Answer, Direction, ParseError, Point2, SolverBase, parse_lines, split_sections};
 
-#[derive(Debug, Clone, Copy)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
+pub struct Solver {
+    map: Vec<Vec<Location>>,
+    moves: Vec<Direction>,
+}
+
+impl Solver {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let (map, moves, moves_line) = split_sections(input)?;
+        Ok(Solver {
+            map: parse_map(map)?,
+            moves: parse_moves(moves).map_err(|error| error.below(moves_line - 1))?,
+        })
+    }
+}
+
+impl SolverBase for Solver {
+    fn solve_part_one(&self) -> Answer {
+        sum_of_all_boxes_gps_coordinates(&self.map, &self.moves, false).into()
+    }
+
+    fn solve_part_two(&self) -> Answer {
+        sum_of_all_boxes_gps_coordinates(&self.map, &self.moves, true).into()
+    }
+
+    fn day_number(&self) -> usize {
+        15
+    }
+
+    fn description(&self) -> &'static str {
+        "Warehouse Woes"
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -23,180 +48,84 @@ enum Location {
     Robot,
 }
 
-#[derive(Debug, Clone, Copy)]
-struct Position {
-    x: usize,
-    y: usize,
-}
-
-impl Position {
-    fn move_in_direction(&self, direction: Direction) -> Position {
-        match direction {
-            Direction::Up => Position {
-                x: self.x,
-                y: self.y - 1,
-            },
-            Direction::Down => Position {
-                x: self.x,
-                y: self.y + 1,
-            },
-            Direction::Left => Position {
-                x: self.x - 1,
-                y: self.y,
-            },
-            Direction::Right => Position {
-                x: self.x + 1,
-                y: self.y,
-            },
-        }
-    }
+type Position = Point2<usize>;
 
-    // fn gps(&self) -> u64 {
-    //     (self.y as u64) * 100 + (self.x as u64)
-    // }
+// The map is surrounded by walls, so nothing moves off its top or left edge
+fn move_in_direction(position: Position, direction: Direction) -> Position {
+    position.checked_step(direction).expect("moved off the map")
 }
 
-pub fn sum_of_all_boxes_gps_coordinates(use_double_map: bool) -> u64 {
-    let mut map = get_map();
-    let _moves = get_moves();
-
-    if use_double_map {
-        map = double_map(&map);
-    }
-
-    print_map(&map);
+fn sum_of_all_boxes_gps_coordinates(
+    map: &[Vec<Location>],
+    moves: &[Direction],
+    use_double_map: bool,
+) -> u64 {
+    let mut map = if use_double_map {
+        double_map(map)
+    } else {
+        map.to_vec()
+    };
 
     let mut robot_position = find_robot_position(&map);
-
-    // for direction in moves {
-    //     if is_allowed_to_move(&map, robot_position, direction) {
-    //         move_object(&mut map, robot_position, direction);
-    //         map[robot_position.y][robot_position.x] = Location::Empty;
-    //         robot_position = robot_position.move_in_direction(direction);
-    //     }
-    //     //print_map(&map);
-    // }
-
-    // print_map(&map);
-
-    loop {
-        let direction = loop {
-            if event::poll(std::time::Duration::from_millis(500)).unwrap() {
-                if let event::Event::Key(_key_event) = event::read().unwrap() {
-                    let key = event::read().unwrap();
-                    if let event::Event::Key(key) = key {
-                        match key.code {
-                            event::KeyCode::Up => break Direction::Up,
-                            event::KeyCode::Down => break Direction::Down,
-                            event::KeyCode::Left => break Direction::Left,
-                            event::KeyCode::Right => break Direction::Right,
-                            _ => (),
-                        }
-                    }
-                }
-            }
-        };
-
-        println!("direction: {:?}", direction);
+    for &direction in moves {
         if is_allowed_to_move(&map, robot_position, direction) {
-            //println!("next move: {:?}", direction);
             move_object(&mut map, robot_position, direction);
             map[robot_position.y][robot_position.x] = Location::Empty;
-            robot_position = robot_position.move_in_direction(direction);
+            robot_position = move_in_direction(robot_position, direction);
         }
-        print_map(&map);
     }
 
-    //calculate_sum_of_all_boxes_gps_coordinates(&map)
+    calculate_sum_of_all_boxes_gps_coordinates(&map)
 }
 
 fn find_robot_position(map: &[Vec<Location>]) -> Position {
     for (y, line) in map.iter().enumerate() {
         for (x, location) in line.iter().enumerate() {
             if let Location::Robot = location {
-                return Position { x, y };
+                return Position::new(x, y);
             }
         }
     }
     panic!("robot not found in map");
 }
 
-fn get_map() -> Vec<Vec<Location>> {
-    //     let input = r"##########
-    // #..O..O.O#
-    // #......O.#
-    // #.OO..O.O#
-    // #..O@..O.#
-    // #O#..O...#
-    // #O..O..O.#
-    // #.OO.O.OO#
-    // #....O...#
-    // ##########";
-    //     let input = r"########
-    // #..O.O.#
-    // ##@.O..#
-    // #...O..#
-    // #.#.O..#
-    // #...O..#
-    // #......#
-    // ########";
-    //     let input = r"#######
-    // #...#.#
-    // #.....#
-    // #..OO@#
-    // #..O..#
-    // #.....#
-    // #######";
-    let input = include_str!("../input/day15_map.txt");
-    let lines = input.lines();
-    let map: Vec<Vec<Location>> = lines
-        .map(|line| {
-            line.chars()
-                .map(|c| match c {
-                    '.' => Location::Empty,
-                    '#' => Location::Wall,
-                    'O' => Location::Box,
-                    '@' => Location::Robot,
-                    _ => panic!("unexpected character in map"),
-                })
-                .collect()
-        })
-        .collect();
-    map
+fn parse_map(input: &str) -> Result<Vec<Vec<Location>>, ParseError> {
+    parse_lines(input, |line| {
+        line.chars()
+            .enumerate()
+            .map(|(column, c)| match c {
+                '.' => Ok(Location::Empty),
+                '#' => Ok(Location::Wall),
+                'O' => Ok(Location::Box),
+                '@' => Ok(Location::Robot),
+                _ => Err(ParseError::expected("`.`, `#`, `O` or `@`", &c.to_string())
+                    .at_column(column + 1)),
+            })
+            .collect()
+    })
 }
 
-fn get_moves() -> Vec<Direction> {
-    //     let input = r"<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
-    // vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
-    // ><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
-    // <<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
-    // ^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
-    // ^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
-    // >^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
-    // <><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
-    // ^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
-    // v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^";
-    //let input = "<^^>>>vv<v>>v<<";
-    let input = include_str!("../input/day15_moves.txt");
-    //let input = "<vv<<^^<<^^";
-
-    let moves: Vec<Direction> = input
-        .chars()
-        .filter_map(|c| match c {
-            '\r' | '\n' => None,
-            '^' => Some(Direction::Up),
-            'v' => Some(Direction::Down),
-            '<' => Some(Direction::Left),
-            '>' => Some(Direction::Right),
-            _ => panic!("unexpected character in moves"),
-        })
-        .collect();
-    moves
+fn parse_moves(input: &str) -> Result<Vec<Direction>, ParseError> {
+    let lines = parse_lines(input, |line| {
+        line.trim_end_matches('\r')
+            .chars()
+            .enumerate()
+            .map(|(column, c)| match c {
+                '^' => Ok(Direction::Up),
+                'v' => Ok(Direction::Down),
+                '<' => Ok(Direction::Left),
+                '>' => Ok(Direction::Right),
+                _ => Err(ParseError::expected("`^`, `v`, `<` or `>`", &c.to_string())
+                    .at_column(column + 1)),
+            })
+            .collect::<Result<Vec<_>, _>>()
+    })?;
+    Ok(lines.concat())
 }
 
-fn move_object(map: &mut Vec<Vec<Location>>, position: Position, direction: Direction) {
+fn move_object(map: &mut [Vec<Location>], position: Position, direction: Direction) {
     let location = map[position.y][position.x];
-    let new_position = position.move_in_direction(direction);
+    let new_position = move_in_direction(position, direction);
     let new_location = map[new_position.y][new_position.x];
     match new_location {
         Location::Empty => {
@@ -214,9 +143,9 @@ fn move_object(map: &mut Vec<Vec<Location>>, position: Position, direction: Dire
             }
             Direction::Up | Direction::Down => {
                 let other_part_new_position = if box_part == BoxPart::Left {
-                    new_position.move_in_direction(Direction::Right)
+                    move_in_direction(new_position, Direction::Right)
                 } else {
-                    new_position.move_in_direction(Direction::Left)
+                    move_in_direction(new_position, Direction::Left)
                 };
                 move_object(map, new_position, direction);
                 move_object(map, other_part_new_position, direction);
@@ -229,7 +158,7 @@ fn move_object(map: &mut Vec<Vec<Location>>, position: Position, direction: Dire
 }
 
 fn is_allowed_to_move(map: &[Vec<Location>], position: Position, direction: Direction) -> bool {
-    let new_position = position.move_in_direction(direction);
+    let new_position = move_in_direction(position, direction);
     let new_location = map[new_position.y][new_position.x];
 
     match new_location {
@@ -240,9 +169,9 @@ fn is_allowed_to_move(map: &[Vec<Location>], position: Position, direction: Dire
             Direction::Left | Direction::Right => is_allowed_to_move(map, new_position, direction),
             Direction::Up | Direction::Down => {
                 let other_part_new_position = if box_part == BoxPart::Left {
-                    new_position.move_in_direction(Direction::Right)
+                    move_in_direction(new_position, Direction::Right)
                 } else {
-                    new_position.move_in_direction(Direction::Left)
+                    move_in_direction(new_position, Direction::Left)
                 };
 
                 let allowed_1 = is_allowed_to_move(map, new_position, direction);
@@ -254,23 +183,7 @@ fn is_allowed_to_move(map: &[Vec<Location>], position: Position, direction: Dire
     }
 }
 
-fn print_map(map: &Vec<Vec<Location>>) {
-    for line in map {
-        for location in line {
-            match location {
-                Location::Empty => print!("."),
-                Location::Wall => print!("#"),
-                Location::Box => print!("O"),
-                Location::DoubleBox(BoxPart::Left) => print!("["),
-                Location::DoubleBox(BoxPart::Right) => print!("]"),
-                Location::Robot => print!("@"),
-            }
-        }
-        println!();
-    }
-}
-
-fn _calculate_sum_of_all_boxes_gps_coordinates(map: &[Vec<Location>]) -> u64 {
+fn calculate_sum_of_all_boxes_gps_coordinates(map: &[Vec<Location>]) -> u64 {
     let mut sum = 0;
     for (y, line) in map.iter().enumerate() {
         for (x, location) in line.iter().enumerate() {
@@ -299,3 +212,73 @@ fn double_map(map: &[Vec<Location>]) -> Vec<Vec<Location>> {
         })
         .collect()
 }
+
+#[cfg(test)]
+mod part1_tests {
+    use super::*;
+
+    #[test]
+    fn test_1() {
+        let result = Solver::new(
+            r"##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^",
+        )
+        .unwrap()
+        .solve_part_one();
+        assert_eq!(result, "10092");
+    }
+}
+
+#[cfg(test)]
+mod part2_tests {
+    use super::*;
+
+    #[test]
+    fn test_1() {
+        let result = Solver::new(
+            r"##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^",
+        )
+        .unwrap()
+        .solve_part_two();
+        assert_eq!(result, "9021");
+    }
+}
@@ -1,25 +1,43 @@
 use itertools::Itertools;
-use rayon::prelude::*;
+use solver::{Answer, Cpu, Machine, ParseError, SolverBase, Step, parse_field, parse_lines};
 
-#[derive(Clone)]
-struct Computer {
-    instruction_pointer: usize,
-    register_a: u64,
-    register_b: u64,
-    register_c: u64,
+pub struct Solver {
+    registers: [u64; 3],
+    program: Program,
 }
 
-impl std::fmt::Debug for Computer {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("Computer")
-            .field("instruction_pointer", &self.instruction_pointer)
-            .field("register_a", &format_args!("{:o}", self.register_a))
-            .field("register_b", &format_args!("{:o}", self.register_b))
-            .field("register_c", &format_args!("{:o}", self.register_c))
-            .finish()
+impl Solver {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let (registers, program) = parse_registers_and_program(input)?;
+        Ok(Solver { registers, program })
     }
 }
 
+impl SolverBase for Solver {
+    fn solve_part_one(&self) -> Answer {
+        run_program(&self.program, self.registers)
+            .iter()
+            .join(",")
+            .into()
+    }
+
+    fn solve_part_two(&self) -> Answer {
+        lowest_positive_value_of_register_a_to_print_copy_of_itself(&self.program).into()
+    }
+
+    fn day_number(&self) -> usize {
+        17
+    }
+
+    fn description(&self) -> &'static str {
+        "Chronospatial Computer"
+    }
+}
+
+const REGISTER_A: usize = 0;
+const REGISTER_B: usize = 1;
+const REGISTER_C: usize = 2;
+
 #[derive(Debug, Clone, Copy)]
 #[allow(non_camel_case_types)]
 enum InstructionType {
@@ -34,7 +52,7 @@ enum InstructionType {
 }
 
 impl std::str::FromStr for InstructionType {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
@@ -46,7 +64,7 @@ impl std::str::FromStr for InstructionType {
             "out" | "5" => Ok(InstructionType::out),
             "bdv" | "6" => Ok(InstructionType::bdv),
             "cdv" | "7" => Ok(InstructionType::cdv),
-            _ => Err(()),
+            _ => Err(ParseError::expected("an opcode from 0 to 7", s)),
         }
     }
 }
@@ -58,15 +76,12 @@ struct Instruction {
 }
 
 impl Instruction {
-    fn get_combo_operand_value(&self, computer: &Computer) -> u64 {
+    fn get_combo_operand_value(&self, cpu: &Cpu<u64>) -> u64 {
         match self.operand {
-            0 => 0,
-            1 => 1,
-            2 => 2,
-            3 => 3,
-            4 => computer.register_a,
-            5 => computer.register_b,
-            6 => computer.register_c,
+            0..=3 => self.operand as u64,
+            4 => cpu.registers[REGISTER_A],
+            5 => cpu.registers[REGISTER_B],
+            6 => cpu.registers[REGISTER_C],
             7 => panic!("Reserved value"),
             _ => panic!("Invalid operand value"),
         }
@@ -75,168 +90,83 @@ impl Instruction {
     fn get_literal_operand_value(&self) -> u64 {
         self.operand as u64
     }
+
+    /// A divided by 2 to the power of the combo operand, truncated
+    fn divide(&self, cpu: &Cpu<u64>) -> u64 {
+        let denominator = 2_u64.pow(self.get_combo_operand_value(cpu) as u32);
+        cpu.registers[REGISTER_A] / denominator
+    }
 }
 
-type Program = Vec<Instruction>;
+impl solver::Instruction for Instruction {
+    type Value = u64;
 
-pub fn run_chronospacial_computer_program() -> String {
-    let (mut computer, program) = get_input();
-    println!("Computer: {:?}, Program: {:?}", computer, program);
-    run_program(&mut computer, &program).iter().join(",")
+    fn execute(&self, cpu: &mut Cpu<u64>) -> Step {
+        match self.operation {
+            InstructionType::adv => cpu.registers[REGISTER_A] = self.divide(cpu),
+            InstructionType::bxl => cpu.registers[REGISTER_B] ^= self.get_literal_operand_value(),
+            InstructionType::bst => {
+                cpu.registers[REGISTER_B] = self.get_combo_operand_value(cpu) % 8;
+            }
+            // The operand is an address in the program, where each instruction takes two numbers
+            InstructionType::jnz if cpu.registers[REGISTER_A] != 0 => {
+                return Step::JumpTo(self.get_literal_operand_value() as usize / 2);
+            }
+            InstructionType::jnz => {}
+            // For legacy reasons, bxc reads an operand but ignores it
+            InstructionType::bxc => cpu.registers[REGISTER_B] ^= cpu.registers[REGISTER_C],
+            InstructionType::out => cpu.write(self.get_combo_operand_value(cpu) % 8),
+            InstructionType::bdv => cpu.registers[REGISTER_B] = self.divide(cpu),
+            InstructionType::cdv => cpu.registers[REGISTER_C] = self.divide(cpu),
+        }
+        Step::Next
+    }
 }
 
-pub fn lowest_positive_value_of_register_a_to_print_copy_of_itself() -> u64 {
-    let (_, program) = get_input();
-
-    search_a(
-        &mut Computer {
-            register_a: 0,
-            register_b: 0,
-            register_c: 0,
-            instruction_pointer: 0,
-        },
-        (program.len() * 2) - 1,
-        &program,
-    )
-    .unwrap()
+type Program = Vec<Instruction>;
 
-    // let program_input: Vec<u64> = program.iter().flat_map(|inst| [inst.operation as u8 as u64, inst.operand as u64]).collect();
-    // println!("Program input: {}", program_input.iter().join(","));
-    // for register_a in (105843716614554..) {
-    //     computer.register_a = register_a;
-    //     computer.register_b = 0;
-    //     computer.register_c = 0;
-    //     computer.instruction_pointer = 0;
-    //     let program_output = run_program(&mut computer, &program);
-    //     //if register_a % 500000 == 0 {
-    //         // println!("Testing register A: {:o}", register_a);
-    //         // println!("Program output: {}", program_output);
-    //         // println!();
-    //     //}
-    //     if program_output == program_input {
-    //         return register_a;
-    //     }
-    // }
-    // 0
+fn lowest_positive_value_of_register_a_to_print_copy_of_itself(program: &Program) -> u64 {
+    search_a(0, (program.len() * 2) - 1, program).unwrap()
 }
 
-fn search_a(memory: &mut Computer, iteration: usize, program: &Program) -> Option<u64> {
+fn search_a(register_a: u64, iteration: usize, program: &Program) -> Option<u64> {
+    let words: Vec<u64> = program
+        .iter()
+        .flat_map(|int| [int.operation as u8 as u64, int.operand as u64])
+        .collect();
     for remainder in 0..8 {
         let multiplier = 8u64.pow(iteration as u32);
+        let candidate = register_a + multiplier * remainder;
 
-        if memory.register_a + multiplier * remainder < 8u64.pow(program.len() as u32 - 1) {
+        if candidate < 8u64.pow(program.len() as u32 - 1) {
             continue;
         }
 
-        let result = run_program(
-            &mut Computer {
-                register_a: memory.register_a + multiplier * remainder,
-                register_b: memory.register_b,
-                register_c: memory.register_c,
-                instruction_pointer: 0,
-            },
-            program,
-        );
-
-        if result[iteration]
-            == program
-                .iter()
-                .flat_map(|int| [int.operation as u8 as u64, int.operand as u64])
-                .collect::<Vec<_>>()[iteration]
-        {
-            return if iteration == 0 {
-                Some(memory.register_a + multiplier * remainder)
-            } else if let Some(register_a) = search_a(
-                &mut Computer {
-                    register_a: memory.register_a + multiplier * remainder,
-                    register_b: memory.register_b,
-                    register_c: memory.register_c,
-                    instruction_pointer: 0,
-                },
-                iteration - 1,
-                program,
-            ) {
-                Some(register_a)
-            } else {
-                continue;
-            };
+        let result = run_program(program, [candidate, 0, 0]);
+        if result.get(iteration) == Some(&words[iteration]) {
+            if iteration == 0 {
+                return Some(candidate);
+            } else if let Some(register_a) = search_a(candidate, iteration - 1, program) {
+                return Some(register_a);
+            }
         }
     }
 
     None
 }
 
-pub fn _lowest_positive_value_of_register_a_to_print_copy_of_itself_parallel() -> u64 {
-    let (computer_template, program) = get_input();
-    let program_input: Vec<u64> = program
-        .iter()
-        .flat_map(|inst| [inst.operation as u8 as u64, inst.operand as u64])
-        .collect();
-    println!("Test value: {}", program_input.iter().join(","));
-
-    // Determine the number of threads to use
-    let num_threads = rayon::current_num_threads();
-    println!("Number of threads: {}", num_threads);
-    // Parallel search across multiple threads
-    let result = (0..num_threads).into_par_iter().find_map_any(|thread_id| {
-        println!("Thread {} started", thread_id);
-        // Each thread searches a different segment of numbers
-        let mut current = thread_id as u64;
-        loop {
-            if current % num_threads as u64 == thread_id as u64 {
-                if current % (13 * 17 * 23 * 29 * 31) == 0 {
-                    println!("Testing register A: {} (thread {})", current, thread_id);
-                }
-
-                // Create a clone of the computer for each iteration
-                let mut computer = computer_template.clone();
-                computer.register_a = current;
-                computer.register_b = 0;
-                computer.register_c = 0;
-                computer.instruction_pointer = 0;
-
-                let program_output = run_program(&mut computer, &program);
-
-                if program_output == program_input {
-                    return Some(current);
-                }
-            }
-
-            // Increment by number of threads to ensure even distribution
-            current += num_threads as u64;
-        }
-    });
-
-    result.unwrap_or(0)
-}
-
-fn get_input() -> (Computer, Program) {
-    let input = include_str!("../input/day17.txt");
-    //     let input = r"Register A: 729
-    // Register B: 0
-    // Register C: 0
-
-    // Program: 0,1,5,4,3,0";
-    //     let input = r"Register A: 2024
-    // Register B: 0
-    // Register C: 0
-
-    // Program: 0,3,5,4,3,0";
-
-    let mut register_a = 0;
-    let mut register_b = 0;
-    let mut register_c = 0;
+fn parse_registers_and_program(input: &str) -> Result<([u64; 3], Program), ParseError> {
+    let mut registers = [0; 3];
     let mut program = Vec::new();
 
-    for line in input.lines() {
-        if line.starts_with("Register A: ") {
-            register_a = line.split(": ").nth(1).unwrap().parse().unwrap();
-        } else if line.starts_with("Register B: ") {
-            register_b = line.split(": ").nth(1).unwrap().parse().unwrap();
-        } else if line.starts_with("Register C: ") {
-            register_c = line.split(": ").nth(1).unwrap().parse().unwrap();
-        } else if line.starts_with("Program: ") {
-            let program_str = line.split(": ").nth(1).unwrap();
+    parse_lines(input, |line| {
+        if let Some(value) = line.strip_prefix("Register A: ") {
+            registers[REGISTER_A] = parse_field(value)?;
+        } else if let Some(value) = line.strip_prefix("Register B: ") {
+            registers[REGISTER_B] = parse_field(value)?;
+        } else if let Some(value) = line.strip_prefix("Register C: ") {
+            registers[REGISTER_C] = parse_field(value)?;
+        } else if let Some(program_str) = line.strip_prefix("Program: ") {
             for chunk in program_str
                 .split(",")
                 .chunks(2)
@@ -244,91 +174,61 @@ fn get_input() -> (Computer, Program) {
                 .map(|chunk| chunk.collect::<Vec<_>>())
             {
                 if let [instruction, operand] = &chunk[..] {
-                    let operation = instruction.parse().unwrap();
-                    let operand = operand.parse().unwrap();
+                    let operation = instruction.parse()?;
+                    let operand = parse_field(operand)?;
                     program.push(Instruction { operation, operand });
                 } else {
-                    panic!("Invalid program");
+                    return Err(ParseError::new("expected an operand for every instruction"));
                 }
             }
+        } else if !line.is_empty() {
+            return Err(ParseError::new("expected a register or the program"));
         }
+        Ok(())
+    })?;
+    Ok((registers, program))
+}
+
+fn run_program(program: &Program, registers: [u64; 3]) -> Vec<u64> {
+    let mut machine = Machine::with_registers(program, registers.to_vec());
+    machine.run();
+    machine.take_output()
+}
+
+#[cfg(test)]
+mod part1_tests {
+    use super::*;
+
+    #[test]
+    fn test_1() {
+        let result = Solver::new(
+            r"Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0",
+        )
+        .unwrap()
+        .solve_part_one();
+        assert_eq!(result, "4,6,3,5,6,3,5,2,1,0");
     }
-    (
-        Computer {
-            instruction_pointer: 0,
-            register_a,
-            register_b,
-            register_c,
-        },
-        program,
-    )
 }
 
-fn run_program(computer: &mut Computer, program: &Program) -> Vec<u64> {
-    let mut output = Vec::<u64>::new();
-    loop {
-        if computer.instruction_pointer >= program.len() {
-            break;
-        }
-        let instruction = &program[computer.instruction_pointer];
-        match instruction.operation {
-            InstructionType::adv => {
-                // The adv instruction (opcode 0) performs division. The numerator is the value in the A register.
-                // The denominator is found by raising 2 to the power of the instruction's combo operand.
-                // (So, an operand of 2 would divide A by 4 (2^2); an operand of 5 would divide A by 2^B.)
-                // The result of the division operation is truncated to an integer and then written to the A register.
-                let denominator = 2_u64.pow(instruction.get_combo_operand_value(computer) as u32);
-                computer.register_a /= denominator;
-                computer.instruction_pointer += 1;
-            }
-            InstructionType::bxl => {
-                // The bxl instruction (opcode 1) calculates the bitwise XOR of register B and the instruction's literal operand, then stores the result in register B.
-                computer.register_b ^= instruction.get_literal_operand_value();
-                computer.instruction_pointer += 1;
-            }
-            InstructionType::bst => {
-                // The bst instruction (opcode 2) calculates the value of its combo operand modulo 8 (thereby keeping only its lowest 3 bits), then writes that value to the B register.
-                computer.register_b = instruction.get_combo_operand_value(computer) % 8;
-                computer.instruction_pointer += 1;
-            }
-            InstructionType::jnz => {
-                // The jnz instruction (opcode 3) does nothing if the A register is 0.
-                // However, if the A register is not zero, it jumps by setting the instruction pointer to the value of its literal operand;
-                // if this instruction jumps, the instruction pointer is not increased by 2 after this instruction.
-                if computer.register_a != 0 {
-                    computer.instruction_pointer = instruction.get_literal_operand_value() as usize;
-                } else {
-                    computer.instruction_pointer += 1;
-                }
-            }
-            InstructionType::bxc => {
-                // The bxc instruction (opcode 4) calculates the bitwise XOR of register B and register C, then stores the result in register B.
-                // (For legacy reasons, this instruction reads an operand but ignores it.)
-                computer.register_b ^= computer.register_c;
-                computer.instruction_pointer += 1;
-            }
-            InstructionType::out => {
-                // The out instruction (opcode 5) calculates the value of its combo operand modulo 8, then outputs that value.
-                // (If a program outputs multiple values, they are separated by commas.)
-                let output_value = instruction.get_combo_operand_value(computer) % 8;
-                output.push(output_value);
-                computer.instruction_pointer += 1;
-            }
-            InstructionType::bdv => {
-                // The bdv instruction (opcode 6) works exactly like the adv instruction except that the result is stored in the B register.
-                // (The numerator is still read from the A register.)
-                let denominator = 2_u64.pow(instruction.get_combo_operand_value(computer) as u32);
-                computer.register_b = computer.register_a / denominator;
-                computer.instruction_pointer += 1;
-            }
-            InstructionType::cdv => {
-                // The cdv instruction (opcode 7) works exactly like the adv instruction except that the result is stored in the C register.
-                // (The numerator is still read from the A register.)
-                let denominator = 2_u64.pow(instruction.get_combo_operand_value(computer) as u32);
-                computer.register_c = computer.register_a / denominator;
-                computer.instruction_pointer += 1;
-            }
-        }
+#[cfg(test)]
+mod part2_tests {
+    use super::*;
+
+    #[test]
+    fn test_1() {
+        let result = Solver::new(
+            r"Register A: 2024
+Register B: 0
+Register C: 0
+
+Program: 0,3,5,4,3,0",
+        )
+        .unwrap()
+        .solve_part_two();
+        assert_eq!(result, "117440");
     }
-    output
 }
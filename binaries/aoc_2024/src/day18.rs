@@ -1,225 +1,242 @@
-use std::collections::VecDeque;
-
-#[derive(Debug, Clone, Copy)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
+use solver::{
+    Answer, ParseError, Point2, SolverBase, bfs, first_disconnection, parse_field, parse_lines,
+};
+
+pub struct Solver {
+    byte_locations: Vec<Position>,
+    map_size: usize,
+    number_of_bytes: usize,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
-enum Location {
-    Empty,
-    Byte,
-}
+impl Solver {
+    pub fn new(input: &str, map_size: usize, number_of_bytes: usize) -> Result<Self, ParseError> {
+        if map_size == 0 {
+            return Err(ParseError::new("expected a map of at least one location"));
+        }
+        Ok(Solver {
+            byte_locations: parse_byte_locations(input, map_size)?,
+            map_size,
+            number_of_bytes,
+        })
+    }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
-struct Position {
-    x: usize,
-    y: usize,
+    fn map_with_fallen_bytes(&self) -> Vec<Vec<Location>> {
+        let mut map = vec![vec![Location::Empty; self.map_size]; self.map_size];
+        for byte in self.byte_locations.iter().take(self.number_of_bytes) {
+            map[byte.y][byte.x] = Location::Byte;
+        }
+        map
+    }
 }
 
-impl Position {
-    fn move_in_direction(
-        &self,
-        direction: Direction,
-        map_width: usize,
-        map_height: usize,
-    ) -> Option<Position> {
-        match direction {
-            Direction::Up => {
-                if self.y > 0 {
-                    Some(Position {
-                        x: self.x,
-                        y: self.y - 1,
-                    })
-                } else {
-                    None
-                }
-            }
-            Direction::Down => {
-                if self.y < map_height - 1 {
-                    Some(Position {
-                        x: self.x,
-                        y: self.y + 1,
-                    })
-                } else {
-                    None
-                }
-            }
-            Direction::Left => {
-                if self.x > 0 {
-                    Some(Position {
-                        x: self.x - 1,
-                        y: self.y,
-                    })
-                } else {
-                    None
-                }
-            }
-            Direction::Right => {
-                if self.x < map_width - 1 {
-                    Some(Position {
-                        x: self.x + 1,
-                        y: self.y,
-                    })
-                } else {
-                    None
-                }
-            }
+impl SolverBase for Solver {
+    fn solve_part_one(&self) -> Answer {
+        match find_shortest_path(&self.map_with_fallen_bytes()) {
+            Some(path) => (path.len() - 1).into(),
+            None => Answer::NoSolution,
         }
     }
 
-    // fn gps(&self) -> u64 {
-    //     (self.y as u64) * 100 + (self.x as u64)
-    // }
+    fn solve_part_two(&self) -> Answer {
+        match get_first_byte_that_blocks_path(&self.byte_locations, self.map_size) {
+            Some(byte) => format!("{},{}", byte.x, byte.y).into(),
+            None => Answer::NoSolution,
+        }
+    }
+
+    fn day_number(&self) -> usize {
+        18
+    }
+
+    fn description(&self) -> &'static str {
+        "RAM Run"
+    }
 }
 
-pub fn minimum_steps_to_reach_exit() -> u32 {
-    let (map, _) = get_input();
-    print_map_with_path(&map, &[]);
-    let path = find_shortest_path(&map);
-    println!();
-    print_map_with_path(&map, &path);
-    (path.len() - 1) as u32
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Location {
+    Empty,
+    Byte,
 }
 
-pub fn get_first_byte_that_blocks_path() -> (usize, usize) {
-    let (mut map, remaining_bytes) = get_input();
-    print_map_with_path(&map, &[]);
-    for byte in remaining_bytes {
-        println!("Checking byte at {},{}", byte.x, byte.y);
-        map[byte.y][byte.x] = Location::Byte;
-        let path = find_shortest_path(&map);
-        println!();
-        print_map_with_path(&map, &path);
-        if path.is_empty() {
-            return (byte.x, byte.y);
+type Position = Point2<usize>;
+
+fn parse_byte_locations(input: &str, map_size: usize) -> Result<Vec<Position>, ParseError> {
+    parse_lines(input, |line| {
+        let (x, y) = line
+            .split_once(",")
+            .ok_or_else(|| ParseError::new("expected byte location `<x>,<y>`"))?;
+        let position = Position::new(parse_field(x)?, parse_field(y)?);
+        if position.x >= map_size || position.y >= map_size {
+            return Err(ParseError::expected(
+                &format!("a byte location below {map_size},{map_size}"),
+                line,
+            ));
         }
-    }
-    (0, 0)
+        Ok(position)
+    })
+}
+
+fn find_shortest_path(map: &[Vec<Location>]) -> Option<Vec<Position>> {
+    let map_width = map[0].len();
+    let map_height = map.len();
+    let start = Position::new(0, 0);
+    let end = Position::new(map_width - 1, map_height - 1);
+
+    let result = bfs(
+        start,
+        |position| {
+            position.neighbors4().filter(|next| {
+                next.x < map_width && next.y < map_height && map[next.y][next.x] != Location::Byte
+            })
+        },
+        |&position| position == end,
+    );
+
+    result.path()
 }
 
-fn print_map_with_path(map: &[Vec<Location>], path: &[Position]) {
-    for (y, row) in map.iter().enumerate() {
-        for (x, location) in row.iter().enumerate() {
-            if path.contains(&Position { x, y }) {
-                print!("O");
-            } else {
-                match location {
-                    Location::Empty => print!("."),
-                    Location::Byte => print!("#"),
+fn get_first_byte_that_blocks_path(
+    byte_locations: &[Position],
+    map_size: usize,
+) -> Option<Position> {
+    // The byte that falls on each location first
+    let mut fallen_at = vec![vec![None; map_size]; map_size];
+    for (index, byte) in byte_locations.iter().enumerate().rev() {
+        fallen_at[byte.y][byte.x] = Some(index);
+    }
+
+    // An edge between two neighbours disappears with the first byte falling on either of them
+    let node = |position: Position| position.y * map_size + position.x;
+    let mut edges = Vec::new();
+    for y in 0..map_size {
+        for x in 0..map_size {
+            let position = Position::new(x, y);
+            for next in [Position::new(x + 1, y), Position::new(x, y + 1)] {
+                if next.x < map_size && next.y < map_size {
+                    let removed_at = match (fallen_at[y][x], fallen_at[next.y][next.x]) {
+                        (Some(a), Some(b)) => Some(a.min(b)),
+                        (a, b) => a.or(b),
+                    };
+                    edges.push((node(position), node(next), removed_at));
                 }
             }
         }
-        println!();
     }
+
+    let end = Position::new(map_size - 1, map_size - 1);
+    first_disconnection(
+        map_size * map_size,
+        edges,
+        node(Position::new(0, 0)),
+        node(end),
+    )
+    .map(|index| byte_locations[index])
 }
 
-fn get_input() -> (Vec<Vec<Location>>, Vec<Position>) {
-    //     let byte_locations = r"5,4
-    // 4,2
-    // 4,5
-    // 3,0
-    // 2,1
-    // 6,3
-    // 2,4
-    // 1,5
-    // 0,6
-    // 3,3
-    // 2,6
-    // 5,1
-    // 1,2
-    // 5,5
-    // 2,5
-    // 6,5
-    // 1,4
-    // 0,4
-    // 6,4
-    // 1,1
-    // 6,1
-    // 1,0
-    // 0,5
-    // 1,6
-    // 2,0";
-    //     let number_of_bytes = 12;
-    //     let map_width = 7;
-    //     let map_height = 7;
-    let byte_locations = include_str!("../input/day18.txt");
-    let number_of_bytes = 1024;
-    let map_width = 71;
-    let map_height = 71;
-    let mut locations = vec![vec![Location::Empty; map_width]; map_height];
-    let mut remaining_bytes = Vec::new();
-    for (index, line) in byte_locations.lines().enumerate() {
-        let mut parts = line.split(",");
-        let x = parts.next().unwrap().parse::<usize>().unwrap();
-        let y = parts.next().unwrap().parse::<usize>().unwrap();
-        if index < number_of_bytes {
-            locations[y][x] = Location::Byte;
-        } else {
-            remaining_bytes.push(Position { x, y });
-        }
+#[cfg(test)]
+mod part1_tests {
+    use super::*;
+
+    #[test]
+    fn test_1() {
+        let result = Solver::new(
+            r"5,4
+4,2
+4,5
+3,0
+2,1
+6,3
+2,4
+1,5
+0,6
+3,3
+2,6
+5,1
+1,2
+5,5
+2,5
+6,5
+1,4
+0,4
+6,4
+1,1
+6,1
+1,0
+0,5
+1,6
+2,0",
+            7,
+            12,
+        )
+        .unwrap()
+        .solve_part_one();
+        assert_eq!(result, "22");
     }
 
-    (locations, remaining_bytes)
+    #[test]
+    fn test_no_path() {
+        let result = Solver::new("0,1\n1,0", 3, 2).unwrap().solve_part_one();
+        assert_eq!(result, Answer::NoSolution);
+    }
 }
 
-fn find_shortest_path(map: &[Vec<Location>]) -> Vec<Position> {
-    let map_width = map[0].len();
-    let map_height = map.len();
-    let start: Position = Position { x: 0, y: 0 };
-    let end: Position = Position {
-        x: map_width - 1,
-        y: map_height - 1,
-    };
-
-    // Use a queue for BFS
-    let mut queue: VecDeque<(Position, Vec<Position>)> = VecDeque::new();
-    let mut visited: Vec<Vec<bool>> = vec![vec![false; map_width]; map_height];
-
-    // Start with the initial position and an empty path
-    queue.push_back((start, vec![start]));
-    visited[start.y][start.x] = true;
-
-    while let Some((current_position, path)) = queue.pop_front() {
-        // Check if we've reached the end
-        if current_position == end {
-            return path;
-        }
+#[cfg(test)]
+mod parse_tests {
+    use super::*;
+
+    #[test]
+    fn test_outside_map() {
+        let error = Solver::new("0,1\n1,3", 3, 2).err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: expected a byte location below 3,3, found `1,3`"
+        );
+    }
 
-        // Explore all four directions
-        for direction in &[
-            Direction::Up,
-            Direction::Down,
-            Direction::Left,
-            Direction::Right,
-        ] {
-            if let Some(next_position) =
-                current_position.move_in_direction(*direction, map_width, map_height)
-            {
-                // check if the next position is valid
-
-                if !visited[next_position.y][next_position.x] {
-                    // check if the next position has been visited
-                    if map[next_position.y][next_position.x] != Location::Byte {
-                        // check if the next position is not a byte
-                        // Mark as visited
-                        visited[next_position.y][next_position.x] = true;
-
-                        // Create a new path by extending the current path
-                        let mut new_path = path.clone();
-                        new_path.push(next_position);
-
-                        // Add to the queue
-                        queue.push_back((next_position, new_path));
-                    }
-                }
-            }
-        }
+    #[test]
+    fn test_empty_map() {
+        assert!(Solver::new("", 0, 0).is_err());
     }
+}
 
-    // No path found
-    vec![]
+#[cfg(test)]
+mod part2_tests {
+    use super::*;
+
+    #[test]
+    fn test_1() {
+        let result = Solver::new(
+            r"5,4
+4,2
+4,5
+3,0
+2,1
+6,3
+2,4
+1,5
+0,6
+3,3
+2,6
+5,1
+1,2
+5,5
+2,5
+6,5
+1,4
+0,4
+6,4
+1,1
+6,1
+1,0
+0,5
+1,6
+2,0",
+            7,
+            12,
+        )
+        .unwrap()
+        .solve_part_two();
+        assert_eq!(result, "6,1");
+    }
 }
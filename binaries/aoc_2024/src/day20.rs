@@ -1,83 +1,60 @@
-use itertools::Itertools;
+use solver::{Answer, ParseError, Point2, SolverBase, parse_lines};
 use std::collections::{HashMap, HashSet, VecDeque};
 
-#[derive(Debug, Clone, Copy, PartialEq)]
-enum Direction {
-    East,
-    West,
-    North,
-    South,
+pub struct Solver {
+    map: Vec<Vec<Location>>,
+    minimum_saving: usize,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
-enum Location {
-    Start,
-    End,
-    Empty,
-    Wall,
+impl Solver {
+    pub fn new(input: &str, minimum_saving: usize) -> Result<Self, ParseError> {
+        Ok(Solver {
+            map: parse_map(input)?,
+            minimum_saving,
+        })
+    }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Position {
-    x: usize,
-    y: usize,
-}
+impl SolverBase for Solver {
+    fn solve_part_one(&self) -> Answer {
+        count_of_cheats_that_save_at_least(&self.map, 2, self.minimum_saving).into()
+    }
 
-impl Position {
-    fn move_in_direction(&self, direction: Direction) -> Position {
-        match direction {
-            Direction::East => Position {
-                x: self.x + 1,
-                y: self.y,
-            },
-            Direction::West => Position {
-                x: self.x - 1,
-                y: self.y,
-            },
-            Direction::North => Position {
-                x: self.x,
-                y: self.y - 1,
-            },
-            Direction::South => Position {
-                x: self.x,
-                y: self.y + 1,
-            },
-        }
+    fn solve_part_two(&self) -> Answer {
+        count_of_cheats_that_save_at_least(&self.map, 20, self.minimum_saving).into()
     }
 
-    fn manhattan_distance_to(&self, other: Position) -> usize {
-        (self.x as isize - other.x as isize).unsigned_abs()
-            + (self.y as isize - other.y as isize).unsigned_abs()
+    fn day_number(&self) -> usize {
+        20
+    }
+
+    fn description(&self) -> &'static str {
+        "Race Condition"
     }
 }
 
-pub fn count_of_cheats_that_save_at_least_100_picoseconds(cheat_length: usize) -> usize {
-    let map = get_input();
-    print_map(&map);
-    let start_position = find_start_position(&map);
-    println!();
-    let path = find_shortest_path(&map, start_position).unwrap();
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Location {
+    Start,
+    End,
+    Empty,
+    Wall,
+}
+
+type Position = Point2<usize>;
 
-    println!("Path time: {}", path.len() - 1);
-    //println!("Path: {:?}", path);
+fn count_of_cheats_that_save_at_least(
+    map: &[Vec<Location>],
+    cheat_length: usize,
+    minimum_saving: usize,
+) -> usize {
+    let start_position = find_start_position(map);
+    let path = find_shortest_path(map, start_position).unwrap();
     let all_path_lengths = get_all_path_lenghts_when_cheating(&path, cheat_length);
     all_path_lengths
         .iter()
-        .sorted_by_key(|(length, _)| *length)
-        .rev()
-        .for_each(|(length, count)| {
-            println!(
-                "{} paths saves {} picoseconds",
-                count,
-                path.len() as isize - *length as isize
-            );
-        });
-    all_path_lengths
-        .iter()
-        .sorted_by_key(|(length, _)| *length)
-        .map(|(length, count)| (count, path.len() as isize - *length as isize))
-        .filter(|(_, length)| *length >= 100)
-        .map(|(count, _)| count)
+        .filter(|(length, _)| path.len() - **length >= minimum_saving)
+        .map(|(_, count)| count)
         .sum()
 }
 
@@ -88,7 +65,7 @@ fn get_all_path_lenghts_when_cheating(
     let mut path_lengths = HashMap::new();
     for cheat_start in 0..path.len() {
         for cheat_end in cheat_start + 4..path.len() {
-            let shortcut_length = path[cheat_start].manhattan_distance_to(path[cheat_end]);
+            let shortcut_length = path[cheat_start].manhattan_distance(path[cheat_end]);
             if shortcut_length <= cheat_length {
                 let original_length = cheat_end - cheat_start;
                 if shortcut_length < original_length {
@@ -101,58 +78,35 @@ fn get_all_path_lenghts_when_cheating(
     path_lengths
 }
 
-fn get_input() -> Vec<Vec<Location>> {
-    let input = include_str!("../input/day20.txt");
-    //     let input = r"###############
-    // #...#...#.....#
-    // #.#.#.#.#.###.#
-    // #S#...#.#.#...#
-    // #######.#.#.###
-    // #######.#.#...#
-    // #######.#.###.#
-    // ###..E#...#...#
-    // ###.#######.###
-    // #...###...#...#
-    // #.#####.#.###.#
-    // #.#...#.#.#...#
-    // #.#.#.#.#.#.###
-    // #...#...#...###
-    // ###############";
-    input
-        .lines()
-        .map(|line| {
-            line.chars()
-                .map(|c| match c {
-                    '#' => Location::Wall,
-                    'S' => Location::Start,
-                    'E' => Location::End,
-                    '.' => Location::Empty,
-                    _ => panic!("Invalid character in input"),
-                })
-                .collect()
-        })
-        .collect()
-}
-
-fn print_map(map: &[Vec<Location>]) {
-    for row in map {
-        for cell in row {
-            match cell {
-                Location::Wall => print!("#"),
-                Location::Start => print!("S"),
-                Location::End => print!("E"),
-                Location::Empty => print!("."),
-            }
-        }
-        println!();
+fn parse_map(input: &str) -> Result<Vec<Vec<Location>>, ParseError> {
+    let map: Vec<Vec<Location>> = parse_lines(input, |line| {
+        line.chars()
+            .enumerate()
+            .map(|(column, c)| match c {
+                '#' => Ok(Location::Wall),
+                'S' => Ok(Location::Start),
+                'E' => Ok(Location::End),
+                '.' => Ok(Location::Empty),
+                _ => Err(ParseError::expected("`#`, `S`, `E` or `.`", &c.to_string())
+                    .at_column(column + 1)),
+            })
+            .collect()
+    })?;
+    if !map
+        .iter()
+        .flatten()
+        .any(|cell| matches!(cell, Location::Start))
+    {
+        return Err(ParseError::new("expected the start `S` on the map"));
     }
+    Ok(map)
 }
 
 fn find_start_position(map: &[Vec<Location>]) -> Position {
     for (y, row) in map.iter().enumerate() {
         for (x, cell) in row.iter().enumerate() {
             if let Location::Start = cell {
-                return Position { x, y };
+                return Position::new(x, y);
             }
         }
     }
@@ -174,13 +128,7 @@ fn find_shortest_path(map: &[Vec<Location>], start_position: Position) -> Option
 
     while let Some(State { position }) = queue.pop_front() {
         visited.insert(position);
-        for direction in [
-            Direction::East,
-            Direction::West,
-            Direction::North,
-            Direction::South,
-        ] {
-            let new_position = position.move_in_direction(direction);
+        for new_position in position.neighbors4() {
             if new_position.x == 0
                 || new_position.y == 0
                 || new_position.x >= map[0].len()
@@ -214,3 +162,63 @@ fn find_shortest_path(map: &[Vec<Location>], start_position: Position) -> Option
     }
     None
 }
+
+#[cfg(test)]
+mod part1_tests {
+    use super::*;
+
+    #[test]
+    fn test_1() {
+        let result = Solver::new(
+            r"###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############",
+            20,
+        )
+        .unwrap()
+        .solve_part_one();
+        assert_eq!(result, "5");
+    }
+}
+
+#[cfg(test)]
+mod part2_tests {
+    use super::*;
+
+    #[test]
+    fn test_1() {
+        let result = Solver::new(
+            r"###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############",
+            76,
+        )
+        .unwrap()
+        .solve_part_two();
+        assert_eq!(result, "3");
+    }
+}
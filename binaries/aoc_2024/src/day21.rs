@@ -1,17 +1,54 @@
 use std::collections::HashMap;
 
-const MAX_DEPTH: usize = 26;
+use solver::{Answer, ParseError, SolverBase, parse_lines};
 
-pub fn sum_of_code_complexities() -> u64 {
-    let input = get_input();
-    let mut keyboard_state = ['A'; MAX_DEPTH + 1];
+pub struct Solver {
+    codes: Vec<String>,
+}
+
+impl Solver {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let codes = parse_lines(input, |line| {
+            match line.find(|c: char| !c.is_ascii_digit() && c != 'A') {
+                Some(column) => Err(ParseError::expected(
+                    "a key of the numeric keypad",
+                    &line[column..=column],
+                )
+                .at_column(column + 1)),
+                None => Ok(line.to_owned()),
+            }
+        })?;
+        Ok(Solver { codes })
+    }
+}
+
+impl SolverBase for Solver {
+    fn solve_part_one(&self) -> Answer {
+        sum_of_code_complexities(&self.codes, 2).into()
+    }
+
+    fn solve_part_two(&self) -> Answer {
+        sum_of_code_complexities(&self.codes, 25).into()
+    }
+
+    fn day_number(&self) -> usize {
+        21
+    }
+
+    fn description(&self) -> &'static str {
+        "Keypad Conundrum"
+    }
+}
+
+fn sum_of_code_complexities(codes: &[String], robot_count: usize) -> u64 {
+    // the numeric keypad is typed on by the first robot, the last directional keypad by me
+    let max_depth = robot_count + 1;
+    let mut keyboard_state = vec!['A'; max_depth + 1];
     let mut complexity_sum = 0;
     let mut memo: HashMap<(String, usize, char), usize> = HashMap::new();
-    for code in input.iter() {
-        println!("Code: {code}");
+    for code in codes {
         let shortest_path_length =
-            get_all_sequences(code, MAX_DEPTH, &mut keyboard_state, &mut memo);
-        //println!("{shortest_path}");
+            get_all_sequences(code, max_depth, max_depth, &mut keyboard_state, &mut memo);
         let numeric_part_of_code = code.chars().fold(0, |acc, c| {
             if let Some(value) = c.to_digit(10) {
                 acc * 10 + value
@@ -25,24 +62,11 @@ pub fn sum_of_code_complexities() -> u64 {
     complexity_sum
 }
 
-fn get_input() -> Vec<String> {
-    let real_input = r"129A
-540A
-789A
-596A
-582A";
-    //     let test_input = r"029A
-    // 980A
-    // 179A
-    // 456A
-    // 379A";
-    real_input.lines().map(|line| line.to_owned()).collect()
-}
-
 fn get_all_sequences(
-    start_path: &String,
+    start_path: &str,
     depth: usize,
-    keyboard_state: &mut [char; MAX_DEPTH + 1],
+    max_depth: usize,
+    keyboard_state: &mut [char],
     memo: &mut HashMap<(String, usize, char), usize>,
 ) -> usize {
     if depth == 0 {
@@ -55,13 +79,10 @@ fn get_all_sequences(
 
     let mut result_length: usize = 0;
     for c in start_path.chars() {
-        if depth == MAX_DEPTH {
-            println!("{c}");
-        }
         let all_paths = find_all_paths(
             keyboard_state[depth],
             c,
-            if depth == MAX_DEPTH {
+            if depth == max_depth {
                 get_neighbors_for_numerical_keyboard
             } else {
                 get_neighbors_for_directional_keyboard
@@ -72,7 +93,8 @@ fn get_all_sequences(
         let mut all_sub_paths: Vec<usize> = Vec::new();
         for path in all_paths {
             let next_level_path = get_next_level_path_from_path(&path);
-            let sub_path = get_all_sequences(&next_level_path, depth - 1, keyboard_state, memo);
+            let sub_path =
+                get_all_sequences(&next_level_path, depth - 1, max_depth, keyboard_state, memo);
             all_sub_paths.push(sub_path);
         }
         let shortest_sub_path_length = all_sub_paths.iter().min().unwrap();
@@ -177,3 +199,22 @@ fn get_neighbors_for_directional_keyboard(key: char) -> Vec<(char, char)> {
         _ => panic!("Invalid key: {}", key),
     }
 }
+
+#[cfg(test)]
+mod part1_tests {
+    use super::*;
+
+    #[test]
+    fn test_1() {
+        let result = Solver::new(
+            r"029A
+980A
+179A
+456A
+379A",
+        )
+        .unwrap()
+        .solve_part_one();
+        assert_eq!(result, "126384");
+    }
+}
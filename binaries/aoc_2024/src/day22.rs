@@ -1,67 +1,68 @@
 use std::collections::HashMap;
 
 use itertools::Itertools;
+use solver::{Answer, ParseError, SolverBase, parse_field, parse_lines};
 
-pub fn sum_of_2000th_secret_numbers() -> i64 {
-    let input = get_input();
-    let mut sum = 0;
-    for number in input {
-        println!("Number: {}", number);
-        let mut secret_number = number;
-        for _ in 0..2000 {
-            secret_number = get_next_secret_number(secret_number);
-        }
-        println!(" -> {}", secret_number);
-        sum += secret_number;
+pub struct Solver {
+    buyers_numbers: Vec<i64>,
+}
+
+impl Solver {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        Ok(Solver {
+            buyers_numbers: parse_lines(input, parse_field)?,
+        })
     }
-    sum
 }
 
-pub fn most_bananas_you_can_get() -> i64 {
-    let input = get_input();
-    let mut map: HashMap<(i8, i8, i8, i8), HashMap<usize, i8>> = HashMap::new();
-    for (buyers_index, buyers_number) in input.iter().enumerate() {
-        let mut secret_number = *buyers_number;
-        for ((a, _), (b, _), (c, _), (d, price)) in (0..2000)
-            .map(|_| {
-                let old_value = secret_number;
+impl SolverBase for Solver {
+    fn solve_part_one(&self) -> Answer {
+        let mut sum = 0;
+        for &number in &self.buyers_numbers {
+            let mut secret_number = number;
+            for _ in 0..2000 {
                 secret_number = get_next_secret_number(secret_number);
-                old_value
-            })
-            .map(|x| (x % 10) as i8)
-            .tuple_windows::<(_, _)>()
-            .map(|(a, b)| (b - a, b))
-            .tuple_windows::<(_, _, _, _)>()
-        {
-            //println!("{:?}: {:?}", (a, b, c, d), price);
-            let entry = map.entry((a, b, c, d)).or_default();
-            entry.entry(buyers_index).or_insert(price);
+            }
+            sum += secret_number;
         }
+        sum.into()
     }
 
-    let max_item = map
-        .iter()
-        .map(|(key, value)| (key, value.values().map(|&p| p as i64).sum::<i64>()))
-        .max_by_key(|(_, value)| *value)
-        .unwrap();
+    fn solve_part_two(&self) -> Answer {
+        let mut map: HashMap<(i8, i8, i8, i8), HashMap<usize, i8>> = HashMap::new();
+        for (buyers_index, buyers_number) in self.buyers_numbers.iter().enumerate() {
+            let mut secret_number = *buyers_number;
+            for ((a, _), (b, _), (c, _), (d, price)) in (0..2000)
+                .map(|_| {
+                    let old_value = secret_number;
+                    secret_number = get_next_secret_number(secret_number);
+                    old_value
+                })
+                .map(|x| (x % 10) as i8)
+                .tuple_windows::<(_, _)>()
+                .map(|(a, b)| (b - a, b))
+                .tuple_windows::<(_, _, _, _)>()
+            {
+                let entry = map.entry((a, b, c, d)).or_default();
+                entry.entry(buyers_index).or_insert(price);
+            }
+        }
 
-    println!("{:?}", max_item);
-    max_item.1
-}
+        let most_bananas = map
+            .values()
+            .map(|prices| prices.values().map(|&p| p as i64).sum::<i64>())
+            .max()
+            .unwrap();
+        most_bananas.into()
+    }
+
+    fn day_number(&self) -> usize {
+        22
+    }
 
-fn get_input() -> Vec<i64> {
-    //let input = "123";
-    //     let input = r"1
-    // 2
-    // 3
-    // 2024";
-    let input = include_str!("../input/day22.txt");
-    //     let input = r"1
-    // 10
-    // 100
-    // 2024";
-    //let input = r"123";
-    input.lines().map(|line| line.parse().unwrap()).collect()
+    fn description(&self) -> &'static str {
+        "Monkey Market"
+    }
 }
 
 #[allow(clippy::let_and_return)]
@@ -90,3 +91,39 @@ fn get_next_secret_number(number: i64) -> i64 {
     let step3 = prune(mix(step2 * 2048, step2));
     step3
 }
+
+#[cfg(test)]
+mod part1_tests {
+    use super::*;
+
+    #[test]
+    fn test_1() {
+        let result = Solver::new(
+            r"1
+10
+100
+2024",
+        )
+        .unwrap()
+        .solve_part_one();
+        assert_eq!(result, "37327623");
+    }
+}
+
+#[cfg(test)]
+mod part2_tests {
+    use super::*;
+
+    #[test]
+    fn test_1() {
+        let result = Solver::new(
+            r"1
+2
+3
+2024",
+        )
+        .unwrap()
+        .solve_part_two();
+        assert_eq!(result, "23");
+    }
+}
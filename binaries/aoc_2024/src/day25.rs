@@ -1,86 +1,64 @@
 use itertools::Itertools;
+use solver::{Answer, ParseError, SolverBase};
 
-pub fn count_of_unique_lock_pin_pairs_that_fit_together_without_overlapping() -> usize {
-    let (locks, keys) = get_input();
-    println!("locks: {:?}", locks);
-    println!("keys: {:?}", keys);
-    let mut count = 0;
-    for (lock, key) in locks.iter().cartesian_product(keys.iter()) {
-        if lock
+pub struct Solver {
+    locks: Vec<[u8; 5]>,
+    keys: Vec<[u8; 5]>,
+}
+
+impl Solver {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let (locks, keys) = parse_locks_and_keys(input)?;
+        Ok(Solver { locks, keys })
+    }
+}
+
+impl SolverBase for Solver {
+    fn solve_part_one(&self) -> Answer {
+        let count = self
+            .locks
             .iter()
-            .zip(key.iter())
-            .all(|(&lock_height, &key_height)| lock_height + key_height <= 5)
-        {
-            count += 1;
-        }
+            .cartesian_product(self.keys.iter())
+            .filter(|(lock, key)| {
+                lock.iter()
+                    .zip(key.iter())
+                    .all(|(&lock_height, &key_height)| lock_height + key_height <= 5)
+            })
+            .count();
+        count.into()
+    }
+
+    fn day_number(&self) -> usize {
+        25
+    }
+
+    fn description(&self) -> &'static str {
+        "Code Chronicle"
     }
-    count
 }
 
-fn get_input() -> (Vec<[u8; 5]>, Vec<[u8; 5]>) {
-    let input = include_str!("../input/day25.txt");
-    //     let input = r"#####
-    // .####
-    // .####
-    // .####
-    // .#.#.
-    // .#...
-    // .....
-
-    // #####
-    // ##.##
-    // .#.##
-    // ...##
-    // ...#.
-    // ...#.
-    // .....
-
-    // .....
-    // #....
-    // #....
-    // #...#
-    // #.#.#
-    // #.###
-    // #####
-
-    // .....
-    // .....
-    // #.#..
-    // ###..
-    // ###.#
-    // ###.#
-    // #####
-
-    // .....
-    // .....
-    // .....
-    // #....
-    // #.#..
-    // #.#.#
-    // #####";
+type PinHeights = [u8; 5];
 
+fn parse_locks_and_keys(input: &str) -> Result<(Vec<PinHeights>, Vec<PinHeights>), ParseError> {
     let mut lock_or_key = [[false; 5]; 7];
-    let mut lines = input.lines();
 
     let mut locks: Vec<[u8; 5]> = Vec::new();
     let mut keys: Vec<[u8; 5]> = Vec::new();
 
-    while let Some(line) = lines.next() {
-        let mut schema = Vec::new();
-        schema.push(line);
-        for _ in 0..6 {
-            schema.push(lines.next().unwrap());
-        }
-        let kind = parse_lock_or_key(&schema, &mut lock_or_key);
+    let mut first_line = 1;
+    for block in input.split("\n\n") {
+        let schema: Vec<&str> = block.lines().collect();
+        let kind = parse_lock_or_key(&schema, &mut lock_or_key)
+            .map_err(|error| error.below(first_line - 1))?;
         let pin_heights = convert_to_pin_heights(&lock_or_key);
         match kind {
             LockOrKey::Lock => locks.push(pin_heights),
             LockOrKey::Key => keys.push(pin_heights),
         }
-        _ = lines.next(); // Skip empty line
+        first_line += schema.len() + 1;
     }
 
-    (locks, keys)
+    Ok((locks, keys))
 }
 
 enum LockOrKey {
@@ -88,20 +66,36 @@ enum LockOrKey {
     Key,
 }
 
-fn parse_lock_or_key(schema: &[&str], lock_or_key: &mut [[bool; 5]; 7]) -> LockOrKey {
+fn parse_lock_or_key(
+    schema: &[&str],
+    lock_or_key: &mut [[bool; 5]; 7],
+) -> Result<LockOrKey, ParseError> {
+    let first_line = schema.first().copied().unwrap_or_default();
+    if schema.len() != 7 {
+        return Err(ParseError::new("expected a schematic of 7 rows").on_line(1, first_line));
+    }
     for (i, line) in schema.iter().enumerate() {
+        if line.len() != 5 {
+            return Err(ParseError::new("expected a row of 5 columns").on_line(i + 1, line));
+        }
         for (j, c) in line.chars().enumerate() {
             match c {
                 '#' => lock_or_key[i][j] = true,
                 '.' => lock_or_key[i][j] = false,
-                _ => panic!("Invalid character: {}", c),
+                _ => {
+                    return Err(ParseError::expected("`#` or `.`", &c.to_string())
+                        .at_column(j + 1)
+                        .on_line(i + 1, line));
+                }
             }
         }
     }
     match (lock_or_key[0], lock_or_key[6]) {
-        ([true, true, true, true, true], [false, false, false, false, false]) => LockOrKey::Lock,
-        ([false, false, false, false, false], [true, true, true, true, true]) => LockOrKey::Key,
-        _ => panic!("Invalid lock or key"),
+        ([true, true, true, true, true], [false, false, false, false, false]) => {
+            Ok(LockOrKey::Lock)
+        }
+        ([false, false, false, false, false], [true, true, true, true, true]) => Ok(LockOrKey::Key),
+        _ => Err(ParseError::new("expected a lock or a key").on_line(1, first_line)),
     }
 }
 
@@ -117,3 +111,56 @@ fn convert_to_pin_heights(lock_or_key: &[[bool; 5]; 7]) -> [u8; 5] {
     pin_heights.iter_mut().for_each(|height| *height -= 1);
     pin_heights
 }
+
+#[cfg(test)]
+mod part1_tests {
+    use super::*;
+
+    #[test]
+    fn test_1() {
+        let result = Solver::new(
+            r"#####
+.####
+.####
+.####
+.#.#.
+.#...
+.....
+
+#####
+##.##
+.#.##
+...##
+...#.
+...#.
+.....
+
+.....
+#....
+#....
+#...#
+#.#.#
+#.###
+#####
+
+.....
+.....
+#.#..
+###..
+###.#
+###.#
+#####
+
+.....
+.....
+.....
+#....
+#.#..
+#.#.#
+#####",
+        )
+        .unwrap()
+        .solve_part_one();
+        assert_eq!(result, "3");
+    }
+}
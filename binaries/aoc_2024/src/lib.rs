@@ -0,0 +1,67 @@
+use solver::{Puzzle, Registry};
+
+mod day01;
+mod day02;
+mod day03;
+mod day04;
+mod day05;
+mod day06;
+mod day07;
+mod day08;
+mod day09;
+mod day10;
+mod day11;
+mod day12;
+mod day13;
+mod day14;
+mod day15;
+mod day16;
+mod day17;
+mod day18;
+mod day19;
+mod day20;
+mod day21;
+mod day22;
+mod day23;
+mod day24;
+mod day25;
+
+const INPUT_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input");
+
+pub fn register(registry: &mut Registry) {
+    registry.add_year(2024, INPUT_DIR, puzzles());
+}
+
+fn puzzles() -> Vec<Puzzle> {
+    vec![
+        Puzzle::new(1, |input| Ok(Box::new(day01::Solver::new(input)?))),
+        Puzzle::new(2, |input| Ok(Box::new(day02::Solver::new(input)?))),
+        Puzzle::new(3, |input| Ok(Box::new(day03::Solver::new(input)?))),
+        Puzzle::new(4, |input| Ok(Box::new(day04::Solver::new(input)?))),
+        Puzzle::new(5, |input| Ok(Box::new(day05::Solver::new(input)?))),
+        Puzzle::new(6, |input| Ok(Box::new(day06::Solver::new(input)?))),
+        Puzzle::new(7, |input| Ok(Box::new(day07::Solver::new(input)?))),
+        Puzzle::new(8, |input| Ok(Box::new(day08::Solver::new(input)?))),
+        Puzzle::new(9, |input| Ok(Box::new(day09::Solver::new(input)?))),
+        Puzzle::new(10, |input| Ok(Box::new(day10::Solver::new(input)?))),
+        Puzzle::new(11, |input| Ok(Box::new(day11::Solver::new(input)?))),
+        Puzzle::new(12, |input| Ok(Box::new(day12::Solver::new(input)?))),
+        Puzzle::new(13, |input| Ok(Box::new(day13::Solver::new(input)?))),
+        Puzzle::new(14, |input| {
+            Ok(Box::new(day14::Solver::new(input, 101, 103)?))
+        }),
+        Puzzle::new(15, |input| Ok(Box::new(day15::Solver::new(input)?))),
+        Puzzle::new(16, |input| Ok(Box::new(day16::Solver::new(input)?))),
+        Puzzle::new(17, |input| Ok(Box::new(day17::Solver::new(input)?))),
+        Puzzle::new(18, |input| {
+            Ok(Box::new(day18::Solver::new(input, 71, 1024)?))
+        }),
+        Puzzle::new(19, |input| Ok(Box::new(day19::Solver::new(input)?))),
+        Puzzle::new(20, |input| Ok(Box::new(day20::Solver::new(input, 100)?))),
+        Puzzle::new(21, |input| Ok(Box::new(day21::Solver::new(input)?))),
+        Puzzle::new(22, |input| Ok(Box::new(day22::Solver::new(input)?))),
+        Puzzle::new(23, |input| Ok(Box::new(day23::Solver::new(input)?))),
+        Puzzle::new(24, |input| Ok(Box::new(day24::Solver::new(input)?))),
+        Puzzle::new(25, |input| Ok(Box::new(day25::Solver::new(input)?))),
+    ]
+}
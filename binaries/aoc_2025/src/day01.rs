@@ -1,4 +1,4 @@
-use solver::SolverBase;
+use solver::{Answer, ParseError, SolverBase, parse_field, parse_lines};
 
 pub struct Solver {
     rotations: Vec<Rotation>,
@@ -11,25 +11,22 @@ pub enum Rotation {
 }
 
 impl Solver {
-    pub fn new(input: &'static str) -> Self {
-        let rotations = input
-            .lines()
-            .map(|line| {
-                let (dir, value) = line.split_at(1);
-                let value: u32 = value.parse().unwrap();
-                match dir {
-                    "L" => Rotation::Left(value),
-                    "R" => Rotation::Right(value),
-                    _ => panic!("Invalid direction"),
-                }
-            })
-            .collect();
-        Solver { rotations }
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let rotations = parse_lines(input, |line| {
+            let dir = line.get(..1).unwrap_or_default();
+            let value: u32 = parse_field(&line[dir.len()..])?;
+            match dir {
+                "L" => Ok(Rotation::Left(value)),
+                "R" => Ok(Rotation::Right(value)),
+                _ => Err(ParseError::expected("`L` or `R`", dir).at_column(1)),
+            }
+        })?;
+        Ok(Solver { rotations })
     }
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
+    fn solve_part_one(&self) -> Answer {
         let mut dial = 50;
         let full = 100;
         let mut zero_reached_counter = 0;
@@ -46,10 +43,10 @@ impl SolverBase for Solver {
                 zero_reached_counter += 1;
             }
         }
-        zero_reached_counter.to_string()
+        zero_reached_counter.into()
     }
 
-    fn solve_part_two(&self) -> String {
+    fn solve_part_two(&self) -> Answer {
         let mut dial = 50;
         let full = 100;
         let mut zero_reached_counter = 0;
@@ -70,7 +67,7 @@ impl SolverBase for Solver {
                 }
             }
         }
-        zero_reached_counter.to_string()
+        zero_reached_counter.into()
     }
 
     fn day_number(&self) -> usize {
@@ -100,6 +97,7 @@ L99
 R14
 L82",
         )
+        .unwrap()
         .solve_part_one();
         assert_eq!(result, "3");
     }
@@ -123,6 +121,7 @@ L99
 R14
 L82",
         )
+        .unwrap()
         .solve_part_two();
         assert_eq!(result, "6");
     }
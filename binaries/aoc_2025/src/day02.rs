@@ -1,75 +1,74 @@
-use solver::SolverBase;
-use std::ops::RangeInclusive;
+use solver::{Answer, IntervalSet, ParseError, SolverBase, parse_field};
 
 pub struct Solver {
-    ranges: Vec<RangeInclusive<u64>>,
+    ranges: IntervalSet<u64>,
 }
 
 impl Solver {
-    pub fn new(input: &'static str) -> Self {
-        let ranges = input
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let line = input.trim_end();
+        let ranges = line
             .split(',')
-            .map(|line| {
-                let (from, to) = line.split_once('-').unwrap();
-                from.parse().unwrap()..=to.parse().unwrap()
+            .map(|range| {
+                let (from, to) = range
+                    .split_once('-')
+                    .ok_or_else(|| ParseError::expected("range `<from>-<to>`", range))?;
+                Ok(parse_field(from)?..=parse_field(to)?)
             })
-            .collect();
-        Solver { ranges }
+            .collect::<Result<_, ParseError>>()
+            .map_err(|error| error.on_line(1, line))?;
+        Ok(Solver { ranges })
     }
 
-    fn sum_of_invalid<F>(&self, is_invalid: &F) -> u64
-    where
-        F: Fn(u64) -> bool,
-    {
-        let mut sum = 0;
-        for range in &self.ranges {
-            for x in range.clone() {
-                if is_invalid(x) {
-                    sum += x;
+    /// Sums the IDs made of a sequence of digits repeated at least twice, or exactly twice
+    fn sum_of_invalid(&self, exactly_twice: bool) -> u128 {
+        // Generates the repeated numbers of each range rather than scanning it, e.g. 123123 is 123 * 1001
+        let mut invalid_ids = Vec::new();
+        for range in self.ranges.iter() {
+            let (from, to) = (*range.start(), *range.end());
+            let lengths = from.to_string().len() as u32..=to.to_string().len() as u32;
+            for length in lengths.filter(|&length| length >= 2) {
+                for chunk_length in
+                    (1..=length / 2).filter(|chunk_length| length % chunk_length == 0)
+                {
+                    if exactly_twice && length != 2 * chunk_length {
+                        continue;
+                    }
+                    let Some(multiplier) =
+                        (0..length / chunk_length).try_fold(0u64, |multiplier, i| {
+                            multiplier.checked_add(10u64.checked_pow(i * chunk_length)?)
+                        })
+                    else {
+                        continue;
+                    };
+                    let (Some(first_chunk), Some(end_chunk)) = (
+                        10u64.checked_pow(chunk_length - 1),
+                        10u64.checked_pow(chunk_length),
+                    ) else {
+                        continue;
+                    };
+                    // Only the chunks whose repetition falls into the range, so the IDs stay within `to`
+                    let chunks = first_chunk.max(from.div_ceil(multiplier))
+                        ..=(end_chunk - 1).min(to / multiplier);
+                    invalid_ids.extend(chunks.map(|chunk| chunk * multiplier));
                 }
             }
         }
-        sum
+        // 222222 repeats 2, 22 and 222
+        invalid_ids.sort_unstable();
+        invalid_ids.dedup();
+        // The IDs get close to `u64::MAX`, so a few of them already overflow it
+        invalid_ids.into_iter().map(u128::from).sum()
     }
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
-        fn is_doubled(number: u64) -> bool {
-            let s = number.to_string();
-            let (left, right) = s.split_at(s.len() / 2);
-            left == right
-        }
-
-        let sum = self.sum_of_invalid(&is_doubled);
-        sum.to_string()
+    fn solve_part_one(&self) -> Answer {
+        self.sum_of_invalid(true).into()
     }
 
-    fn solve_part_two(&self) -> String {
-        fn has_repetitions(number: u64) -> bool {
-            let s = number.to_string();
-            let len = s.len();
-
-            for chunk_size in 1..=len / 2 {
-                if !len.is_multiple_of(chunk_size) {
-                    continue;
-                }
-
-                let bytes = s.as_bytes();
-                let first_chunk = &bytes[..chunk_size];
-
-                if bytes
-                    .chunks_exact(chunk_size)
-                    .all(|chunk| chunk == first_chunk)
-                {
-                    return true;
-                }
-            }
-            false
-        }
-
-        let sum = self.sum_of_invalid(&has_repetitions);
-        sum.to_string()
+    fn solve_part_two(&self) -> Answer {
+        self.sum_of_invalid(false).into()
     }
 
     fn day_number(&self) -> usize {
@@ -87,7 +86,7 @@ mod part1_tests {
 
     #[test]
     fn test_1() {
-        let result = Solver::new("11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124").solve_part_one();
+        let result = Solver::new("11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124").unwrap().solve_part_one();
         assert_eq!(result, "1227775554");
     }
 }
@@ -98,7 +97,39 @@ mod part2_tests {
 
     #[test]
     fn test_1() {
-        let result = Solver::new("11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124").solve_part_two();
+        let result = Solver::new("11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124").unwrap().solve_part_two();
         assert_eq!(result, "4174379265");
     }
 }
+
+#[cfg(test)]
+mod overflow_tests {
+    use super::*;
+
+    #[test]
+    fn test_largest_ids() {
+        let solver = Solver::new("18446744071844674407-18446744073709551615").unwrap();
+        assert_eq!(solver.solve_part_one(), "18446744071844674407");
+        assert_eq!(solver.solve_part_two(), "18446744071844674407");
+    }
+
+    #[test]
+    fn test_distant_ranges() {
+        let solver = Solver::new("1-2,999999999999999990-999999999999999999").unwrap();
+        assert_eq!(solver.solve_part_one(), "999999999999999999");
+        assert_eq!(solver.solve_part_two(), "999999999999999999");
+    }
+
+    #[test]
+    fn test_sum_beyond_u64() {
+        let solver = Solver::new(
+            "9999999999999999999-9999999999999999999,8888888888888888888-8888888888888888888",
+        )
+        .unwrap();
+        assert_eq!(solver.solve_part_one(), "0");
+        assert_eq!(
+            solver.solve_part_two(),
+            Answer::BigInteger(18888888888888888887)
+        );
+    }
+}
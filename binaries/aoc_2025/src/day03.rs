@@ -1,20 +1,23 @@
-use solver::SolverBase;
+use solver::{Answer, ParseError, SolverBase, parse_lines};
 
 pub struct Solver {
     battery_joltage: Vec<Vec<u64>>,
 }
 
 impl Solver {
-    pub fn new(input: &'static str) -> Self {
-        let battery_joltage = input
-            .lines()
-            .map(|line| {
-                line.chars()
-                    .map(|c| c.to_digit(10).unwrap() as u64)
-                    .collect()
-            })
-            .collect();
-        Solver { battery_joltage }
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let battery_joltage = parse_lines(input, |line| {
+            line.chars()
+                .enumerate()
+                .map(|(column, c)| {
+                    c.to_digit(10).map(u64::from).ok_or_else(|| {
+                        ParseError::expected("a joltage digit", &c.to_string())
+                            .at_column(column + 1)
+                    })
+                })
+                .collect()
+        })?;
+        Ok(Solver { battery_joltage })
     }
 
     fn find_largest_joltage_two(bank: &[u64]) -> u64 {
@@ -44,20 +47,20 @@ impl Solver {
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
+    fn solve_part_one(&self) -> Answer {
         self.battery_joltage
             .iter()
             .map(|bank| Solver::find_largest_joltage_two(bank))
             .sum::<u64>()
-            .to_string()
+            .into()
     }
 
-    fn solve_part_two(&self) -> String {
+    fn solve_part_two(&self) -> Answer {
         self.battery_joltage
             .iter()
             .map(|bank| Solver::find_largest_joltage_twelve(bank))
             .sum::<u64>()
-            .to_string()
+            .into()
     }
 
     fn day_number(&self) -> usize {
@@ -81,6 +84,7 @@ mod part1_tests {
 234234234234278
 818181911112111",
         )
+        .unwrap()
         .solve_part_one();
         assert_eq!(result, "357");
     }
@@ -98,6 +102,7 @@ mod part2_tests {
 234234234234278
 818181911112111",
         )
+        .unwrap()
         .solve_part_two();
         assert_eq!(result, "3121910778619");
     }
@@ -1,94 +1,42 @@
-use solver::SolverBase;
+use solver::{Answer, Grid, ParseError, SolverBase, parse_char_map};
 
 pub struct Solver {
-    grid: Vec<Vec<bool>>,
+    grid: Grid<bool>,
 }
 
 impl Solver {
-    pub fn new(input: &'static str) -> Self {
-        let grid = input
-            .lines()
-            .map(|line| {
-                line.bytes()
-                    .map(|x| match x {
-                        b'@' => true,
-                        b'.' => false,
-                        _ => panic!("unrecognized grid location"),
-                    })
-                    .collect::<Vec<_>>()
-            })
-            .collect();
-        Solver { grid }
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let grid = parse_char_map(input, &[('@', true), ('.', false)])?;
+        Ok(Solver { grid })
     }
 
-    fn get_number_of_neighbors(grid: &[Vec<bool>], i: usize, j: usize) -> usize {
-        let mut count = 0;
-
-        if j > 0 {
-            if grid[i][j - 1] {
-                count += 1;
-            }
-            if i > 0 && grid[i - 1][j - 1] {
-                count += 1;
-            }
-        }
-
-        if i > 0 {
-            if grid[i - 1][j] {
-                count += 1;
-            }
-            if j < grid[0].len() - 1 && grid[i - 1][j + 1] {
-                count += 1;
-            }
-        }
-
-        if j < grid[0].len() - 1 {
-            if grid[i][j + 1] {
-                count += 1;
-            }
-            if i < grid.len() - 1 && grid[i + 1][j + 1] {
-                count += 1;
-            }
-        }
-
-        if i < grid.len() - 1 {
-            if grid[i + 1][j] {
-                count += 1;
-            }
-            if j > 0 && grid[i + 1][j - 1] {
-                count += 1;
-            }
-        }
-
-        count
+    fn get_number_of_neighbors(grid: &Grid<bool>, position: (usize, usize)) -> usize {
+        grid.neighbors8(position)
+            .filter(|&neighbor| grid[neighbor])
+            .count()
     }
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
+    fn solve_part_one(&self) -> Answer {
         let mut sum = 0;
-        for (i, row) in self.grid.iter().enumerate() {
-            for (j, value) in row.iter().enumerate() {
-                if *value && Solver::get_number_of_neighbors(&self.grid, i, j) < 4 {
-                    sum += 1;
-                }
+        for (position, value) in self.grid.iter() {
+            if *value && Solver::get_number_of_neighbors(&self.grid, position) < 4 {
+                sum += 1;
             }
         }
-        sum.to_string()
+        sum.into()
     }
 
-    fn solve_part_two(&self) -> String {
+    fn solve_part_two(&self) -> Answer {
         let mut grid = self.grid.clone();
         let mut total_sum = 0;
         loop {
             let mut sum = 0;
-            for i in 0..grid.len() {
-                let row_len = grid[i].len();
-                for j in 0..row_len {
-                    if grid[i][j] && Solver::get_number_of_neighbors(&grid, i, j) < 4 {
-                        sum += 1;
-                        grid[i][j] = false;
-                    }
+            for position in self.grid.positions() {
+                if grid[position] && Solver::get_number_of_neighbors(&grid, position) < 4 {
+                    sum += 1;
+                    grid[position] = false;
                 }
             }
             if sum == 0 {
@@ -96,7 +44,7 @@ impl SolverBase for Solver {
             }
             total_sum += sum;
         }
-        total_sum.to_string()
+        total_sum.into()
     }
 
     fn day_number(&self) -> usize {
@@ -126,6 +74,7 @@ mod part1_tests {
 .@@@@@@@@.
 @.@.@@@.@.",
         )
+        .unwrap()
         .solve_part_one();
         assert_eq!(result, "13");
     }
@@ -149,6 +98,7 @@ mod part2_tests {
 .@@@@@@@@.
 @.@.@@@.@.",
         )
+        .unwrap()
         .solve_part_two();
         assert_eq!(result, "43");
     }
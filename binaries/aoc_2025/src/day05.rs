@@ -1,70 +1,43 @@
-use std::ops::RangeInclusive;
-
-use solver::SolverBase;
+use solver::{
+    Answer, IntervalSet, ParseError, SolverBase, parse_field, parse_lines, split_pair,
+    split_sections,
+};
 
 pub struct Solver {
-    fresh_ranges: Vec<RangeInclusive<u64>>,
+    fresh_ranges: IntervalSet<u64>,
     available_ingredients: Vec<u64>,
 }
 
 impl Solver {
-    pub fn new(input: &'static str) -> Self {
-        let mut fresh_ranges = Vec::<RangeInclusive<u64>>::new();
-        let mut available_ingredients = Vec::<u64>::new();
-        let mut parsing_ranges = true;
-        for line in input.lines() {
-            if line.is_empty() {
-                parsing_ranges = false;
-            } else if parsing_ranges {
-                let (from, to) = line.split_once('-').unwrap();
-                let range = from.parse().unwrap()..=to.parse().unwrap();
-                fresh_ranges.push(range);
-            } else {
-                let id = line.parse().unwrap();
-                available_ingredients.push(id);
-            }
-        }
-        Solver {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let (ranges, ingredients, ingredients_line) = split_sections(input)?;
+        let fresh_ranges = parse_lines(ranges, |line| {
+            let (from, to) = split_pair(line, "-")?;
+            Ok(parse_field(from)?..=parse_field(to)?)
+        })?
+        .into_iter()
+        .collect();
+        let available_ingredients = parse_lines(ingredients, parse_field)
+            .map_err(|error| error.below(ingredients_line - 1))?;
+        Ok(Solver {
             fresh_ranges,
             available_ingredients,
-        }
+        })
     }
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
-        let mut count = 0;
-        for id in &self.available_ingredients {
-            for range in &self.fresh_ranges {
-                if range.contains(id) {
-                    count += 1;
-                    break;
-                }
-            }
-        }
-        count.to_string()
+    fn solve_part_one(&self) -> Answer {
+        let count = self
+            .available_ingredients
+            .iter()
+            .filter(|&&id| self.fresh_ranges.contains(id))
+            .count();
+        count.into()
     }
 
-    fn solve_part_two(&self) -> String {
-        let mut ranges = self.fresh_ranges.clone();
-        ranges.sort_by_key(|range| *range.start());
-        let mut current = 1;
-        while current < ranges.len() {
-            let previous = current - 1;
-            if ranges[current].start() <= ranges[previous].end() {
-                if ranges[current].end() > ranges[previous].end() {
-                    ranges[previous] = *ranges[previous].start()..=*ranges[current].end();
-                }
-                ranges.remove(current);
-            } else {
-                current += 1;
-            }
-        }
-        let mut total_count = 0;
-        for range in ranges {
-            total_count += range.end() - range.start() + 1;
-        }
-        total_count.to_string()
+    fn solve_part_two(&self) -> Answer {
+        self.fresh_ranges.len().into()
     }
 
     fn day_number(&self) -> usize {
@@ -95,6 +68,7 @@ mod part1_tests {
 17
 32",
         )
+        .unwrap()
         .solve_part_one();
         assert_eq!(result, "3");
     }
@@ -119,6 +93,7 @@ mod part2_tests {
 17
 32",
         )
+        .unwrap()
         .solve_part_two();
         assert_eq!(result, "14");
     }
@@ -1,4 +1,4 @@
-use solver::SolverBase;
+use solver::{Answer, ParseError, SolverBase, parse_field, parse_lines};
 
 pub struct Solver {
     columns_part1: Vec<Column>,
@@ -18,27 +18,45 @@ pub enum Operator {
 }
 
 impl Solver {
-    pub fn new(input: &'static str) -> Self {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
         let mut operand_lines = Vec::<Vec<u64>>::new();
         let mut operator_line = Vec::<Operator>::new();
 
-        for line in input.lines() {
+        parse_lines(input, |line| {
             let tokens = line.split_whitespace().collect::<Vec<_>>();
-            match tokens[0] {
-                "+" | "*" => {
+            match tokens.first() {
+                Some(&"+") | Some(&"*") => {
                     operator_line = tokens
                         .iter()
                         .map(|x| match *x {
-                            "+" => Operator::Add,
-                            "*" => Operator::Multiply,
-                            _ => panic!(),
+                            "+" => Ok(Operator::Add),
+                            "*" => Ok(Operator::Multiply),
+                            _ => Err(ParseError::expected("`+` or `*`", x)),
                         })
-                        .collect();
+                        .collect::<Result<_, _>>()?;
                 }
-                _ => {
-                    operand_lines.push(tokens.iter().map(|x| x.parse().unwrap()).collect());
+                Some(_) => {
+                    operand_lines.push(
+                        tokens
+                            .iter()
+                            .map(|x| parse_field(x))
+                            .collect::<Result<_, _>>()?,
+                    );
                 }
+                None => return Err(ParseError::new("expected operands or operators")),
             }
+            Ok(())
+        })?;
+        if operator_line.is_empty() {
+            return Err(ParseError::new("expected a line of operators"));
+        }
+        if operand_lines
+            .iter()
+            .any(|line| line.len() != operator_line.len())
+        {
+            return Err(ParseError::new(
+                "expected as many operands on each line as there are operators",
+            ));
         }
 
         let mut columns_part1 = Vec::<Column>::new();
@@ -111,15 +129,15 @@ impl Solver {
             columns_part2.push(current_column);
         }
 
-        Solver {
+        Ok(Solver {
             columns_part1,
             columns_part2,
-        }
+        })
     }
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
+    fn solve_part_one(&self) -> Answer {
         let mut sum = 0;
         for column in &self.columns_part1 {
             let result = match column.operator {
@@ -128,10 +146,10 @@ impl SolverBase for Solver {
             };
             sum += result;
         }
-        sum.to_string()
+        sum.into()
     }
 
-    fn solve_part_two(&self) -> String {
+    fn solve_part_two(&self) -> Answer {
         let mut sum = 0;
         for column in &self.columns_part2 {
             let result = match column.operator {
@@ -140,7 +158,7 @@ impl SolverBase for Solver {
             };
             sum += result;
         }
-        sum.to_string()
+        sum.into()
     }
 
     fn day_number(&self) -> usize {
@@ -164,6 +182,7 @@ mod part1_tests {
   6 98  215 314
 *   +   *   +  ",
         )
+        .unwrap()
         .solve_part_one();
         assert_eq!(result, "4277556");
     }
@@ -181,6 +200,7 @@ mod part2_tests {
   6 98  215 314
 *   +   *   +  ",
         )
+        .unwrap()
         .solve_part_two();
         assert_eq!(result, "3263827");
     }
@@ -1,4 +1,4 @@
-use solver::SolverBase;
+use solver::{Answer, ParseError, SolverBase, parse_lines};
 
 pub struct Solver {
     grid: Vec<Vec<Location>>,
@@ -13,22 +13,20 @@ pub enum Location {
 }
 
 impl Solver {
-    pub fn new(input: &'static str) -> Self {
-        let grid = input
-            .lines()
-            .map(|line| {
-                line.bytes()
-                    .map(|b| match b {
-                        b'.' => Location::Empty,
-                        b'S' => Location::Start,
-                        b'^' => Location::Splitter,
-                        b'|' => panic!("beam not expected in input"),
-                        _ => panic!("unknown location"),
-                    })
-                    .collect()
-            })
-            .collect();
-        Solver { grid }
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let grid = parse_lines(input, |line| {
+            line.chars()
+                .enumerate()
+                .map(|(column, c)| match c {
+                    '.' => Ok(Location::Empty),
+                    'S' => Ok(Location::Start),
+                    '^' => Ok(Location::Splitter),
+                    _ => Err(ParseError::expected("`.`, `S` or `^`", &c.to_string())
+                        .at_column(column + 1)),
+                })
+                .collect()
+        })?;
+        Ok(Solver { grid })
     }
 
     fn find_start(grid: &[Vec<Location>]) -> Option<(usize, usize)> {
@@ -90,16 +88,16 @@ impl Solver {
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
+    fn solve_part_one(&self) -> Answer {
         let mut grid = self.grid.clone();
         let split_count = Solver::tachion_beam_traverse(&mut grid);
-        split_count.to_string()
+        split_count.into()
     }
 
-    fn solve_part_two(&self) -> String {
+    fn solve_part_two(&self) -> Answer {
         let mut grid = self.grid.clone();
         let path_count = Solver::tachion_beam_path_counter(&mut grid);
-        path_count.to_string()
+        path_count.into()
     }
 
     fn day_number(&self) -> usize {
@@ -135,6 +133,7 @@ mod part1_tests {
 .^.^.^.^.^...^.
 ...............",
         )
+        .unwrap()
         .solve_part_one();
         assert_eq!(result, "21");
     }
@@ -164,6 +163,7 @@ mod part2_tests {
 .^.^.^.^.^...^.
 ...............",
         )
+        .unwrap()
         .solve_part_two();
         assert_eq!(result, "40");
     }
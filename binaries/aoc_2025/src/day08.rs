@@ -1,6 +1,6 @@
 use std::cmp::Ordering;
 
-use solver::SolverBase;
+use solver::{Answer, ParseError, SolverBase, parse_field, parse_lines};
 
 use solver::UnionFind;
 
@@ -34,24 +34,23 @@ impl<'a> PointPair<'a> {
 }
 
 impl Solver {
-    pub fn new(input: &'static str, pair_count: usize) -> Self {
-        let points = input
-            .lines()
-            .enumerate()
-            .map(|(index, line)| {
-                let mut tokens = line.split(',');
-                let x = tokens.next().unwrap().parse().unwrap();
-                let y = tokens.next().unwrap().parse().unwrap();
-                let z = tokens.next().unwrap().parse().unwrap();
-                Point {
-                    id: index as u32,
-                    x,
-                    y,
-                    z,
-                }
-            })
-            .collect();
-        Solver { points, pair_count }
+    pub fn new(input: &str, pair_count: usize) -> Result<Self, ParseError> {
+        let mut index = 0;
+        let points = parse_lines(input, |line| {
+            let tokens: Vec<_> = line.split(',').collect();
+            let [x, y, z] = tokens[..] else {
+                return Err(ParseError::new("expected position `<x>,<y>,<z>`"));
+            };
+            let point = Point {
+                id: index,
+                x: parse_field(x)?,
+                y: parse_field(y)?,
+                z: parse_field(z)?,
+            };
+            index += 1;
+            Ok(point)
+        })?;
+        Ok(Solver { points, pair_count })
     }
 
     fn get_point_pairs_sorted(&self) -> Vec<PointPair<'_>> {
@@ -80,7 +79,7 @@ impl Solver {
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
+    fn solve_part_one(&self) -> Answer {
         let pairs = self.get_point_pairs_sorted();
 
         let mut uf = UnionFind::default();
@@ -89,35 +88,30 @@ impl SolverBase for Solver {
             uf.union(pair.a, pair.b);
         }
 
-        let result: u32 = uf
-            .groups_by_size()
-            .into_iter()
-            .take(3)
-            .map(|g| g.len() as u32)
-            .product();
+        let mut sizes: Vec<usize> = uf.component_sizes().collect();
+        sizes.sort_by(|a, b| b.cmp(a));
+        let result: usize = sizes.into_iter().take(3).product();
 
-        result.to_string()
+        result.into()
     }
 
-    fn solve_part_two(&self) -> String {
+    fn solve_part_two(&self) -> Answer {
         let pairs = self.get_point_pairs_sorted();
 
-        let mut uf = UnionFind::default();
-
-        let mut last_pair_to_connect: Option<&PointPair> = None;
-
-        for pair in pairs.iter() {
-            if uf.union(pair.a, pair.b) {
-                last_pair_to_connect = Some(pair);
-            }
+        let mut uf = UnionFind::with_capacity(self.points.len());
+        for point in &self.points {
+            uf.insert(point);
         }
 
-        if let Some(PointPair { a, b, .. }) = last_pair_to_connect {
-            let x_coordinate_product = a.x * b.x;
-            return x_coordinate_product.to_string();
+        for PointPair { a, b, .. } in pairs.iter() {
+            if uf.union(*a, *b) && uf.count() == 1 {
+                let x_coordinate_product = i64::from(a.x) * i64::from(b.x);
+                return x_coordinate_product.into();
+            }
         }
 
-        unreachable!()
+        // Fewer than two points, no connection joins them
+        Answer::NoSolution
     }
 
     fn day_number(&self) -> usize {
@@ -158,6 +152,7 @@ mod part1_tests {
 425,690,689",
             10,
         )
+        .unwrap()
         .solve_part_one();
         assert_eq!(result, "40");
     }
@@ -192,7 +187,22 @@ mod part2_tests {
 425,690,689",
             0,
         )
+        .unwrap()
         .solve_part_two();
         assert_eq!(result, "25272");
     }
+
+    #[test]
+    fn test_single_point() {
+        let result = Solver::new("1,2,3", 0).unwrap().solve_part_two();
+        assert_eq!(result, Answer::NoSolution);
+    }
+
+    #[test]
+    fn test_large_coordinates() {
+        let result = Solver::new("2000000000,0,0\n2000000000,1,0", 0)
+            .unwrap()
+            .solve_part_two();
+        assert_eq!(result, "4000000000000000000");
+    }
 }
@@ -1,17 +1,18 @@
-use solver::SolverBase;
+use solver::{Answer, ParseError, SolverBase, parse_field, parse_lines};
 
 pub struct Solver {
     tile_positions: Vec<(u64, u64)>,
 }
 
 impl Solver {
-    pub fn new(input: &'static str) -> Self {
-        let tile_positions = input
-            .lines()
-            .map(|line| line.split_once(',').unwrap())
-            .map(|(x, y)| (x.parse().unwrap(), y.parse().unwrap()))
-            .collect();
-        Solver { tile_positions }
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let tile_positions = parse_lines(input, |line| {
+            let (x, y) = line
+                .split_once(',')
+                .ok_or_else(|| ParseError::new("expected tile position `<x>,<y>`"))?;
+            Ok((parse_field(x)?, parse_field(y)?))
+        })?;
+        Ok(Solver { tile_positions })
     }
 }
 
@@ -178,7 +179,7 @@ fn rectangle_inside_polygon(a: Point, b: Point, polygon: &[Edge]) -> bool {
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
+    fn solve_part_one(&self) -> Answer {
         let mut max_area = None;
         for i in 0..self.tile_positions.len() {
             for j in i + 1..self.tile_positions.len() {
@@ -194,10 +195,10 @@ impl SolverBase for Solver {
                 }
             }
         }
-        max_area.unwrap().to_string()
+        max_area.unwrap().into()
     }
 
-    fn solve_part_two(&self) -> String {
+    fn solve_part_two(&self) -> Answer {
         let mut polygon: Vec<Edge> = Vec::new();
         let mut previous: (u64, u64) = self.tile_positions[self.tile_positions.len() - 1];
         for &(x, y) in &self.tile_positions {
@@ -232,7 +233,7 @@ impl SolverBase for Solver {
                 }
             }
         }
-        max_area.unwrap().to_string()
+        max_area.unwrap().into()
     }
 
     fn day_number(&self) -> usize {
@@ -260,6 +261,7 @@ mod part1_tests {
 2,3
 7,3",
         )
+        .unwrap()
         .solve_part_one();
         assert_eq!(result, "50");
     }
@@ -281,6 +283,7 @@ mod part2_tests {
 2,3
 7,3",
         )
+        .unwrap()
         .solve_part_two();
         assert_eq!(result, "24");
     }
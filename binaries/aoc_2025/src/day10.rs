@@ -1,6 +1,8 @@
-use std::{cmp::Ordering, collections::VecDeque, str::FromStr};
+use std::str::FromStr;
 
-use solver::SolverBase;
+use solver::{
+    Answer, BitVector, Gf2System, LinearSystem, ParseError, SolverBase, parse_field, parse_lines,
+};
 
 pub struct Solver {
     lights: Vec<Light>,
@@ -8,71 +10,88 @@ pub struct Solver {
 
 #[derive(Debug)]
 pub struct Light {
-    light_diagram: u32,
-    toggles: Vec<u32>,
+    light_diagram: BitVector,
+    toggles: Vec<BitVector>,
     toggle_indices: Vec<Vec<u32>>,
     joltages: Vec<u32>,
 }
 
 impl FromStr for Light {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         // split by spaces
         let parts: Vec<&str> = s.split_whitespace().collect();
 
-        // Helper to convert pattern to bitmask
-        fn pattern_to_bitmask(pattern: &str) -> u32 {
-            pattern.chars().enumerate().fold(
-                0u32,
-                |mask, (i, c)| {
-                    if c == '#' { mask | (1 << i) } else { mask }
-                },
-            )
-        }
-
         // 1. Extract pattern
-        let diagram_pattern =
-            pattern_to_bitmask(parts[0].trim_start_matches('[').trim_end_matches(']'));
+        let diagram = parts
+            .first()
+            .and_then(|s| s.strip_prefix('[')?.strip_suffix(']'))
+            .ok_or_else(|| {
+                ParseError::expected("`[...]` light diagram", parts.first().unwrap_or(&s))
+            })?;
+        let light_count = diagram.len();
+        let diagram_pattern = BitVector::from_indices(
+            light_count,
+            diagram
+                .chars()
+                .enumerate()
+                .filter(|&(_, c)| c == '#')
+                .map(|(i, _)| i),
+        );
 
         // Helper to parse "(...)"
-        fn parse_paren(s: &str) -> Vec<u32> {
+        fn parse_paren(s: &str) -> Result<Vec<u32>, ParseError> {
             s.trim_start_matches('(')
                 .trim_end_matches(')')
                 .split(',')
                 .filter(|x| !x.is_empty())
-                .map(|x| x.parse::<u32>().unwrap())
+                .map(parse_field)
                 .collect()
         }
 
-        // Helper to convert indices to bitmask
-        fn to_bitmask(bits: &[u32]) -> u32 {
-            bits.iter().fold(0u32, |mask, &bit| mask | (1 << bit))
-        }
+        // The joltage block comes last, the buttons sit between it and the diagram
+        let (last, buttons) = parts
+            .split_first()
+            .and_then(|(_, rest)| rest.split_last())
+            .ok_or_else(|| ParseError::expected("`{...}` joltage block", s))?;
 
         // 2. All (...) groups
-        let toggles: Vec<u32> = parts[1..parts.len() - 1]
+        let toggle_indices: Vec<Vec<u32>> = buttons
             .iter()
-            .filter(|s| s.starts_with('('))
-            .map(|s| to_bitmask(&parse_paren(s)))
-            .collect();
+            .map(|s| match s.starts_with('(') && s.ends_with(')') {
+                true => parse_paren(s),
+                false => Err(ParseError::expected("`(...)` button wiring", s)),
+            })
+            .collect::<Result<_, _>>()?;
 
-        // 3. All (...) groups
-        let toggle_indices: Vec<Vec<u32>> = parts[1..parts.len() - 1]
+        // 3. Bit vectors of the (...) groups
+        let toggles = toggle_indices
             .iter()
-            .filter(|s| s.starts_with('('))
-            .map(|s| parse_paren(s))
-            .collect();
+            .zip(buttons)
+            .map(|(indices, part)| {
+                let indices = indices.iter().map(|&i| i as usize);
+                match indices.clone().all(|i| i < light_count) {
+                    true => Ok(BitVector::from_indices(light_count, indices)),
+                    false => Err(ParseError::expected("lights of the diagram", part)),
+                }
+            })
+            .collect::<Result<_, _>>()?;
 
         // 4. Parse {...}
-        let joltages: Vec<u32> = parts
-            .last()
-            .unwrap()
-            .trim_start_matches('{')
-            .trim_end_matches('}')
+        let joltages: Vec<u32> = last
+            .strip_prefix('{')
+            .and_then(|s| s.strip_suffix('}'))
+            .ok_or_else(|| ParseError::expected("`{...}` joltage block", last))?
             .split(',')
-            .map(|x| x.parse::<u32>().unwrap())
-            .collect();
+            .map(parse_field)
+            .collect::<Result<_, _>>()?;
+        if joltages.len() != light_count {
+            return Err(ParseError::expected(
+                &format!("{light_count} joltages, one per light"),
+                last,
+            ));
+        }
 
         Ok(Light {
             light_diagram: diagram_pattern,
@@ -84,71 +103,73 @@ impl FromStr for Light {
 }
 
 impl Solver {
-    pub fn new(input: &'static str) -> Self {
-        Solver {
-            lights: input
-                .lines()
-                .map(|line| Light::from_str(line).unwrap())
-                .collect(),
-        }
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        Ok(Solver {
+            lights: parse_lines(input, Light::from_str)?,
+        })
     }
 }
 
 impl SolverBase for Solver {
-    fn solve_part_one(&self) -> String {
-        fn bfs(light: &Light) -> usize {
-            let mut queue: VecDeque<(u32, usize)> = VecDeque::new();
-            queue.push_back((0, 0));
-            while let Some((pattern, depth)) = queue.pop_front() {
-                for toggle_indices in &light.toggles {
-                    let new_pattern = pattern ^ toggle_indices;
-                    if new_pattern == light.light_diagram {
-                        return depth + 1;
-                    }
-                    queue.push_back((new_pattern, depth + 1));
-                }
-            }
-            unreachable!()
+    fn solve_part_one(&self) -> Answer {
+        // Pressing a button twice undoes it, so each button is pressed at most once: a linear system over GF(2)
+        fn fewest_presses(light: &Light) -> Option<usize> {
+            Gf2System::from_columns(&light.toggles, &light.light_diagram)
+                .min_weight_solution()
+                .map(|presses| presses.count_ones())
         }
 
-        let sum: usize = self.lights.iter().map(bfs).sum();
-        sum.to_string()
+        // A single light diagram which cannot be reached leaves the input without a solution
+        match self
+            .lights
+            .iter()
+            .map(fewest_presses)
+            .sum::<Option<usize>>()
+        {
+            Some(sum) => sum.into(),
+            None => Answer::NoSolution,
+        }
     }
 
-    fn solve_part_two(&self) -> String {
-        fn bfs(light: &Light) -> usize {
-            let mut queue: VecDeque<(Vec<u32>, usize)> = VecDeque::new();
-            queue.push_back((vec![0; light.joltages.len()], 0));
-            while let Some((pattern, depth)) = queue.pop_front() {
-                for indices in &light.toggle_indices {
-                    let mut new_pattern = pattern.clone();
-                    for index in indices {
-                        new_pattern[*index as usize] += 1;
-                    }
-                    match compare_patterns(&new_pattern, &light.joltages) {
-                        Ordering::Equal => return depth + 1,
-                        Ordering::Less => queue.push_back((new_pattern, depth + 1)),
-                        Ordering::Greater => {}
-                    }
-                }
-            }
-            unreachable!()
+    fn solve_part_two(&self) -> Answer {
+        // Each counter gives an equation: the presses of the buttons wired to it add up to its joltage
+        fn fewest_presses(light: &Light) -> Option<i64> {
+            let coefficients = (0..light.joltages.len() as u32)
+                .map(|counter| {
+                    light
+                        .toggle_indices
+                        .iter()
+                        .map(|indices| indices.contains(&counter) as i64)
+                        .collect()
+                })
+                .collect();
+            let joltages = light
+                .joltages
+                .iter()
+                .map(|&joltage| joltage as i64)
+                .collect();
+            // A button cannot be pressed more often than the lowest joltage of its counters
+            let upper_bounds: Vec<i64> = light
+                .toggle_indices
+                .iter()
+                .map(|indices| {
+                    indices
+                        .iter()
+                        .map(|&index| light.joltages[index as usize] as i64)
+                        .min()
+                        .unwrap_or(0)
+                })
+                .collect();
+            let presses =
+                LinearSystem::new(coefficients, joltages).min_sum_non_negative(&upper_bounds)?;
+            Some(presses.iter().sum())
         }
 
-        fn compare_patterns(a: &[u32], b: &[u32]) -> Ordering {
-            if a == b {
-                return Ordering::Equal;
-            }
-            for i in 0..a.len() {
-                if a[i].cmp(&b[i]) == Ordering::Greater {
-                    return Ordering::Greater;
-                }
-            }
-            Ordering::Less
+        // A single light whose joltages cannot be reached leaves the input without a solution
+        match self.lights.iter().map(fewest_presses).sum::<Option<i64>>() {
+            Some(sum) => sum.into(),
+            None => Answer::NoSolution,
         }
-
-        let sum: usize = self.lights.iter().map(bfs).sum();
-        sum.to_string()
     }
 
     fn day_number(&self) -> usize {
@@ -171,9 +192,17 @@ mod part1_tests {
 [...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}
 [.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}",
         )
+        .unwrap()
         .solve_part_one();
         assert_eq!(result, "7");
     }
+
+    #[test]
+    fn test_unreachable_diagram() {
+        // The only button toggles both lights together
+        let result = Solver::new("[#.] (0,1) {1,1}").unwrap().solve_part_one();
+        assert_eq!(result, Answer::NoSolution);
+    }
 }
 
 #[cfg(test)]
@@ -187,7 +216,49 @@ mod part2_tests {
 [...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}
 [.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}",
         )
+        .unwrap()
         .solve_part_two();
         assert_eq!(result, "33");
     }
+
+    #[test]
+    fn test_unreachable_joltages() {
+        // The only button raises both counters together
+        let result = Solver::new("[##] (0,1) {1,2}").unwrap().solve_part_two();
+        assert_eq!(result, Answer::NoSolution);
+    }
+}
+
+#[cfg(test)]
+mod parse_tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_joltages() {
+        let error = Solver::new("[.##.] (3) (1,3) {3,5,4,7}\n[...#.] (0,2,3,4) (2,3)")
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 19: expected `{...}` joltage block, found `(2,3)`"
+        );
+    }
+
+    #[test]
+    fn test_diagram_only() {
+        let error = Solver::new("[.#]").err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: expected `{...}` joltage block, found `[.#]`"
+        );
+    }
+
+    #[test]
+    fn test_joltage_count() {
+        let error = Solver::new("[.#] (1) {1}").err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 10: expected 2 joltages, one per light, found `{1}`"
+        );
+    }
 }
@@ -0,0 +1,33 @@
+use solver::{Puzzle, Registry};
+
+mod day01;
+mod day02;
+mod day03;
+mod day04;
+mod day05;
+mod day06;
+mod day07;
+mod day08;
+mod day09;
+mod day10;
+
+const INPUT_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input");
+
+pub fn register(registry: &mut Registry) {
+    registry.add_year(2025, INPUT_DIR, puzzles());
+}
+
+fn puzzles() -> Vec<Puzzle> {
+    vec![
+        Puzzle::new(1, |input| Ok(Box::new(day01::Solver::new(input)?))),
+        Puzzle::new(2, |input| Ok(Box::new(day02::Solver::new(input)?))),
+        Puzzle::new(3, |input| Ok(Box::new(day03::Solver::new(input)?))),
+        Puzzle::new(4, |input| Ok(Box::new(day04::Solver::new(input)?))),
+        Puzzle::new(5, |input| Ok(Box::new(day05::Solver::new(input)?))),
+        Puzzle::new(6, |input| Ok(Box::new(day06::Solver::new(input)?))),
+        Puzzle::new(7, |input| Ok(Box::new(day07::Solver::new(input)?))),
+        Puzzle::new(8, |input| Ok(Box::new(day08::Solver::new(input, 1000)?))),
+        Puzzle::new(9, |input| Ok(Box::new(day09::Solver::new(input)?))),
+        Puzzle::new(10, |input| Ok(Box::new(day10::Solver::new(input)?))),
+    ]
+}
@@ -0,0 +1,189 @@
+use std::fmt;
+
+/// Answer of a puzzle part
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Integer(i64),
+    /// Integer that does not fit into `i64`
+    BigInteger(i128),
+    Text(String),
+    /// Rows of an ASCII-art picture, e.g. letters drawn on a screen
+    Grid(Vec<String>),
+    /// The part is not implemented
+    Unsolved,
+    /// The input has no answer, e.g. the goal cannot be reached
+    NoSolution,
+}
+
+impl Answer {
+    /// Draws lit pixels as `#` and the others as `.`
+    pub fn from_pixels<R: AsRef<[bool]>>(rows: &[R]) -> Answer {
+        Answer::Grid(
+            rows.iter()
+                .map(|row| {
+                    row.as_ref()
+                        .iter()
+                        .map(|&lit| if lit { '#' } else { '.' })
+                        .collect()
+                })
+                .collect(),
+        )
+    }
+
+    /// Whether the answer renders as an empty string
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Answer::Integer(_) | Answer::BigInteger(_) => false,
+            Answer::Text(text) => text.is_empty(),
+            Answer::Grid(rows) => rows.iter().all(|row| row.is_empty()),
+            Answer::Unsolved | Answer::NoSolution => true,
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(value) => value.fmt(f),
+            Answer::BigInteger(value) => value.fmt(f),
+            Answer::Text(text) => text.fmt(f),
+            Answer::Grid(rows) => rows.join("\n").fmt(f),
+            Answer::Unsolved | Answer::NoSolution => Ok(()),
+        }
+    }
+}
+
+macro_rules! impl_from_small_integer {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(value: $t) -> Self {
+                Answer::Integer(value.into())
+            }
+        })*
+    };
+}
+
+macro_rules! impl_from_large_integer {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(value: $t) -> Self {
+                match i64::try_from(value) {
+                    Ok(value) => Answer::Integer(value),
+                    Err(_) => Answer::BigInteger(value as i128),
+                }
+            }
+        })*
+    };
+}
+
+impl_from_small_integer!(i8, i16, i32, i64, u8, u16, u32);
+impl_from_large_integer!(isize, usize, u64, i128);
+
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        match i128::try_from(value) {
+            Ok(value) => value.into(),
+            Err(_) => Answer::Text(value.to_string()),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_owned())
+    }
+}
+
+impl From<char> for Answer {
+    fn from(c: char) -> Self {
+        Answer::Text(c.to_string())
+    }
+}
+
+/// Answers compare equal to their rendering, which keeps expectations in tests short
+impl PartialEq<str> for Answer {
+    fn eq(&self, other: &str) -> bool {
+        self.to_string().as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+impl PartialEq<String> for Answer {
+    fn eq(&self, other: &String) -> bool {
+        self == other.as_str()
+    }
+}
+
+/// Reason why a part did not produce an answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    NotImplemented,
+    NoSolution,
+    Panicked(String),
+    EmptyAnswer,
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::NotImplemented => write!(f, "not implemented"),
+            SolveError::NoSolution => write!(f, "no solution"),
+            SolveError::Panicked(message) => write!(f, "panicked: {message}"),
+            SolveError::EmptyAnswer => write!(f, "empty answer"),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+#[cfg(test)]
+mod answer_tests {
+    use super::*;
+
+    #[test]
+    fn test_from_integers() {
+        assert_eq!(Answer::from(42u8), Answer::Integer(42));
+        assert_eq!(Answer::from(-42i32), Answer::Integer(-42));
+        assert_eq!(Answer::from(42usize), Answer::Integer(42));
+        assert_eq!(Answer::from(u64::MAX), Answer::BigInteger(u64::MAX as i128));
+        assert_eq!(Answer::from(u128::MAX), Answer::Text(u128::MAX.to_string()));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::Integer(-3).to_string(), "-3");
+        assert_eq!(
+            Answer::BigInteger(1 << 70).to_string(),
+            "1180591620717411303424"
+        );
+        assert_eq!(Answer::from("abc").to_string(), "abc");
+        assert_eq!(Answer::Unsolved.to_string(), "");
+    }
+
+    #[test]
+    fn test_from_pixels() {
+        let answer = Answer::from_pixels(&[[true, false], [false, true]]);
+        assert_eq!(answer, Answer::Grid(vec!["#.".to_owned(), ".#".to_owned()]));
+        assert_eq!(answer, "#.\n.#");
+    }
+
+    #[test]
+    fn test_is_empty() {
+        assert!(Answer::Unsolved.is_empty());
+        assert!(Answer::from("").is_empty());
+        assert!(Answer::Grid(vec![String::new()]).is_empty());
+        assert!(!Answer::Integer(0).is_empty());
+        assert!(!Answer::from("0").is_empty());
+    }
+}
@@ -0,0 +1,167 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use serde_json::Value;
+
+use crate::{InputError, Part, read_input};
+
+/// Name of the file with the accepted answers of a year, stored next to its inputs
+pub const ANSWERS_FILE_NAME: &str = "answers.json";
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Read(InputError),
+    Invalid { path: PathBuf, message: String },
+}
+
+impl fmt::Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnswersError::Read(error) => error.fmt(f),
+            AnswersError::Invalid { path, message } => {
+                write!(f, "invalid answers file `{}`: {message}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AnswersError::Read(error) => Some(error),
+            AnswersError::Invalid { .. } => None,
+        }
+    }
+}
+
+/// Accepted answers of the days of a year, e.g. `{ "1": { "part1": "995", "part2": 1130 } }`
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    days: BTreeMap<usize, [Option<String>; 2]>,
+}
+
+impl Answers {
+    pub fn parse(text: &str) -> Result<Answers, String> {
+        let root: Value = serde_json::from_str(text).map_err(|error| error.to_string())?;
+        let Value::Object(days) = root else {
+            return Err("expected an object keyed by day number".to_owned());
+        };
+
+        let mut answers = Answers::default();
+        for (day, parts) in days {
+            let day_number = day
+                .parse()
+                .map_err(|_| format!("invalid day number `{day}`"))?;
+            let Value::Object(parts) = parts else {
+                return Err(format!("expected an object with the answers of day {day}"));
+            };
+            for (name, answer) in parts {
+                let part = match name.as_str() {
+                    "part1" => Part::One,
+                    "part2" => Part::Two,
+                    _ => return Err(format!("unknown part `{name}` of day {day}")),
+                };
+                let answer = match answer {
+                    Value::String(answer) => answer,
+                    Value::Number(answer) => answer.to_string(),
+                    _ => {
+                        return Err(format!(
+                            "answer of day {day} {name} must be a string or a number"
+                        ));
+                    }
+                };
+                answers.insert(day_number, part, answer);
+            }
+        }
+        Ok(answers)
+    }
+
+    pub fn load(path: &Path) -> Result<Answers, AnswersError> {
+        let text = read_input(path).map_err(AnswersError::Read)?;
+        Answers::parse(&text).map_err(|message| AnswersError::Invalid {
+            path: path.to_owned(),
+            message,
+        })
+    }
+
+    pub fn insert(&mut self, day: usize, part: Part, answer: String) {
+        self.days.entry(day).or_default()[part.number() - 1] = Some(answer);
+    }
+
+    pub fn get(&self, day: usize, part: Part) -> Option<&str> {
+        self.days.get(&day)?[part.number() - 1].as_deref()
+    }
+}
+
+/// Outcome of comparing a computed answer with the accepted one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Missing,
+}
+
+impl Verdict {
+    pub fn of(expected: Option<&str>, actual: &str) -> Verdict {
+        match expected {
+            Some(expected) if expected == actual => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_owned(),
+            },
+            None => Verdict::Missing,
+        }
+    }
+}
+
+#[cfg(test)]
+mod answers_tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(
+            r#"{
+                "1": { "part1": "995", "part2": 1130 },
+                "25": { "part1": "abc,def" }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(answers.get(1, Part::One), Some("995"));
+        assert_eq!(answers.get(1, Part::Two), Some("1130"));
+        assert_eq!(answers.get(25, Part::One), Some("abc,def"));
+        assert_eq!(answers.get(25, Part::Two), None);
+        assert_eq!(answers.get(2, Part::One), None);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Answers::parse("[]").is_err());
+        assert!(Answers::parse(r#"{ "one": { "part1": "1" } }"#).is_err());
+        assert!(Answers::parse(r#"{ "1": { "part3": "1" } }"#).is_err());
+        assert!(Answers::parse(r#"{ "1": { "part1": null } }"#).is_err());
+        assert!(Answers::parse(r#"{ "1": "1" }"#).is_err());
+    }
+
+    #[test]
+    fn test_load_missing_file() {
+        match Answers::load(Path::new("does/not/exist.json")) {
+            Err(AnswersError::Read(error)) => {
+                assert_eq!(error.kind(), std::io::ErrorKind::NotFound)
+            }
+            other => panic!("unexpected result {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_verdict() {
+        assert_eq!(Verdict::of(Some("42"), "42"), Verdict::Pass);
+        assert_eq!(
+            Verdict::of(Some("42"), "41"),
+            Verdict::Fail {
+                expected: "42".to_owned()
+            }
+        );
+        assert_eq!(Verdict::of(None, "42"), Verdict::Missing);
+    }
+}
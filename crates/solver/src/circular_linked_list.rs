@@ -1,102 +1,172 @@
-#![allow(dead_code)]
-
-use std::cell::RefCell;
-use std::rc::Rc;
-
-type NodeRef<T> = Rc<RefCell<Node<T>>>;
-type Link<T> = Option<NodeRef<T>>;
-
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct Node<T> {
-    value: T,
-    next: Link<T>,
+    value: Option<T>, // None once removed
+    prev: usize,
+    next: usize,
 }
 
-impl<T> Node<T>
-where
-    T: Copy,
-{
-    fn get_value(&self) -> T {
-        self.value
-    }
+/// Ring of values stored in an arena, with a cursor on one of them
+///
+/// Moving the cursor by one, inserting and removing are O(1). Removed slots are reused by later insertions.
+#[derive(Debug, Clone)]
+pub struct CircularLinkedList<T> {
+    nodes: Vec<Node<T>>,
+    free: Vec<usize>,
+    cursor: Option<usize>,
+    len: usize,
 }
 
-#[derive(Debug)]
-struct CircularLinkedList<T> {
-    head: Link<T>,
+impl<T> Default for CircularLinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
-impl<T> CircularLinkedList<T>
-where
-    T: std::fmt::Debug,
-{
-    fn new() -> Self {
-        CircularLinkedList { head: None }
-    }
-
-    fn append(&mut self, value: T) -> NodeRef<T> {
-        let new_node = Rc::new(RefCell::new(Node { value, next: None }));
-
-        match &self.head {
-            Some(head) => {
-                let mut tail = head.clone();
-                while tail
-                    .borrow()
-                    .next
-                    .as_ref()
-                    .is_some_and(|n| !Rc::ptr_eq(n, head))
-                {
-                    let new_tail = tail.borrow().next.as_ref().unwrap().clone();
-                    tail = new_tail;
-                }
-                tail.borrow_mut().next = Some(new_node.clone());
-                new_node.borrow_mut().next = Some(head.clone());
-            }
-            None => {
-                new_node.borrow_mut().next = Some(new_node.clone());
-                self.head = Some(new_node.clone());
-            }
+impl<T> CircularLinkedList<T> {
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        CircularLinkedList {
+            nodes: Vec::with_capacity(capacity),
+            free: Vec::new(),
+            cursor: None,
+            len: 0,
         }
-        new_node
     }
 
-    fn get_head(&self) -> NodeRef<T> {
-        self.head.as_ref().unwrap().clone()
+    pub fn len(&self) -> usize {
+        self.len
     }
 
-    fn insert_after_node(&mut self, node: NodeRef<T>, value: T) -> NodeRef<T> {
-        let new_node = Rc::new(RefCell::new(Node { value, next: None }));
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
 
-        let mut tail = node.clone();
-        while tail
-            .borrow()
-            .next
-            .as_ref()
-            .is_some_and(|n| !Rc::ptr_eq(n, &node))
-        {
-            let new_tail = tail.borrow().next.as_ref().unwrap().clone();
-            tail = new_tail;
-        }
-        tail.borrow_mut().next = Some(new_node.clone());
-        new_node.borrow_mut().next = Some(node.clone());
+    /// Value under the cursor, `None` when the list is empty
+    pub fn current(&self) -> Option<&T> {
+        self.cursor.and_then(|i| self.nodes[i].value.as_ref())
+    }
 
-        new_node
+    pub fn current_mut(&mut self) -> Option<&mut T> {
+        self.cursor.and_then(|i| self.nodes[i].value.as_mut())
     }
 
-    fn display(&self, count: usize) {
-        if self.head.is_none() {
-            println!("List is empty");
+    /// Moves the cursor `n` values clockwise, in at most `len / 2` steps
+    pub fn move_forward(&mut self, n: usize) {
+        if self.is_empty() {
             return;
         }
+        let n = n % self.len;
+        if n > self.len / 2 {
+            self.step_backward(self.len - n);
+        } else {
+            self.step_forward(n);
+        }
+    }
+
+    /// Moves the cursor `n` values counterclockwise, in at most `len / 2` steps
+    pub fn move_backward(&mut self, n: usize) {
+        if self.is_empty() {
+            return;
+        }
+        let n = n % self.len;
+        if n > self.len / 2 {
+            self.step_forward(self.len - n);
+        } else {
+            self.step_backward(n);
+        }
+    }
+
+    fn step_forward(&mut self, n: usize) {
+        if let Some(mut i) = self.cursor {
+            for _ in 0..n {
+                i = self.nodes[i].next;
+            }
+            self.cursor = Some(i);
+        }
+    }
+
+    fn step_backward(&mut self, n: usize) {
+        if let Some(mut i) = self.cursor {
+            for _ in 0..n {
+                i = self.nodes[i].prev;
+            }
+            self.cursor = Some(i);
+        }
+    }
 
-        let mut current = self.head.clone();
-        for _ in 0..count {
-            if let Some(node) = current {
-                print!("{:?} -> ", node.borrow().value);
-                current = node.borrow().next.clone();
+    /// Inserts `value` clockwise after the cursor and moves the cursor onto it
+    pub fn insert_after(&mut self, value: T) {
+        let (prev, next) = match self.cursor {
+            Some(current) => (current, self.nodes[current].next),
+            None => (self.nodes.len(), self.nodes.len()),
+        };
+        let node = Node {
+            value: Some(value),
+            prev,
+            next,
+        };
+        let i = match self.free.pop() {
+            Some(i) => {
+                self.nodes[i] = node;
+                i
             }
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+        };
+        if self.cursor.is_none() {
+            // The only value links to itself
+            self.nodes[i].prev = i;
+            self.nodes[i].next = i;
+        } else {
+            self.nodes[prev].next = i;
+            self.nodes[next].prev = i;
         }
-        println!("...");
+        self.cursor = Some(i);
+        self.len += 1;
+    }
+
+    /// Removes the value under the cursor, which moves to the next value clockwise
+    pub fn remove(&mut self) -> Option<T> {
+        let i = self.cursor?;
+        let Node { prev, next, .. } = self.nodes[i];
+        let value = self.nodes[i].value.take();
+        self.free.push(i);
+        self.len -= 1;
+        if self.len == 0 {
+            self.cursor = None;
+        } else {
+            self.nodes[prev].next = next;
+            self.nodes[next].prev = prev;
+            self.cursor = Some(next);
+        }
+        value
+    }
+
+    /// Values clockwise, starting with the one under the cursor
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        let mut i = self.cursor;
+        (0..self.len).filter_map(move |_| {
+            let node = &self.nodes[i?];
+            i = Some(node.next);
+            node.value.as_ref()
+        })
+    }
+}
+
+impl<T> FromIterator<T> for CircularLinkedList<T> {
+    /// The cursor is on the first value
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = CircularLinkedList::new();
+        for value in iter {
+            list.insert_after(value);
+        }
+        list.move_forward(1);
+        list
     }
 }
 
@@ -106,14 +176,68 @@ mod circular_linked_list_tests {
 
     #[test]
     fn test_1() {
-        let mut list = CircularLinkedList::new();
+        let mut list: CircularLinkedList<_> = [1, 2, 3].into_iter().collect();
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.current(), Some(&1));
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [1, 2, 3]);
+
+        list.move_forward(4);
+        assert_eq!(list.current(), Some(&2));
+        list.move_backward(2);
+        assert_eq!(list.current(), Some(&3));
+        list.move_backward(7);
+        assert_eq!(list.current(), Some(&2));
+
+        list.insert_after(4);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [4, 3, 1, 2]);
+        *list.current_mut().unwrap() = 5;
+        assert_eq!(list.remove(), Some(5));
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [3, 1, 2]);
+    }
 
-        list.append(1);
-        list.append(2);
-        list.append(3);
+    #[test]
+    fn test_remove_all() {
+        let mut list: CircularLinkedList<_> = (0..3).collect();
+        assert_eq!(list.remove(), Some(0));
+        assert_eq!(list.remove(), Some(1));
+        assert_eq!(list.remove(), Some(2));
+        assert_eq!(list.remove(), None);
+        assert!(list.is_empty());
+        assert_eq!(list.current(), None);
+        list.move_forward(3);
+
+        list.insert_after(7);
+        list.insert_after(8);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [8, 7]);
+        // Removed slots are reused
+        assert_eq!(list.nodes.len(), 3);
+    }
 
-        list.display(10);
+    #[test]
+    fn test_spinlock() {
+        let mut list: CircularLinkedList<_> = [0].into_iter().collect();
+        for value in 1..=2017 {
+            list.move_forward(3);
+            list.insert_after(value);
+        }
+        list.move_forward(1);
+        assert_eq!(list.current(), Some(&638));
+    }
 
-        assert_eq!(0, 1);
+    #[test]
+    fn test_marble_game() {
+        let (players, last_marble) = (10, 1618);
+        let mut scores = vec![0; players];
+        let mut circle: CircularLinkedList<_> = [0].into_iter().collect();
+        for marble in 1..=last_marble {
+            if marble % 23 == 0 {
+                circle.move_backward(7);
+                scores[marble % players] += marble + circle.remove().unwrap();
+            } else {
+                circle.move_forward(1);
+                circle.insert_after(marble);
+            }
+        }
+        assert_eq!(scores.into_iter().max(), Some(8317));
     }
 }
@@ -0,0 +1,162 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// States reached by repeatedly stepping from an initial state, up to the first repetition
+#[derive(Debug, Clone)]
+pub struct Cycle<S> {
+    /// Number of steps before entering the cycle (mu)
+    pub start: usize,
+    /// Number of states in the cycle (lambda)
+    pub length: usize,
+    states: Vec<S>, // the first start + length states
+}
+
+impl<S> Cycle<S> {
+    /// State after `steps` steps, for any number of steps
+    pub fn state_at(&self, steps: usize) -> &S {
+        if steps < self.start {
+            &self.states[steps]
+        } else {
+            &self.states[self.start + (steps - self.start) % self.length]
+        }
+    }
+
+    /// Every state before the first repetition, starting with the initial one
+    pub fn states(&self) -> &[S] {
+        &self.states
+    }
+}
+
+/// Steps from `initial` until a state repeats, remembering every state seen
+pub fn find_cycle<S, F>(initial: S, step: F) -> Cycle<S>
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> S,
+{
+    match run_until_repeat(initial, step, usize::MAX) {
+        Ok(cycle) => cycle,
+        Err(_) => unreachable!("the step limit cannot be reached"),
+    }
+}
+
+/// State after `steps` steps, skipping whole cycles as soon as a state repeats
+pub fn fast_forward<S, F>(initial: S, step: F, steps: usize) -> S
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> S,
+{
+    match run_until_repeat(initial, step, steps) {
+        Ok(cycle) => cycle.state_at(steps).clone(),
+        Err(state) => state,
+    }
+}
+
+/// Finds the cycle, or returns the state after `limit` steps when no state repeats before it
+fn run_until_repeat<S, F>(initial: S, mut step: F, limit: usize) -> Result<Cycle<S>, S>
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> S,
+{
+    let mut seen = HashMap::from([(initial.clone(), 0)]);
+    let mut states = vec![initial];
+    loop {
+        let current = states.last().unwrap();
+        if states.len() > limit {
+            return Err(current.clone());
+        }
+        let next = step(current);
+        if let Some(&start) = seen.get(&next) {
+            let length = states.len() - start;
+            return Ok(Cycle {
+                start,
+                length,
+                states,
+            });
+        }
+        seen.insert(next.clone(), states.len());
+        states.push(next);
+    }
+}
+
+/// Start (mu) and length (lambda) of the cycle with Brent's algorithm, which only keeps two states around
+pub fn brent<S, F>(initial: S, mut step: F) -> (usize, usize)
+where
+    S: Clone + Eq,
+    F: FnMut(&S) -> S,
+{
+    // Find the length by looking for the tortoise ahead of the hare, in windows of growing powers of two
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    // Then move two states `length` apart until they meet at the start of the cycle
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+    (start, length)
+}
+
+#[cfg(test)]
+mod cycle_tests {
+    use super::*;
+
+    // 0, 1, 2, then 3..=7 repeating
+    fn step(&x: &u32) -> u32 {
+        if x == 7 { 3 } else { x + 1 }
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        let cycle = find_cycle(0, step);
+        assert_eq!((cycle.start, cycle.length), (3, 5));
+        assert_eq!(cycle.states(), [0, 1, 2, 3, 4, 5, 6, 7]);
+        assert_eq!(*cycle.state_at(2), 2);
+        assert_eq!(*cycle.state_at(8), 3);
+        assert_eq!(*cycle.state_at(1_000_000_000), 3 + (1_000_000_000 - 3) % 5);
+    }
+
+    #[test]
+    fn test_fast_forward() {
+        assert_eq!(fast_forward(0, step, 0), 0);
+        assert_eq!(fast_forward(0, step, 2), 2);
+        assert_eq!(fast_forward(0, step, 12), 7);
+        assert_eq!(fast_forward(0, step, 1_000_000_000), 5);
+
+        // No repetition before the last step
+        let mut calls = 0;
+        let state = fast_forward(
+            0u64,
+            |&x| {
+                calls += 1;
+                x + 1
+            },
+            10,
+        );
+        assert_eq!((state, calls), (10, 10));
+    }
+
+    #[test]
+    fn test_brent() {
+        assert_eq!(brent(0, step), (3, 5));
+        assert_eq!(brent(5, step), (0, 5));
+        assert_eq!(brent(1, |&x: &u32| x), (0, 1));
+    }
+}
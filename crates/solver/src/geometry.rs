@@ -0,0 +1,441 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use crate::ParseError;
+
+/// Point or vector on a plane, `y` grows downwards like the rows of a map
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// Point or vector in space
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Point2 { x, y }
+    }
+}
+
+impl<T> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Point3 { x, y, z }
+    }
+}
+
+/// The four directions of a map, `Up` is towards lower `y`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Clockwise from `Up`
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn turn_right(self) -> Direction {
+        Direction::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Direction {
+        Direction::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Direction {
+        Direction::ALL[(self as usize + 2) % 4]
+    }
+
+    /// Step of one unit as `(x, y)`
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Direction::Up => (0, -1),
+            Direction::Right => (1, 0),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+        }
+    }
+
+    /// Accepts arrows `^>v<`, compass points `NESW` and `UDLR`
+    pub fn from_char(c: char) -> Option<Direction> {
+        match c {
+            '^' | 'N' | 'U' => Some(Direction::Up),
+            '>' | 'E' | 'R' => Some(Direction::Right),
+            'v' | 'S' | 'D' => Some(Direction::Down),
+            '<' | 'W' | 'L' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+}
+
+impl TryFrom<char> for Direction {
+    type Error = ParseError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        Direction::from_char(c).ok_or_else(|| ParseError::expected("a direction", &c.to_string()))
+    }
+}
+
+/// The eight directions of a map, the four of `Direction` and the diagonals between them
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    /// Clockwise from `Up`
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    /// Turns by 45 degrees
+    pub fn turn_right(self) -> Direction8 {
+        Direction8::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Turns by 45 degrees
+    pub fn turn_left(self) -> Direction8 {
+        Direction8::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn reverse(self) -> Direction8 {
+        Direction8::ALL[(self as usize + 4) % 8]
+    }
+
+    /// Step of one unit as `(x, y)`, a diagonal step moves along both axes
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Direction8::Up => (0, -1),
+            Direction8::UpRight => (1, -1),
+            Direction8::Right => (1, 0),
+            Direction8::DownRight => (1, 1),
+            Direction8::Down => (0, 1),
+            Direction8::DownLeft => (-1, 1),
+            Direction8::Left => (-1, 0),
+            Direction8::UpLeft => (-1, -1),
+        }
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        Direction8::ALL[direction as usize * 2]
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point2<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Point2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point2<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Point2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Point2<T> {
+    type Output = Self;
+
+    fn mul(self, factor: T) -> Self {
+        Point2::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point2<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Point2::new(-self.x, -self.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point2<T> {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point2<T> {
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point3<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Point3::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point3<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Point3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Point3<T> {
+    type Output = Self;
+
+    fn mul(self, factor: T) -> Self {
+        Point3::new(self.x * factor, self.y * factor, self.z * factor)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point3<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Point3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point3<T> {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+        self.z += other.z;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point3<T> {
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+        self.z -= other.z;
+    }
+}
+
+/// Distances of integer points, `$distance` is the unsigned type of the same size
+macro_rules! impl_distances {
+    ($($t:ty => $distance:ty),*) => {
+        $(impl Point2<$t> {
+            pub fn manhattan_distance(self, other: Self) -> $distance {
+                self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+            }
+
+            /// Number of king moves between the points
+            pub fn chebyshev_distance(self, other: Self) -> $distance {
+                self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+            }
+
+            pub fn euclidean_distance(self, other: Self) -> f64 {
+                let dx = self.x.abs_diff(other.x) as f64;
+                let dy = self.y.abs_diff(other.y) as f64;
+                dx.hypot(dy)
+            }
+        }
+
+        impl Point3<$t> {
+            pub fn manhattan_distance(self, other: Self) -> $distance {
+                self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z)
+            }
+
+            pub fn chebyshev_distance(self, other: Self) -> $distance {
+                self.x
+                    .abs_diff(other.x)
+                    .max(self.y.abs_diff(other.y))
+                    .max(self.z.abs_diff(other.z))
+            }
+
+            pub fn euclidean_distance(self, other: Self) -> f64 {
+                let dx = self.x.abs_diff(other.x) as f64;
+                let dy = self.y.abs_diff(other.y) as f64;
+                let dz = self.z.abs_diff(other.z) as f64;
+                (dx * dx + dy * dy + dz * dz).sqrt()
+            }
+        })*
+    };
+}
+
+impl_distances!(
+    i32 => u32, i64 => u64, isize => usize, u32 => u32, u64 => u64, usize => usize
+);
+
+/// Moves of signed points, which never leave the plane
+macro_rules! impl_signed_moves {
+    ($($t:ty),*) => {
+        $(impl Point2<$t> {
+            pub fn step(self, direction: Direction) -> Self {
+                self.step8(direction.into())
+            }
+
+            pub fn step8(self, direction: Direction8) -> Self {
+                let (dx, dy) = direction.offset();
+                Point2::new(self.x + dx as $t, self.y + dy as $t)
+            }
+
+            /// Rotates the vector by 90 degrees clockwise, as seen on a map
+            pub fn rotate_right(self) -> Self {
+                Point2::new(-self.y, self.x)
+            }
+
+            /// Rotates the vector by 90 degrees counterclockwise, as seen on a map
+            pub fn rotate_left(self) -> Self {
+                Point2::new(self.y, -self.x)
+            }
+
+            pub fn neighbors4(self) -> impl Iterator<Item = Self> {
+                Direction::ALL.into_iter().map(move |direction| self.step(direction))
+            }
+
+            pub fn neighbors8(self) -> impl Iterator<Item = Self> {
+                Direction8::ALL.into_iter().map(move |direction| self.step8(direction))
+            }
+        })*
+    };
+}
+
+/// Moves of unsigned points, `None` when a coordinate would become negative
+macro_rules! impl_unsigned_moves {
+    ($($t:ty => $signed:ty),*) => {
+        $(impl Point2<$t> {
+            pub fn checked_step(self, direction: Direction) -> Option<Self> {
+                self.checked_step8(direction.into())
+            }
+
+            pub fn checked_step8(self, direction: Direction8) -> Option<Self> {
+                let (dx, dy) = direction.offset();
+                Some(Point2::new(
+                    self.x.checked_add_signed(dx as $signed)?,
+                    self.y.checked_add_signed(dy as $signed)?,
+                ))
+            }
+
+            pub fn neighbors4(self) -> impl Iterator<Item = Self> {
+                Direction::ALL
+                    .into_iter()
+                    .filter_map(move |direction| self.checked_step(direction))
+            }
+
+            pub fn neighbors8(self) -> impl Iterator<Item = Self> {
+                Direction8::ALL
+                    .into_iter()
+                    .filter_map(move |direction| self.checked_step8(direction))
+            }
+        })*
+    };
+}
+
+impl_signed_moves!(i32, i64, isize);
+impl_unsigned_moves!(u32 => i32, u64 => i64, usize => isize);
+
+#[cfg(test)]
+mod geometry_tests {
+    use super::*;
+
+    #[test]
+    fn test_directions() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.turn_right(), Direction::Up);
+        assert_eq!(Direction::Down.reverse(), Direction::Up);
+        assert_eq!(Direction8::Up.turn_left(), Direction8::UpLeft);
+        assert_eq!(Direction8::DownRight.reverse(), Direction8::UpLeft);
+        assert_eq!(Direction8::from(Direction::Left), Direction8::Left);
+    }
+
+    #[test]
+    fn test_parse_directions() {
+        let parse = |text: &str| -> Vec<_> { text.chars().map(Direction::from_char).collect() };
+        let expected = Direction::ALL.map(Some).to_vec();
+        assert_eq!(parse("^>v<"), expected);
+        assert_eq!(parse("NESW"), expected);
+        assert_eq!(parse("URDL"), expected);
+        assert_eq!(Direction::from_char('x'), None);
+        assert!(Direction::try_from('x').is_err());
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Point2::new(1, 2);
+        let b = Point2::new(4, -2);
+        assert_eq!(a + b, Point2::new(5, 0));
+        assert_eq!(a - b, Point2::new(-3, 4));
+        assert_eq!(a * 3, Point2::new(3, 6));
+        assert_eq!(-a, Point2::new(-1, -2));
+        let mut c = a;
+        c += b;
+        c -= a;
+        assert_eq!(c, b);
+        assert_eq!(
+            Point3::new(1, 2, 3) + Point3::new(1, 1, 1) * 2,
+            Point3::new(3, 4, 5)
+        );
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Point2::new(1i64, 2);
+        let b = Point2::new(4i64, -2);
+        assert_eq!(a.manhattan_distance(b), 7);
+        assert_eq!(a.chebyshev_distance(b), 4);
+        assert_eq!(a.euclidean_distance(b), 5.0);
+        let c = Point3::new(0usize, 0, 0);
+        let d = Point3::new(1usize, 2, 2);
+        assert_eq!(c.manhattan_distance(d), 5);
+        assert_eq!(c.chebyshev_distance(d), 2);
+        assert_eq!(c.euclidean_distance(d), 3.0);
+    }
+
+    #[test]
+    fn test_moves() {
+        let origin = Point2::new(0i32, 0);
+        assert_eq!(origin.step(Direction::Up), Point2::new(0, -1));
+        assert_eq!(origin.step8(Direction8::DownLeft), Point2::new(-1, 1));
+        assert_eq!(origin.neighbors8().count(), 8);
+
+        let facing = Point2::new(0i32, -1);
+        assert_eq!(facing.rotate_right(), Point2::new(1, 0));
+        assert_eq!(facing.rotate_left(), Point2::new(-1, 0));
+
+        let corner = Point2::new(0usize, 0);
+        assert_eq!(corner.checked_step(Direction::Left), None);
+        assert_eq!(
+            corner.checked_step(Direction::Down),
+            Some(Point2::new(0, 1))
+        );
+        assert_eq!(
+            corner.neighbors4().collect::<Vec<_>>(),
+            [Point2::new(1, 0), Point2::new(0, 1)]
+        );
+    }
+}
@@ -0,0 +1,300 @@
+use std::ops::BitXorAssign;
+
+/// Fixed-length vector over GF(2), stored as bits
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitVector {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl BitVector {
+    /// All-zero vector of `len` bits
+    pub fn new(len: usize) -> Self {
+        BitVector {
+            words: vec![0; len.div_ceil(64)],
+            len,
+        }
+    }
+
+    /// Vector of `len` bits where the bits at `indices` are set
+    pub fn from_indices(len: usize, indices: impl IntoIterator<Item = usize>) -> Self {
+        let mut vector = BitVector::new(len);
+        for index in indices {
+            vector.set(index, true);
+        }
+        vector
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn get(&self, index: usize) -> bool {
+        assert!(index < self.len, "bit {index} out of {}", self.len);
+        self.words[index / 64] >> (index % 64) & 1 == 1
+    }
+
+    pub fn set(&mut self, index: usize, value: bool) {
+        assert!(index < self.len, "bit {index} out of {}", self.len);
+        let mask = 1 << (index % 64);
+        if value {
+            self.words[index / 64] |= mask;
+        } else {
+            self.words[index / 64] &= !mask;
+        }
+    }
+
+    pub fn flip(&mut self, index: usize) {
+        assert!(index < self.len, "bit {index} out of {}", self.len);
+        self.words[index / 64] ^= 1 << (index % 64);
+    }
+
+    /// Number of set bits, the Hamming weight
+    pub fn count_ones(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.words.iter().all(|&word| word == 0)
+    }
+
+    /// Indices of the set bits in increasing order
+    pub fn ones(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(i, &word)| {
+            let mut rest = word;
+            std::iter::from_fn(move || {
+                (rest != 0).then(|| {
+                    let bit = rest.trailing_zeros() as usize;
+                    rest &= rest - 1;
+                    i * 64 + bit
+                })
+            })
+        })
+    }
+}
+
+impl BitXorAssign<&BitVector> for BitVector {
+    fn bitxor_assign(&mut self, other: &BitVector) {
+        assert_eq!(self.len, other.len, "expected vectors of the same length");
+        for (word, &other_word) in self.words.iter_mut().zip(&other.words) {
+            *word ^= other_word;
+        }
+    }
+}
+
+/// Linear equations over GF(2), where adding is XOR: each equation is a set of variables and the parity of their sum
+#[derive(Debug, Clone)]
+pub struct Gf2System {
+    rows: Vec<BitVector>, // coefficients followed by the constant
+    variable_count: usize,
+}
+
+/// Every solution of a `Gf2System`, a particular one plus any combination of the nullspace basis
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Gf2Solutions {
+    pub particular: BitVector,
+    pub nullspace: Vec<BitVector>,
+}
+
+impl Gf2System {
+    pub fn new(variable_count: usize) -> Self {
+        Gf2System {
+            rows: Vec::new(),
+            variable_count,
+        }
+    }
+
+    /// System where variable `j` toggles the equations of `columns[j]`, and `target` gives the parity of each equation
+    ///
+    /// This is the shape of "which switches to flip to reach a pattern" puzzles.
+    pub fn from_columns(columns: &[BitVector], target: &BitVector) -> Self {
+        let mut system = Gf2System::new(columns.len());
+        for equation in 0..target.len() {
+            let coefficients = BitVector::from_indices(
+                columns.len(),
+                (0..columns.len()).filter(|&j| columns[j].get(equation)),
+            );
+            system.push_equation(&coefficients, target.get(equation));
+        }
+        system
+    }
+
+    pub fn variable_count(&self) -> usize {
+        self.variable_count
+    }
+
+    /// Adds the equation `sum of the variables set in coefficients = constant`
+    pub fn push_equation(&mut self, coefficients: &BitVector, constant: bool) {
+        assert_eq!(
+            coefficients.len(),
+            self.variable_count,
+            "expected a coefficient per variable"
+        );
+        let mut row = BitVector::from_indices(self.variable_count + 1, coefficients.ones());
+        row.set(self.variable_count, constant);
+        self.rows.push(row);
+    }
+
+    // Gauss-Jordan elimination, returns the reduced rows and their pivot columns
+    fn eliminate(&self) -> (Vec<BitVector>, Vec<usize>) {
+        let mut rows = self.rows.clone();
+        let mut pivots = Vec::new();
+        for column in 0..self.variable_count {
+            let rank = pivots.len();
+            let Some(pivot_row) = (rank..rows.len()).find(|&i| rows[i].get(column)) else {
+                continue;
+            };
+            rows.swap(rank, pivot_row);
+            let pivot = rows[rank].clone();
+            for (i, row) in rows.iter_mut().enumerate() {
+                if i != rank && row.get(column) {
+                    *row ^= &pivot;
+                }
+            }
+            pivots.push(column);
+        }
+        (rows, pivots)
+    }
+
+    /// Number of independent equations
+    pub fn rank(&self) -> usize {
+        self.eliminate().1.len()
+    }
+
+    /// All the solutions, `None` when the equations contradict each other
+    pub fn solve(&self) -> Option<Gf2Solutions> {
+        let (rows, pivots) = self.eliminate();
+        // The rows below the pivots have no coefficients left, an equation 0 = 1 has no solution
+        if rows[pivots.len()..]
+            .iter()
+            .any(|row| row.get(self.variable_count))
+        {
+            return None;
+        }
+
+        let mut particular = BitVector::new(self.variable_count);
+        for (row, &pivot) in rows.iter().zip(&pivots) {
+            particular.set(pivot, row.get(self.variable_count));
+        }
+        // Setting a free variable flips the pivot variables of the rows which contain it
+        let nullspace = (0..self.variable_count)
+            .filter(|variable| !pivots.contains(variable))
+            .map(|free| {
+                let mut vector = BitVector::new(self.variable_count);
+                vector.set(free, true);
+                for (row, &pivot) in rows.iter().zip(&pivots) {
+                    if row.get(free) {
+                        vector.set(pivot, true);
+                    }
+                }
+                vector
+            })
+            .collect();
+        Some(Gf2Solutions {
+            particular,
+            nullspace,
+        })
+    }
+
+    /// Solution with the fewest variables set, `None` when there is no solution
+    pub fn min_weight_solution(&self) -> Option<BitVector> {
+        self.solve().map(|solutions| solutions.min_weight())
+    }
+}
+
+impl Gf2Solutions {
+    /// Number of solutions, `2^dimension` of the nullspace
+    pub fn count(&self) -> u128 {
+        1 << self.nullspace.len()
+    }
+
+    /// Solution with the fewest variables set, enumerating every combination of the nullspace basis
+    pub fn min_weight(&self) -> BitVector {
+        assert!(
+            self.nullspace.len() < 64,
+            "too many solutions to enumerate, the nullspace has dimension {}",
+            self.nullspace.len()
+        );
+        let mut current = self.particular.clone();
+        let mut best = current.clone();
+        // Gray code order, each step adds a single basis vector
+        for step in 1..1u64 << self.nullspace.len() {
+            current ^= &self.nullspace[step.trailing_zeros() as usize];
+            if current.count_ones() < best.count_ones() {
+                best = current.clone();
+            }
+        }
+        best
+    }
+}
+
+#[cfg(test)]
+mod gf2_tests {
+    use super::*;
+
+    #[test]
+    fn test_bit_vector() {
+        let mut vector = BitVector::from_indices(130, [0, 63, 64, 129]);
+        assert_eq!(vector.count_ones(), 4);
+        assert!(vector.get(63) && vector.get(64) && !vector.get(65));
+        vector.flip(63);
+        vector.set(100, true);
+        assert_eq!(vector.ones().collect::<Vec<_>>(), [0, 64, 100, 129]);
+
+        vector ^= &BitVector::from_indices(130, [0, 64, 100, 129]);
+        assert!(vector.is_zero());
+    }
+
+    #[test]
+    fn test_solve() {
+        // x0 + x1 = 1, x1 + x2 = 0, x0 + x2 = 1 has a free variable
+        let mut system = Gf2System::new(3);
+        system.push_equation(&BitVector::from_indices(3, [0, 1]), true);
+        system.push_equation(&BitVector::from_indices(3, [1, 2]), false);
+        system.push_equation(&BitVector::from_indices(3, [0, 2]), true);
+        assert_eq!(system.rank(), 2);
+        let solutions = system.solve().unwrap();
+        assert_eq!(solutions.count(), 2);
+        assert_eq!(solutions.nullspace, [BitVector::from_indices(3, [0, 1, 2])]);
+        assert_eq!(solutions.particular, BitVector::from_indices(3, [0]));
+
+        // Adding x1 + x2 = 1 makes it inconsistent
+        system.push_equation(&BitVector::from_indices(3, [1, 2]), true);
+        assert_eq!(system.solve(), None);
+    }
+
+    #[test]
+    fn test_min_weight_solution() {
+        // The first machine of 2025 day 10: [.##.] (3) (1,3) (2) (2,3) (0,2) (0,1)
+        let columns: Vec<_> = [
+            vec![3],
+            vec![1, 3],
+            vec![2],
+            vec![2, 3],
+            vec![0, 2],
+            vec![0, 1],
+        ]
+        .into_iter()
+        .map(|lights| BitVector::from_indices(4, lights))
+        .collect();
+        let target = BitVector::from_indices(4, [1, 2]);
+        let system = Gf2System::from_columns(&columns, &target);
+        assert_eq!(system.rank(), 4);
+        let solution = system.min_weight_solution().unwrap();
+        assert_eq!(solution.count_ones(), 2);
+
+        // The switches of the solution give the target back
+        let mut lights = BitVector::new(4);
+        for switch in solution.ones() {
+            lights ^= &columns[switch];
+        }
+        assert_eq!(lights, target);
+    }
+}
@@ -0,0 +1,371 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+use crate::DenseUnionFind;
+
+/// Graph over nodes `N`, built from a list of edges
+///
+/// Nodes are kept in the order they are first seen, which makes the results of the algorithms deterministic.
+#[derive(Debug, Clone)]
+pub struct Graph<N> {
+    directed: bool,
+    nodes: Vec<N>,
+    index: HashMap<N, usize>,
+    successors: Vec<Vec<usize>>,
+    predecessors: Vec<Vec<usize>>,
+    edges: HashSet<(usize, usize)>,
+}
+
+impl<N> Graph<N>
+where
+    N: Eq + Hash + Clone,
+{
+    fn new(directed: bool) -> Self {
+        Graph {
+            directed,
+            nodes: Vec::new(),
+            index: HashMap::new(),
+            successors: Vec::new(),
+            predecessors: Vec::new(),
+            edges: HashSet::new(),
+        }
+    }
+
+    /// Graph where an edge `(a, b)` goes from `a` to `b`
+    pub fn directed(edges: impl IntoIterator<Item = (N, N)>) -> Self {
+        let mut graph = Graph::new(true);
+        for (a, b) in edges {
+            graph.add_edge(a, b);
+        }
+        graph
+    }
+
+    /// Graph where an edge `(a, b)` also goes from `b` to `a`
+    pub fn undirected(edges: impl IntoIterator<Item = (N, N)>) -> Self {
+        let mut graph = Graph::new(false);
+        for (a, b) in edges {
+            graph.add_edge(a, b);
+        }
+        graph
+    }
+
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    /// Adds a node without edges unless it is already known, returns its index
+    pub fn add_node(&mut self, node: N) -> usize {
+        if let Some(&i) = self.index.get(&node) {
+            return i;
+        }
+        let i = self.nodes.len();
+        self.index.insert(node.clone(), i);
+        self.nodes.push(node);
+        self.successors.push(Vec::new());
+        self.predecessors.push(Vec::new());
+        i
+    }
+
+    /// Adds both nodes when needed, returns false when the edge was already there
+    ///
+    /// An undirected graph keeps no loop from a node to itself, a node would be its own neighbor in the cliques.
+    pub fn add_edge(&mut self, a: N, b: N) -> bool {
+        let (i, j) = (self.add_node(a), self.add_node(b));
+        if (!self.directed && i == j) || !self.edges.insert((i, j)) {
+            return false;
+        }
+        self.successors[i].push(j);
+        self.predecessors[j].push(i);
+        if !self.directed {
+            self.edges.insert((j, i));
+            self.successors[j].push(i);
+            self.predecessors[i].push(j);
+        }
+        true
+    }
+
+    /// Nodes in the order they were added
+    pub fn nodes(&self) -> &[N] {
+        &self.nodes
+    }
+
+    /// Number of nodes
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn contains(&self, node: &N) -> bool {
+        self.index.contains_key(node)
+    }
+
+    pub fn has_edge(&self, a: &N, b: &N) -> bool {
+        match (self.index.get(a), self.index.get(b)) {
+            (Some(&i), Some(&j)) => self.edges.contains(&(i, j)),
+            _ => false,
+        }
+    }
+
+    /// Nodes reached by an edge from `node`
+    pub fn neighbors<'a>(&'a self, node: &N) -> impl Iterator<Item = &'a N> + 'a {
+        let successors = self
+            .index
+            .get(node)
+            .map_or(&[][..], |&i| &self.successors[i]);
+        successors.iter().map(|&j| &self.nodes[j])
+    }
+
+    /// Number of edges leaving `node`, which is its number of neighbors in an undirected graph
+    pub fn degree(&self, node: &N) -> usize {
+        self.index
+            .get(node)
+            .map_or(0, |&i| self.successors[i].len())
+    }
+
+    /// Number of edges reaching `node`
+    pub fn in_degree(&self, node: &N) -> usize {
+        self.index
+            .get(node)
+            .map_or(0, |&i| self.predecessors[i].len())
+    }
+
+    /// Nodes connected by edges in either direction, largest components first
+    pub fn connected_components(&self) -> Vec<Vec<N>> {
+        let mut sets = DenseUnionFind::new(self.len());
+        for &(i, j) in &self.edges {
+            sets.union(i, j);
+        }
+        let mut components: HashMap<usize, Vec<N>> = HashMap::new();
+        for (i, node) in self.nodes.iter().enumerate() {
+            components
+                .entry(sets.find(i))
+                .or_default()
+                .push(node.clone());
+        }
+        let mut components: Vec<_> = components.into_values().collect();
+        components.sort_by_key(|component| {
+            (
+                std::cmp::Reverse(component.len()),
+                self.index[&component[0]],
+            )
+        });
+        components
+    }
+
+    /// Nodes ordered so that every edge goes forward, with Kahn's algorithm
+    ///
+    /// Fails with the nodes which are on a cycle or after one.
+    pub fn topological_sort(&self) -> Result<Vec<N>, Vec<N>> {
+        let mut in_degrees: Vec<usize> = self.predecessors.iter().map(Vec::len).collect();
+        let mut ready: VecDeque<usize> = (0..self.len()).filter(|&i| in_degrees[i] == 0).collect();
+        let mut order = Vec::with_capacity(self.len());
+        while let Some(i) = ready.pop_front() {
+            order.push(i);
+            for &j in &self.successors[i] {
+                in_degrees[j] -= 1;
+                if in_degrees[j] == 0 {
+                    ready.push_back(j);
+                }
+            }
+        }
+        if order.len() == self.len() {
+            Ok(order.into_iter().map(|i| self.nodes[i].clone()).collect())
+        } else {
+            Err((0..self.len())
+                .filter(|&i| in_degrees[i] > 0)
+                .map(|i| self.nodes[i].clone())
+                .collect())
+        }
+    }
+
+    /// Sets of three nodes which are all connected to each other, in an undirected graph
+    pub fn triangles(&self) -> Vec<[N; 3]> {
+        let mut triangles = Vec::new();
+        for (i, successors) in self.successors.iter().enumerate() {
+            for &j in successors.iter().filter(|&&j| j > i) {
+                for &k in self.successors[j].iter().filter(|&&k| k > j) {
+                    if self.edges.contains(&(i, k)) {
+                        triangles.push([i, j, k].map(|n| self.nodes[n].clone()));
+                    }
+                }
+            }
+        }
+        triangles
+    }
+
+    /// Cliques which cannot be extended, in an undirected graph, with Bron–Kerbosch and pivoting
+    pub fn maximal_cliques(&self) -> Vec<Vec<N>> {
+        let mut cliques = Vec::new();
+        self.bron_kerbosch(
+            &mut Vec::new(),
+            (0..self.len()).collect(),
+            Vec::new(),
+            &mut |clique| cliques.push(clique.iter().map(|&i| self.nodes[i].clone()).collect()),
+        );
+        cliques
+    }
+
+    /// One of the largest cliques, in an undirected graph
+    pub fn maximum_clique(&self) -> Vec<N> {
+        let mut largest = Vec::new();
+        self.bron_kerbosch(
+            &mut Vec::new(),
+            (0..self.len()).collect(),
+            Vec::new(),
+            &mut |clique| {
+                if clique.len() > largest.len() {
+                    largest = clique.to_vec();
+                }
+            },
+        );
+        largest.into_iter().map(|i| self.nodes[i].clone()).collect()
+    }
+
+    // `clique` is the current clique, `candidates` can extend it and `excluded` were already tried
+    fn bron_kerbosch(
+        &self,
+        clique: &mut Vec<usize>,
+        mut candidates: Vec<usize>,
+        mut excluded: Vec<usize>,
+        report: &mut impl FnMut(&[usize]),
+    ) {
+        if candidates.is_empty() {
+            if excluded.is_empty() {
+                report(clique);
+            }
+            return;
+        }
+
+        // Any maximal clique contains the pivot or one of its non-neighbors
+        let pivot = candidates
+            .iter()
+            .chain(&excluded)
+            .copied()
+            .max_by_key(|&u| {
+                candidates
+                    .iter()
+                    .filter(|&&v| self.edges.contains(&(u, v)))
+                    .count()
+            })
+            .unwrap();
+        let branches: Vec<usize> = candidates
+            .iter()
+            .copied()
+            .filter(|&v| !self.edges.contains(&(pivot, v)))
+            .collect();
+
+        for v in branches {
+            let is_neighbor = |&u: &usize| self.edges.contains(&(v, u));
+            clique.push(v);
+            self.bron_kerbosch(
+                clique,
+                candidates.iter().copied().filter(is_neighbor).collect(),
+                excluded.iter().copied().filter(is_neighbor).collect(),
+                report,
+            );
+            clique.pop();
+            candidates.retain(|&u| u != v);
+            excluded.push(v);
+        }
+    }
+}
+
+#[cfg(test)]
+mod graph_tests {
+    use super::*;
+
+    #[test]
+    fn test_undirected() {
+        let mut graph = Graph::undirected([("a", "b"), ("b", "c"), ("d", "e")]);
+        assert!(!graph.add_edge("b", "a"));
+        graph.add_node("f");
+        assert_eq!(graph.len(), 6);
+        assert!(graph.has_edge(&"c", &"b"));
+        assert!(!graph.has_edge(&"a", &"c"));
+        assert_eq!(graph.degree(&"b"), 2);
+        assert_eq!(graph.in_degree(&"b"), 2);
+        assert_eq!(graph.degree(&"x"), 0);
+        assert_eq!(graph.neighbors(&"b").collect::<Vec<_>>(), [&"a", &"c"]);
+        assert_eq!(
+            graph.connected_components(),
+            [vec!["a", "b", "c"], vec!["d", "e"], vec!["f"]]
+        );
+    }
+
+    #[test]
+    fn test_topological_sort() {
+        let graph = Graph::directed([
+            (5, 11),
+            (7, 11),
+            (7, 8),
+            (3, 8),
+            (3, 10),
+            (11, 2),
+            (11, 9),
+            (8, 9),
+        ]);
+        let order = graph.topological_sort().unwrap();
+        assert_eq!(order.len(), 8);
+        let position = |node| order.iter().position(|&n| n == node).unwrap();
+        for (a, b) in [
+            (5, 11),
+            (7, 11),
+            (7, 8),
+            (3, 8),
+            (3, 10),
+            (11, 2),
+            (11, 9),
+            (8, 9),
+        ] {
+            assert!(position(a) < position(b));
+        }
+        assert_eq!(graph.in_degree(&9), 2);
+        assert_eq!(graph.connected_components().len(), 1);
+
+        let graph = Graph::directed([(1, 2), (2, 3), (3, 2), (3, 4), (0, 1)]);
+        assert_eq!(graph.topological_sort(), Err(vec![2, 3, 4]));
+    }
+
+    #[test]
+    fn test_cliques() {
+        // Two triangles sharing an edge, plus a 4-clique
+        let graph = Graph::undirected([
+            (1, 2),
+            (2, 3),
+            (3, 1),
+            (2, 4),
+            (3, 4),
+            (5, 6),
+            (5, 7),
+            (5, 8),
+            (6, 7),
+            (6, 8),
+            (7, 8),
+        ]);
+        assert_eq!(graph.triangles().len(), 6);
+
+        let mut cliques: Vec<_> = graph
+            .maximal_cliques()
+            .into_iter()
+            .map(|mut clique| {
+                clique.sort();
+                clique
+            })
+            .collect();
+        cliques.sort();
+        assert_eq!(cliques, [vec![1, 2, 3], vec![2, 3, 4], vec![5, 6, 7, 8]]);
+
+        let looped = Graph::undirected([(1, 1), (1, 2), (2, 2)]);
+        assert!(!looped.has_edge(&1, &1));
+        assert_eq!(looped.degree(&2), 1);
+        assert_eq!(looped.maximal_cliques(), [vec![2, 1]]);
+        assert_eq!(looped.maximum_clique().len(), 2);
+
+        let mut largest = graph.maximum_clique();
+        largest.sort();
+        assert_eq!(largest, [5, 6, 7, 8]);
+    }
+}
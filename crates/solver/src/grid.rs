@@ -0,0 +1,428 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::{ParseError, Point2, parse_lines};
+
+/// Offsets of the orthogonal neighbors as `(row, column)`, clockwise from up
+pub const NEIGHBORS_4: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// Offsets of the orthogonal and diagonal neighbors as `(row, column)`, clockwise from up
+pub const NEIGHBORS_8: [(isize, isize); 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+/// Rectangular grid stored row by row, cells are addressed by `(row, column)`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Returns `None` when the rows are not of the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let width = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+        Some(Grid {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Parses a character map, an error of `f` is located at the character it comes from
+    pub fn parse(
+        input: &str,
+        mut f: impl FnMut(char) -> Result<T, ParseError>,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let rows = parse_lines(input, |line| {
+            let row = line
+                .chars()
+                .enumerate()
+                .map(|(column, c)| {
+                    f(c).map_err(|error| match error.column() {
+                        Some(_) => error,
+                        None => error.at_column(column + 1),
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            match width {
+                Some(width) if width != row.len() => Err(ParseError::new(format!(
+                    "expected a row of {width} columns"
+                ))),
+                _ => {
+                    width = Some(row.len());
+                    Ok(row)
+                }
+            }
+        })?;
+        Ok(Grid::from_rows(rows).expect("rows have the same length"))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (row, column): (usize, usize)) -> bool {
+        row < self.height && column < self.width
+    }
+
+    pub fn get(&self, position: (usize, usize)) -> Option<&T> {
+        self.contains(position)
+            .then(|| &self.cells[position.0 * self.width + position.1])
+    }
+
+    pub fn get_mut(&mut self, position: (usize, usize)) -> Option<&mut T> {
+        self.contains(position)
+            .then(|| &mut self.cells[position.0 * self.width + position.1])
+    }
+
+    /// Moves by `(rows, columns)`, `None` when the result is outside of the grid
+    pub fn offset(
+        &self,
+        (row, column): (usize, usize),
+        (rows, columns): (isize, isize),
+    ) -> Option<(usize, usize)> {
+        let position = (
+            row.checked_add_signed(rows)?,
+            column.checked_add_signed(columns)?,
+        );
+        self.contains(position).then_some(position)
+    }
+
+    /// Moves by `(rows, columns)`, leaving the grid on one edge enters it on the opposite one
+    pub fn wrapping_offset(
+        &self,
+        (row, column): (usize, usize),
+        (rows, columns): (isize, isize),
+    ) -> (usize, usize) {
+        (
+            (row as isize + rows).rem_euclid(self.height as isize) as usize,
+            (column as isize + columns).rem_euclid(self.width as isize) as usize,
+        )
+    }
+
+    pub fn neighbors4(&self, position: (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+        NEIGHBORS_4
+            .into_iter()
+            .filter_map(move |offset| self.offset(position, offset))
+    }
+
+    pub fn neighbors8(&self, position: (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+        NEIGHBORS_8
+            .into_iter()
+            .filter_map(move |offset| self.offset(position, offset))
+    }
+
+    pub fn wrapping_neighbors4(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> {
+        NEIGHBORS_4
+            .into_iter()
+            .map(move |offset| self.wrapping_offset(position, offset))
+    }
+
+    pub fn wrapping_neighbors8(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> {
+        NEIGHBORS_8
+            .into_iter()
+            .map(move |offset| self.wrapping_offset(position, offset))
+    }
+
+    /// All positions, row by row
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |column| (row, column)))
+    }
+
+    /// Cells together with their positions, row by row
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// Position of the first cell matching `predicate`, row by row
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<(usize, usize)> {
+        self.iter()
+            .find(|(_, value)| predicate(value))
+            .map(|(position, _)| position)
+    }
+
+    pub fn position_of(&self, value: &T) -> Option<(usize, usize)>
+    where
+        T: PartialEq,
+    {
+        self.find(|cell| cell == value)
+    }
+
+    pub fn count(&self, mut predicate: impl FnMut(&T) -> bool) -> usize {
+        self.cells.iter().filter(|value| predicate(value)).count()
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    pub fn column(&self, column: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            column < self.width,
+            "column {column} is outside of the grid"
+        );
+        self.cells[column..].iter().step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|column| self.column(column))
+    }
+
+    /// Diagonals running down and right, from the bottom left corner to the top right one
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..(self.width + self.height).saturating_sub(1)).map(move |index| {
+            let (row, column) = match index < self.height {
+                true => (self.height - 1 - index, 0),
+                false => (0, index + 1 - self.height),
+            };
+            self.ray((row, column), (1, 1))
+        })
+    }
+
+    /// Diagonals running down and left, from the top left corner to the bottom right one
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..(self.width + self.height).saturating_sub(1)).map(move |index| {
+            let (row, column) = match index < self.width {
+                true => (0, index),
+                false => (index + 1 - self.width, self.width - 1),
+            };
+            self.ray((row, column), (1, -1))
+        })
+    }
+
+    /// Cells from `start` in steps of `step` until the edge of the grid
+    pub fn ray(
+        &self,
+        start: (usize, usize),
+        step: (isize, isize),
+    ) -> impl Iterator<Item = &T> + '_ {
+        std::iter::successors(self.contains(start).then_some(start), move |&position| {
+            self.offset(position, step)
+        })
+        .map(|position| &self[position])
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl Grid<char> {
+    /// Grid of the characters of `input` as they are
+    pub fn parse_chars(input: &str) -> Result<Self, ParseError> {
+        Grid::parse(input, Ok)
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: (usize, usize)) -> &T {
+        let (width, height) = (self.width, self.height);
+        self.get(position).unwrap_or_else(|| {
+            panic!("position {position:?} is outside of the grid of {width}x{height}")
+        })
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, position: (usize, usize)) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(position).unwrap_or_else(|| {
+            panic!("position {position:?} is outside of the grid of {width}x{height}")
+        })
+    }
+}
+
+/// A point addresses the cell in row `y` and column `x`
+impl<T> Index<Point2<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point2<usize>) -> &T {
+        &self[(point.y, point.x)]
+    }
+}
+
+impl<T> IndexMut<Point2<usize>> for Grid<T> {
+    fn index_mut(&mut self, point: Point2<usize>) -> &mut T {
+        &mut self[(point.y, point.x)]
+    }
+}
+
+/// Rows of cells without separators, e.g. a `Grid<char>` renders as the map it was parsed from
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, row) in self.rows().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            for value in row {
+                value.fmt(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod grid_tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        Grid::parse_chars("abc\ndef").unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 0)], 'd');
+        assert_eq!(grid[Point2::new(0, 1)], 'd');
+        assert_eq!(grid.to_string(), "abc\ndef");
+
+        let error = Grid::parse("#.\n.x", |c| match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err(ParseError::expected("`#` or `.`", &c.to_string())),
+        })
+        .unwrap_err();
+        assert_eq!((error.line(), error.column()), (Some(2), Some(2)));
+
+        let error = Grid::parse_chars("ab\nabc").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2: expected a row of 2 columns, found `abc`"
+        );
+    }
+
+    #[test]
+    fn test_bounds() {
+        let mut grid = grid();
+        assert_eq!(grid.get((1, 2)), Some(&'f'));
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.get((0, 3)), None);
+        *grid.get_mut((0, 0)).unwrap() = 'x';
+        grid[(0, 1)] = 'y';
+        assert_eq!(grid.row(0), ['x', 'y', 'c']);
+        assert_eq!(grid.offset((0, 0), (-1, 0)), None);
+        assert_eq!(grid.offset((0, 0), (1, 2)), Some((1, 2)));
+        assert_eq!(grid.wrapping_offset((0, 0), (-1, -1)), (1, 2));
+        assert_eq!(grid.wrapping_offset((1, 2), (3, 4)), (0, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "outside of the grid")]
+    fn test_index_out_of_bounds() {
+        let _ = grid()[(2, 0)];
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = grid();
+        let values = |positions: Vec<(usize, usize)>| -> String {
+            positions
+                .into_iter()
+                .map(|position| grid[position])
+                .collect()
+        };
+        assert_eq!(values(grid.neighbors4((0, 0)).collect()), "bd");
+        assert_eq!(values(grid.neighbors4((1, 1)).collect()), "bfd");
+        assert_eq!(values(grid.neighbors8((0, 1)).collect()), "cfeda");
+        assert_eq!(values(grid.wrapping_neighbors4((0, 0)).collect()), "dbdc");
+        assert_eq!(grid.wrapping_neighbors8((1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn test_find() {
+        let grid = grid();
+        assert_eq!(grid.position_of(&'e'), Some((1, 1)));
+        assert_eq!(grid.position_of(&'z'), None);
+        assert_eq!(grid.find(|c| *c > 'b'), Some((0, 2)));
+        assert_eq!(grid.count(|c| "aeiou".contains(*c)), 2);
+    }
+
+    #[test]
+    fn test_lines() {
+        let grid = grid();
+        let collect = |lines: Vec<String>| lines.join(" ");
+        assert_eq!(
+            collect(grid.rows().map(|row| row.iter().collect()).collect()),
+            "abc def"
+        );
+        assert_eq!(
+            collect(grid.columns().map(|column| column.collect()).collect()),
+            "ad be cf"
+        );
+        assert_eq!(
+            collect(
+                grid.diagonals()
+                    .map(|diagonal| diagonal.collect())
+                    .collect()
+            ),
+            "d ae bf c"
+        );
+        assert_eq!(
+            collect(
+                grid.anti_diagonals()
+                    .map(|diagonal| diagonal.collect())
+                    .collect()
+            ),
+            "a bd ce f"
+        );
+        assert_eq!(grid.ray((1, 2), (0, -1)).collect::<String>(), "fed");
+    }
+
+    #[test]
+    fn test_map() {
+        let grid = Grid::from_rows(vec![vec![true, false], vec![false, true]]).unwrap();
+        assert_eq!(
+            grid.map(|&lit| if lit { '#' } else { '.' }).to_string(),
+            "#.\n.#"
+        );
+        assert!(Grid::from_rows(vec![vec![1], vec![1, 2]]).is_none());
+        assert_eq!(Grid::new(2, 1, 0).to_string(), "00");
+    }
+}
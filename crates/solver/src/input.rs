@@ -0,0 +1,127 @@
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::Year;
+
+/// Environment variable with the root directory of puzzle inputs
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Default name of the input file of a day, e.g. `day07.txt`
+pub fn input_file_name(day: usize) -> String {
+    format!("day{day:02}.txt")
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    path: PathBuf,
+    source: std::io::Error,
+}
+
+impl InputError {
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn kind(&self) -> std::io::ErrorKind {
+        self.source.kind()
+    }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "cannot read input file `{}`: {}",
+            self.path.display(),
+            self.source
+        )
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// Reads a puzzle input, the trailing line break of downloaded inputs is removed
+pub fn read_input(path: &Path) -> Result<String, InputError> {
+    let mut input = fs::read_to_string(path).map_err(|source| InputError {
+        path: path.to_owned(),
+        source,
+    })?;
+    let trimmed_len = input.trim_end_matches(['\r', '\n']).len();
+    input.truncate(trimmed_len);
+    Ok(input)
+}
+
+/// Resolves where input files of puzzles are read from
+#[derive(Debug, Clone, Default)]
+pub struct InputLocator {
+    root: Option<PathBuf>,
+}
+
+impl InputLocator {
+    /// Inputs are read from `<root>/<year>/`, or from the default directory of each year if there is no root
+    pub fn new(root: Option<PathBuf>) -> Self {
+        InputLocator { root }
+    }
+
+    /// Uses the root directory from the `AOC_INPUT_DIR` environment variable, if set
+    pub fn from_env() -> Self {
+        InputLocator::new(std::env::var_os(INPUT_DIR_VAR).map(PathBuf::from))
+    }
+
+    pub fn locate(&self, year: Year, default_dir: &Path, file_name: &str) -> PathBuf {
+        match &self.root {
+            Some(root) => root.join(year.to_string()).join(file_name),
+            None => default_dir.join(file_name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod input_tests {
+    use super::*;
+
+    #[test]
+    fn test_input_file_name() {
+        assert_eq!(input_file_name(7), "day07.txt");
+        assert_eq!(input_file_name(25), "day25.txt");
+    }
+
+    #[test]
+    fn test_locate_in_default_dir() {
+        let locator = InputLocator::new(None);
+        assert_eq!(
+            locator.locate(2017, Path::new("binaries/aoc_2017/input"), "day13.txt"),
+            Path::new("binaries/aoc_2017/input/day13.txt")
+        );
+    }
+
+    #[test]
+    fn test_locate_in_root_dir() {
+        let locator = InputLocator::new(Some(PathBuf::from("/home/elf/inputs")));
+        assert_eq!(
+            locator.locate(2017, Path::new("binaries/aoc_2017/input"), "day13.txt"),
+            Path::new("/home/elf/inputs/2017/day13.txt")
+        );
+    }
+
+    #[test]
+    fn test_read_input_trims_trailing_line_break() {
+        let path = std::env::temp_dir().join(format!("solver_input_{}.txt", std::process::id()));
+        fs::write(&path, "1 2\n3 4\r\n").unwrap();
+        let input = read_input(&path);
+        fs::remove_file(&path).unwrap();
+        assert_eq!(input.unwrap(), "1 2\n3 4");
+    }
+
+    #[test]
+    fn test_read_missing_input() {
+        let error = read_input(Path::new("does/not/exist.txt")).unwrap_err();
+        assert_eq!(error.path(), Path::new("does/not/exist.txt"));
+        assert_eq!(error.kind(), std::io::ErrorKind::NotFound);
+    }
+}
@@ -0,0 +1,278 @@
+use std::collections::BTreeMap;
+use std::ops::{Add, RangeInclusive, Sub};
+
+/// Integer type which can bound the ranges of an `IntervalSet`
+pub trait IntervalBound: Copy + Ord + Add<Output = Self> + Sub<Output = Self> {
+    const ZERO: Self;
+    const ONE: Self;
+    const MIN: Self;
+    const MAX: Self;
+}
+
+macro_rules! impl_interval_bound {
+    ($($t:ty),*) => {
+        $(impl IntervalBound for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+            const MIN: Self = <$t>::MIN;
+            const MAX: Self = <$t>::MAX;
+        })*
+    };
+}
+
+impl_interval_bound!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+fn next<T: IntervalBound>(value: T) -> Option<T> {
+    (value < T::MAX).then(|| value + T::ONE)
+}
+
+fn previous<T: IntervalBound>(value: T) -> Option<T> {
+    (value > T::MIN).then(|| value - T::ONE)
+}
+
+/// Set of integers stored as disjoint inclusive ranges, which are merged when they overlap or touch
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct IntervalSet<T> {
+    ranges: BTreeMap<T, T>, // start -> end
+}
+
+impl<T: IntervalBound> IntervalSet<T> {
+    pub fn new() -> Self {
+        IntervalSet {
+            ranges: BTreeMap::new(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Number of disjoint ranges
+    pub fn range_count(&self) -> usize {
+        self.ranges.len()
+    }
+
+    /// Number of values in the set, which overflows when it covers the whole of `T`
+    pub fn len(&self) -> T {
+        self.ranges
+            .iter()
+            .fold(T::ZERO, |len, (&start, &end)| len + (end - start) + T::ONE)
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first_key_value().map(|(&start, _)| start)
+    }
+
+    pub fn max(&self) -> Option<T> {
+        self.ranges.last_key_value().map(|(_, &end)| end)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.ranges
+            .range(..=value)
+            .next_back()
+            .is_some_and(|(_, &end)| end >= value)
+    }
+
+    /// Adds every value of `range`, an empty range is ignored
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        // Absorb the ranges which overlap or touch, they start at most one after the end
+        let limit = next(end).unwrap_or(end);
+        let touching: Vec<(T, T)> = self
+            .ranges
+            .range(..=limit)
+            .rev()
+            .take_while(|&(_, &other_end)| next(other_end).is_none_or(|after| after >= start))
+            .map(|(&other_start, &other_end)| (other_start, other_end))
+            .collect();
+        for (other_start, other_end) in touching {
+            self.ranges.remove(&other_start);
+            start = start.min(other_start);
+            end = end.max(other_end);
+        }
+        self.ranges.insert(start, end);
+    }
+
+    /// Removes every value of `range`, splitting the ranges which are cut in the middle
+    pub fn remove(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        let overlapping: Vec<(T, T)> = self
+            .ranges
+            .range(..=end)
+            .rev()
+            .take_while(|&(_, &other_end)| other_end >= start)
+            .map(|(&other_start, &other_end)| (other_start, other_end))
+            .collect();
+        for (other_start, other_end) in overlapping {
+            self.ranges.remove(&other_start);
+            if other_start < start {
+                self.ranges.insert(other_start, start - T::ONE);
+            }
+            if other_end > end {
+                self.ranges.insert(end + T::ONE, other_end);
+            }
+        }
+    }
+
+    /// Disjoint ranges in increasing order
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges.iter().map(|(&start, &end)| start..=end)
+    }
+
+    /// Ranges of `within` which are not in the set, in increasing order
+    pub fn gaps(&self, within: RangeInclusive<T>) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        let (start, end) = within.into_inner();
+        let mut from = (start <= end).then_some(start);
+        self.iter()
+            .map(Some)
+            .chain([None])
+            .filter_map(move |range| {
+                let current = from?;
+                match range {
+                    Some(range) if *range.end() < current => None,
+                    Some(range) if *range.start() > end => {
+                        from = None;
+                        Some(current..=end)
+                    }
+                    Some(range) => {
+                        from = next(*range.end()).filter(|&after| after <= end);
+                        previous(*range.start())
+                            .filter(|&before| before >= current)
+                            .map(|before| current..=before)
+                    }
+                    None => {
+                        from = None;
+                        Some(current..=end)
+                    }
+                }
+            })
+    }
+
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut result = self.clone();
+        for range in other.iter() {
+            result.insert(range);
+        }
+        result
+    }
+
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut result = IntervalSet::new();
+        let (mut left, mut right) = (self.iter().peekable(), other.iter().peekable());
+        while let (Some(a), Some(b)) = (left.peek(), right.peek()) {
+            let start = *a.start().max(b.start());
+            let end = *a.end().min(b.end());
+            if start <= end {
+                result.ranges.insert(start, end);
+            }
+            // Move on from the range which ends first
+            if a.end() < b.end() {
+                left.next();
+            } else {
+                right.next();
+            }
+        }
+        result
+    }
+
+    pub fn difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut result = self.clone();
+        for range in other.iter() {
+            result.remove(range);
+        }
+        result
+    }
+}
+
+impl<T: IntervalBound> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut set = IntervalSet::new();
+        for range in iter {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod interval_set_tests {
+    use super::*;
+
+    #[test]
+    fn test_insert() {
+        let mut set: IntervalSet<u64> = [3..=5, 10..=14, 16..=20, 12..=18].into_iter().collect();
+        assert_eq!(set.iter().collect::<Vec<_>>(), [3..=5, 10..=20]);
+        assert_eq!(set.len(), 14);
+
+        // Touching ranges are merged as well
+        set.insert(6..=9);
+        let (from, to) = (30, 29);
+        set.insert(from..=to);
+        assert_eq!(set.iter().collect::<Vec<_>>(), [3..=20]);
+        set.insert(0..=1);
+        set.insert(u64::MAX - 1..=u64::MAX);
+        set.insert(2..=2);
+        assert_eq!(
+            set.iter().collect::<Vec<_>>(),
+            [0..=20, u64::MAX - 1..=u64::MAX]
+        );
+        assert_eq!((set.min(), set.max()), (Some(0), Some(u64::MAX)));
+        assert!(set.contains(0) && set.contains(20) && set.contains(u64::MAX));
+        assert!(!set.contains(21));
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut set: IntervalSet<i32> = [-10..=10, 20..=30].into_iter().collect();
+        set.remove(-2..=2);
+        set.remove(8..=22);
+        assert_eq!(set.iter().collect::<Vec<_>>(), [-10..=-3, 3..=7, 23..=30]);
+        set.remove(i32::MIN..=-3);
+        assert_eq!(set.range_count(), 2);
+        assert_eq!(set.len(), 13);
+    }
+
+    #[test]
+    fn test_gaps() {
+        let set: IntervalSet<u8> = [3..=5, 8..=9, 12..=20].into_iter().collect();
+        assert_eq!(
+            set.gaps(0..=15).collect::<Vec<_>>(),
+            [0..=2, 6..=7, 10..=11]
+        );
+        assert_eq!(
+            set.gaps(4..=25).collect::<Vec<_>>(),
+            [6..=7, 10..=11, 21..=25]
+        );
+        assert_eq!(set.gaps(13..=14).count(), 0);
+        assert_eq!(set.gaps(0..=255).count(), 4);
+        assert_eq!(IntervalSet::new().gaps(1..=2).collect::<Vec<_>>(), [1..=2]);
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a: IntervalSet<u32> = [1..=5, 10..=15].into_iter().collect();
+        let b: IntervalSet<u32> = [4..=11, 14..=20].into_iter().collect();
+        assert_eq!(a.union(&b).iter().collect::<Vec<_>>(), [1..=20]);
+        assert_eq!(
+            a.intersection(&b).iter().collect::<Vec<_>>(),
+            [4..=5, 10..=11, 14..=15]
+        );
+        assert_eq!(
+            a.difference(&b).iter().collect::<Vec<_>>(),
+            [1..=3, 12..=13]
+        );
+        assert_eq!(
+            b.difference(&a).iter().collect::<Vec<_>>(),
+            [6..=9, 16..=20]
+        );
+    }
+}
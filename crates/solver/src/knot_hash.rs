@@ -0,0 +1,133 @@
+use std::fmt::Write;
+
+use crate::parallel_map;
+
+/// Lengths appended to the input of the full knot hash
+pub const KNOT_HASH_SUFFIX: [usize; 5] = [17, 31, 73, 47, 23];
+
+/// Rounds of the full knot hash
+pub const KNOT_HASH_ROUNDS: usize = 64;
+
+/// Ties knots in the circular `list`: each length reverses that many elements from the current position, which then
+/// moves forward by the length plus a skip size growing after every length
+///
+/// The position and the skip size carry over from one round to the next.
+pub fn knot<I>(list: &mut [u8], lengths: I, rounds: usize)
+where
+    I: IntoIterator<Item = usize>,
+    I::IntoIter: Clone,
+{
+    let size = list.len();
+    let lengths = lengths.into_iter();
+    let (mut position, mut skip_size) = (0, 0);
+    for _ in 0..rounds {
+        for length in lengths.clone() {
+            assert!(length <= size, "length {length} is longer than the list");
+            for i in 0..length / 2 {
+                list.swap((position + i) % size, (position + length - 1 - i) % size);
+            }
+            position = (position + length + skip_size) % size;
+            skip_size += 1;
+        }
+    }
+}
+
+/// The list `0..size` after `rounds` rounds of knots, the size is at most 256
+pub fn sparse_hash(lengths: &[usize], size: usize, rounds: usize) -> Vec<u8> {
+    assert!(size <= 256, "the list holds bytes, {size} is too long");
+    let mut list: Vec<u8> = (0..size).map(|value| value as u8).collect();
+    knot(&mut list, lengths.iter().copied(), rounds);
+    list
+}
+
+/// XOR of every block of 16 numbers of the sparse hash
+pub fn dense_hash(sparse: &[u8]) -> Vec<u8> {
+    sparse
+        .chunks(16)
+        .map(|block| block.iter().fold(0, |result, value| result ^ value))
+        .collect()
+}
+
+/// Full knot hash of `input`: its bytes followed by `KNOT_HASH_SUFFIX` as lengths, 64 rounds over 256 numbers
+pub fn knot_hash(input: &[u8]) -> [u8; 16] {
+    let mut list = [0u8; 256];
+    for (i, value) in list.iter_mut().enumerate() {
+        *value = i as u8;
+    }
+    let lengths = input
+        .iter()
+        .map(|&byte| byte as usize)
+        .chain(KNOT_HASH_SUFFIX);
+    knot(&mut list, lengths, KNOT_HASH_ROUNDS);
+
+    let mut dense = [0; 16];
+    for (result, block) in dense.iter_mut().zip(list.chunks(16)) {
+        *result = block.iter().fold(0, |result, value| result ^ value);
+    }
+    dense
+}
+
+/// Full knot hash of `input` as 32 hexadecimal digits
+pub fn knot_hash_hex(input: &str) -> String {
+    to_hex(&knot_hash(input.as_bytes()))
+}
+
+/// Full knot hashes of many inputs, spread over the available threads
+pub fn knot_hashes<S: AsRef<[u8]> + Sync>(inputs: &[S]) -> Vec<[u8; 16]> {
+    parallel_map(inputs, |input| knot_hash(input.as_ref()))
+}
+
+/// Lowercase hexadecimal digits of the bytes
+pub fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::new(), |mut hex, byte| {
+        _ = write!(hex, "{byte:02x}");
+        hex
+    })
+}
+
+#[cfg(test)]
+mod knot_hash_tests {
+    use super::*;
+
+    #[test]
+    fn test_sparse_hash() {
+        assert_eq!(sparse_hash(&[3, 4, 1, 5], 5, 1), [3, 4, 2, 1, 0]);
+        let mut list = [0, 1, 2, 3, 4];
+        knot(&mut list, [3, 4, 1, 5], 1);
+        assert_eq!(list, [3, 4, 2, 1, 0]);
+    }
+
+    #[test]
+    fn test_dense_hash() {
+        let mut sparse = vec![0; 32];
+        sparse[..16].copy_from_slice(&[65, 27, 9, 1, 4, 3, 40, 50, 91, 7, 6, 0, 2, 5, 68, 22]);
+        assert_eq!(dense_hash(&sparse), [64, 0]);
+        assert_eq!(to_hex(&[64, 7, 255]), "4007ff");
+    }
+
+    #[test]
+    fn test_knot_hash() {
+        for (input, hash) in [
+            ("", "a2582a3a0e66e6e86e3812dcb672a272"),
+            ("AoC 2017", "33efeb34ea91902bb2f59c9920caa6cd"),
+            ("1,2,3", "3efbe78a8d82f29979031a4aa0b16a9d"),
+            ("1,2,4", "63960835bcdc130f0b66d7ff4f6a5a8e"),
+        ] {
+            assert_eq!(knot_hash_hex(input), hash);
+        }
+
+        let lengths: Vec<usize> = "AoC 2017"
+            .bytes()
+            .map(|byte| byte as usize)
+            .chain(KNOT_HASH_SUFFIX)
+            .collect();
+        let sparse = sparse_hash(&lengths, 256, KNOT_HASH_ROUNDS);
+        assert_eq!(dense_hash(&sparse), knot_hash(b"AoC 2017"));
+
+        let keys = ["", "AoC 2017", "1,2,3"];
+        assert_eq!(
+            knot_hashes(&keys),
+            keys.map(|key| knot_hash(key.as_bytes()))
+        );
+    }
+}
@@ -1,14 +1,61 @@
+mod answer;
+mod answers;
 mod circular_linked_list;
+mod cycle;
+mod geometry;
+mod gf2;
+mod graph;
+mod grid;
+mod input;
+mod interval_set;
+mod knot_hash;
+mod linear_system;
+mod math;
+mod nonce_miner;
+mod ocr;
+mod parallel;
+mod parse;
+mod parse_error;
+mod registry;
+#[cfg(feature = "sat")]
+mod sat;
+mod search;
+mod trie;
 mod union_find;
+mod vm;
 
+pub use answer::*;
+pub use answers::*;
+pub use circular_linked_list::*;
+pub use cycle::*;
+pub use geometry::*;
+pub use gf2::*;
+pub use graph::*;
+pub use grid::*;
+pub use input::*;
+pub use interval_set::*;
+pub use knot_hash::*;
+pub use linear_system::*;
+pub use math::*;
+pub use nonce_miner::*;
+pub use ocr::*;
+pub use parallel::*;
+pub use parse::*;
+pub use parse_error::*;
+pub use registry::*;
+#[cfg(feature = "sat")]
+pub use sat::*;
+pub use search::*;
+pub use trie::*;
 pub use union_find::*;
+pub use vm::*;
 
 pub trait SolverBase {
-    fn solve_part_one(&self) -> String {
-        "".to_owned()
+    fn solve_part_one(&self) -> Answer {
+        Answer::Unsolved
     }
-    fn solve_part_two(&self) -> String {
-        "".to_owned()
+    fn solve_part_two(&self) -> Answer {
+        Answer::Unsolved
     }
     fn day_number(&self) -> usize;
     fn description(&self) -> &'static str {
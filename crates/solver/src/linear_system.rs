@@ -0,0 +1,247 @@
+use crate::gcd;
+
+/// Linear equations `coefficients * x = constants` whose solutions are searched among the integers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinearSystem {
+    rows: Vec<Vec<i64>>, // coefficients followed by the constant
+    variable_count: usize,
+}
+
+/// Linear system in reduced row echelon form, where every pivot variable depends on the free variables only
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReducedSystem {
+    rows: Vec<Vec<i64>>, // one row per pivot
+    pivots: Vec<usize>,
+    free: Vec<usize>,
+    variable_count: usize,
+}
+
+/// Divides the row by the gcd of its entries and makes its first non-zero entry positive
+fn normalize(row: &mut [i64]) {
+    let divisor = row.iter().fold(0, |g, &value| gcd(g, value.unsigned_abs())) as i64;
+    let sign = row
+        .iter()
+        .find(|&&value| value != 0)
+        .map_or(1, |value| value.signum());
+    if divisor > 1 || sign < 0 {
+        for value in row.iter_mut() {
+            *value /= divisor * sign;
+        }
+    }
+}
+
+impl LinearSystem {
+    /// One row of `coefficients` and one constant per equation, the rows must have the same length
+    pub fn new(coefficients: Vec<Vec<i64>>, constants: Vec<i64>) -> Self {
+        assert_eq!(
+            coefficients.len(),
+            constants.len(),
+            "expected one constant per equation"
+        );
+        let variable_count = coefficients.first().map_or(0, Vec::len);
+        let rows = coefficients
+            .into_iter()
+            .zip(constants)
+            .map(|(mut row, constant)| {
+                assert_eq!(
+                    row.len(),
+                    variable_count,
+                    "expected equations of the same length"
+                );
+                row.push(constant);
+                row
+            })
+            .collect();
+        LinearSystem {
+            rows,
+            variable_count,
+        }
+    }
+
+    pub fn variable_count(&self) -> usize {
+        self.variable_count
+    }
+
+    /// Fraction-free Gauss-Jordan elimination, `None` when the equations contradict each other
+    pub fn reduce(&self) -> Option<ReducedSystem> {
+        let mut rows = self.rows.clone();
+        let mut pivots = Vec::new();
+        let mut free = Vec::new();
+        for column in 0..self.variable_count {
+            let rank = pivots.len();
+            let Some(pivot_row) = (rank..rows.len()).find(|&i| rows[i][column] != 0) else {
+                free.push(column);
+                continue;
+            };
+            rows.swap(rank, pivot_row);
+            normalize(&mut rows[rank]);
+            let pivot = rows[rank].clone();
+            for (i, row) in rows.iter_mut().enumerate() {
+                let factor = row[column];
+                if i != rank && factor != 0 {
+                    // Cross-multiplying keeps every entry an integer
+                    for (value, &pivot_value) in row.iter_mut().zip(&pivot) {
+                        *value = *value * pivot[column] - pivot_value * factor;
+                    }
+                    normalize(row);
+                }
+            }
+            pivots.push(column);
+        }
+        // The remaining rows are all zeros, unless an equation says 0 = c
+        if rows[pivots.len()..]
+            .iter()
+            .any(|row| row[self.variable_count] != 0)
+        {
+            return None;
+        }
+        rows.truncate(pivots.len());
+        Some(ReducedSystem {
+            rows,
+            pivots,
+            free,
+            variable_count: self.variable_count,
+        })
+    }
+
+    /// Non-negative integer solution with the smallest sum, where each variable is at most its upper bound
+    pub fn min_sum_non_negative(&self, upper_bounds: &[i64]) -> Option<Vec<i64>> {
+        self.reduce()?.min_sum_non_negative(upper_bounds)
+    }
+}
+
+impl ReducedSystem {
+    /// Variables whose values can be chosen
+    pub fn free_variables(&self) -> &[usize] {
+        &self.free
+    }
+
+    /// Integer solution with the given values of the free variables, `None` when a pivot variable is not an integer
+    pub fn solve(&self, free_values: &[i64]) -> Option<Vec<i64>> {
+        assert_eq!(
+            free_values.len(),
+            self.free.len(),
+            "expected a value per free variable"
+        );
+        let mut solution = vec![0; self.variable_count];
+        for (&variable, &value) in self.free.iter().zip(free_values) {
+            solution[variable] = value;
+        }
+        for (row, &pivot) in self.rows.iter().zip(&self.pivots) {
+            let rest: i64 = self
+                .free
+                .iter()
+                .map(|&variable| row[variable] * solution[variable])
+                .sum();
+            let numerator = row[self.variable_count] - rest;
+            if numerator % row[pivot] != 0 {
+                return None;
+            }
+            solution[pivot] = numerator / row[pivot];
+        }
+        Some(solution)
+    }
+
+    /// Non-negative integer solution with the smallest sum, enumerating the free variables up to their upper bounds
+    pub fn min_sum_non_negative(&self, upper_bounds: &[i64]) -> Option<Vec<i64>> {
+        assert_eq!(
+            upper_bounds.len(),
+            self.variable_count,
+            "expected a bound per variable"
+        );
+        let mut best = None;
+        self.search(
+            &mut Vec::with_capacity(self.free.len()),
+            0,
+            upper_bounds,
+            &mut best,
+        );
+        best.map(|(_, solution)| solution)
+    }
+
+    fn search(
+        &self,
+        free_values: &mut Vec<i64>,
+        free_sum: i64,
+        upper_bounds: &[i64],
+        best: &mut Option<(i64, Vec<i64>)>,
+    ) {
+        // The pivot variables are non-negative, so the sum cannot get lower than the free variables alone
+        if best
+            .as_ref()
+            .is_some_and(|(best_sum, _)| free_sum >= *best_sum)
+        {
+            return;
+        }
+        if free_values.len() == self.free.len() {
+            if let Some(solution) = self.solve(free_values)
+                && solution
+                    .iter()
+                    .zip(upper_bounds)
+                    .all(|(&value, &bound)| (0..=bound).contains(&value))
+            {
+                let sum = solution.iter().sum();
+                if best.as_ref().is_none_or(|(best_sum, _)| sum < *best_sum) {
+                    *best = Some((sum, solution));
+                }
+            }
+            return;
+        }
+        for value in 0..=upper_bounds[self.free[free_values.len()]] {
+            free_values.push(value);
+            self.search(free_values, free_sum + value, upper_bounds, best);
+            free_values.pop();
+        }
+    }
+}
+
+#[cfg(test)]
+mod linear_system_tests {
+    use super::*;
+
+    #[test]
+    fn test_unique_solution() {
+        // 2x + y = 5, x - y = 1
+        let system = LinearSystem::new(vec![vec![2, 1], vec![1, -1]], vec![5, 1]);
+        let reduced = system.reduce().unwrap();
+        assert!(reduced.free_variables().is_empty());
+        assert_eq!(reduced.solve(&[]), Some(vec![2, 1]));
+
+        // 2x = 3 has no integer solution
+        let system = LinearSystem::new(vec![vec![2]], vec![3]);
+        assert_eq!(system.reduce().unwrap().solve(&[]), None);
+    }
+
+    #[test]
+    fn test_inconsistent() {
+        // x + y = 1, 2x + 2y = 3
+        let system = LinearSystem::new(vec![vec![1, 1], vec![2, 2]], vec![1, 3]);
+        assert_eq!(system.reduce(), None);
+        // Redundant equations are fine
+        let system = LinearSystem::new(vec![vec![1, 1], vec![2, 2]], vec![1, 2]);
+        assert_eq!(system.reduce().unwrap().free_variables(), [1]);
+    }
+
+    #[test]
+    fn test_min_sum_non_negative() {
+        // The first machine of 2025 day 10: the buttons are the columns, the counters the rows
+        let system = LinearSystem::new(
+            vec![
+                vec![0, 0, 0, 0, 1, 1],
+                vec![0, 1, 0, 0, 0, 1],
+                vec![0, 0, 1, 1, 1, 0],
+                vec![1, 1, 0, 1, 0, 0],
+            ],
+            vec![3, 5, 4, 7],
+        );
+        let solution = system.min_sum_non_negative(&[7, 5, 4, 4, 3, 3]).unwrap();
+        assert_eq!(solution.iter().sum::<i64>(), 10);
+
+        // x + y = 3 with x <= 1 and y <= 1
+        let system = LinearSystem::new(vec![vec![1, 1]], vec![3]);
+        assert_eq!(system.min_sum_non_negative(&[1, 1]), None);
+        // x - y = 2, the smallest sum has y = 0
+        let system = LinearSystem::new(vec![vec![1, -1]], vec![2]);
+        assert_eq!(system.min_sum_non_negative(&[10, 10]), Some(vec![2, 0]));
+    }
+}
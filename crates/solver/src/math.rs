@@ -0,0 +1,203 @@
+/// Greatest common divisor, `gcd(0, 0)` is 0
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Least common multiple, `lcm(0, x)` is 0
+pub fn lcm(a: u64, b: u64) -> u64 {
+    if a == 0 || b == 0 {
+        0
+    } else {
+        a / gcd(a, b) * b
+    }
+}
+
+/// Greatest common divisor of all the values, 0 for none
+pub fn gcd_all(values: &[u64]) -> u64 {
+    values.iter().fold(0, |result, &value| gcd(result, value))
+}
+
+/// Least common multiple of all the values, 1 for none
+pub fn lcm_all(values: &[u64]) -> u64 {
+    values.iter().fold(1, |result, &value| lcm(result, value))
+}
+
+/// `(g, x, y)` such that `a * x + b * y = g`, where `g` is the non-negative gcd of `a` and `b`
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// `x` in `0..modulus` such that `a * x = 1 (mod modulus)`, `None` when `a` and `modulus` are not coprime or the
+/// modulus is not positive
+pub fn mod_inverse(a: i64, modulus: i64) -> Option<i64> {
+    if modulus <= 0 {
+        return None;
+    }
+    let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus);
+    (g == 1).then(|| x.rem_euclid(modulus))
+}
+
+/// `base` to the power `exponent`, modulo `modulus`
+pub fn mod_pow(base: u64, mut exponent: u64, modulus: u64) -> u64 {
+    let modulus = modulus as u128;
+    let mut base = base as u128 % modulus;
+    let mut result = 1 % modulus;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exponent >>= 1;
+    }
+    result as u64
+}
+
+/// Solves `x = residue (mod modulus)` for every `(residue, modulus)` with the Chinese remainder theorem
+///
+/// The moduli do not need to be coprime and a negative one stands for its absolute value. Returns `(x, lcm)` with `x`
+/// in `0..lcm`, or `None` when the congruences contradict each other, a modulus is 0 or the lcm does not fit an `i64`.
+pub fn crt(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+    let (mut x, mut modulus) = (0i128, 1i128);
+    for &(residue, other_modulus) in congruences {
+        // `x = r (mod -m)` is `x = r (mod m)`, keeping the lcm positive
+        let other_modulus = other_modulus.checked_abs()?;
+        if other_modulus == 0 {
+            return None;
+        }
+        // x + modulus * k = residue (mod other_modulus)
+        let (g, p, _) = extended_gcd(i64::try_from(modulus).ok()?, other_modulus);
+        let (residue, other_modulus) = (residue as i128, other_modulus as i128);
+        let g = g as i128;
+        let difference = residue - x;
+        if difference % g != 0 {
+            return None;
+        }
+        let step = other_modulus / g;
+        let k = (difference / g % step * p as i128).rem_euclid(step);
+        x += modulus * k;
+        modulus *= step;
+        x = x.rem_euclid(modulus);
+    }
+    Some((i64::try_from(x).ok()?, i64::try_from(modulus).ok()?))
+}
+
+/// Smallest `t` such that `t % modulus != residue` for every `(residue, modulus)`, with a sieve over windows of `t`
+///
+/// The moduli must be positive. Returns `None` when every `t` is excluded, which is known once the lcm of the moduli
+/// has been sieved.
+pub fn smallest_avoiding(excluded: &[(u64, u64)]) -> Option<u64> {
+    const WINDOW: u64 = 1 << 16;
+    let period = excluded
+        .iter()
+        .try_fold(1u64, |period, &(_, modulus)| {
+            period.checked_mul(modulus / gcd(period, modulus))
+        })
+        .unwrap_or(u64::MAX);
+    let mut sieve = vec![false; WINDOW as usize];
+    let mut start = 0;
+    while start < period {
+        sieve.fill(false);
+        for &(residue, modulus) in excluded {
+            // First excluded value of the window
+            let mut t = start + (residue % modulus + modulus - start % modulus) % modulus;
+            while t < start + WINDOW {
+                sieve[(t - start) as usize] = true;
+                t += modulus;
+            }
+        }
+        if let Some(offset) = sieve.iter().position(|&excluded| !excluded) {
+            let t = start + offset as u64;
+            return (t < period).then_some(t);
+        }
+        start += WINDOW;
+    }
+    None
+}
+
+#[cfg(test)]
+mod math_tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(0, 7), 7);
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(0, 6), 0);
+        assert_eq!(gcd_all(&[12, 18, 27]), 3);
+        assert_eq!(lcm_all(&[2, 3, 4, 5]), 60);
+        assert_eq!(lcm_all(&[]), 1);
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        for (a, b) in [(240, 46), (46, 240), (-15, 35), (7, 0), (0, -4)] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(g, gcd(a.unsigned_abs(), b.unsigned_abs()) as i64);
+            assert_eq!(a * x + b * y, g);
+        }
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(6, 9), None);
+        assert_eq!(mod_inverse(3, 0), None);
+        assert_eq!(mod_inverse(3, -11), None);
+    }
+
+    #[test]
+    fn test_mod_pow() {
+        assert_eq!(mod_pow(4, 13, 497), 445);
+        assert_eq!(mod_pow(16807, 0, 2147483647), 1);
+        assert_eq!(mod_pow(2, 100, 1), 0);
+        assert_eq!(mod_pow(u64::MAX, 2, u64::MAX - 1), 1);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // Moduli which are not coprime
+        assert_eq!(crt(&[(2, 4), (4, 6)]), Some((10, 12)));
+        assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+        let (x, modulus) = crt(&[(-1, 101), (-2, 103)]).unwrap();
+        assert_eq!((x % 101, x % 103, modulus), (100, 101, 10403));
+        assert_eq!(crt(&[]), Some((0, 1)));
+        assert_eq!(crt(&[(1, 3), (0, 0)]), None);
+        assert_eq!(crt(&[(2, -3), (3, 5)]), Some((8, 15)));
+        assert_eq!(crt(&[(1, i64::MIN)]), None);
+        // The lcm of two large primes is beyond `i64`
+        assert_eq!(crt(&[(1, 4_294_967_311), (2, 4_294_967_357)]), None);
+    }
+
+    #[test]
+    fn test_smallest_avoiding() {
+        // The firewall of 2017 day 13: a layer at depth d with a period p catches the packets where (t + d) % p == 0
+        assert_eq!(
+            smallest_avoiding(&[(0, 4), (1, 2), (2, 6), (0, 6)]),
+            Some(10)
+        );
+        assert_eq!(smallest_avoiding(&[]), Some(0));
+        assert_eq!(smallest_avoiding(&[(0, 2), (1, 2)]), None);
+        assert_eq!(smallest_avoiding(&[(0, 1)]), None);
+        // The answer is beyond the first window
+        let excluded: Vec<_> = (0..70_001)
+            .filter(|&residue| residue != 70_000)
+            .map(|residue| (residue, 70_001))
+            .collect();
+        assert_eq!(smallest_avoiding(&excluded), Some(70_000));
+    }
+}
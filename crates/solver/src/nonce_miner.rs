@@ -0,0 +1,186 @@
+use std::collections::VecDeque;
+
+use crate::{parallel_map, thread_count};
+
+/// MD5 digest of a key followed by a nonce
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NonceMatch {
+    pub nonce: u64,
+    pub digest: [u8; 16],
+}
+
+impl NonceMatch {
+    /// Hexadecimal digit `index` of the digest, as a number
+    pub fn nibble(&self, index: usize) -> u8 {
+        nibble(&self.digest, index)
+    }
+
+    /// Hexadecimal digit `index` of the digest, as a lowercase character
+    pub fn hex_digit(&self, index: usize) -> char {
+        char::from_digit(self.nibble(index) as u32, 16).unwrap()
+    }
+}
+
+/// Hexadecimal digit `index` of the digest, the first one is the high half of the first byte
+pub fn nibble(digest: &[u8; 16], index: usize) -> u8 {
+    let byte = digest[index / 2];
+    if index.is_multiple_of(2) {
+        byte >> 4
+    } else {
+        byte & 0xf
+    }
+}
+
+/// Number of zeros at the start of the hexadecimal digest
+pub fn leading_zero_nibbles(digest: &[u8; 16]) -> u32 {
+    let zero_bits = digest
+        .iter()
+        .position(|&byte| byte != 0)
+        .map_or(128, |i| i as u32 * 8 + digest[i].leading_zeros());
+    zero_bits / 4
+}
+
+/// Searches the nonces `0, 1, 2...` for the MD5 digests of `key` followed by the nonce in decimal which satisfy a
+/// condition
+///
+/// The nonces are mined in batches of one chunk per thread, so the matches come in nonce order and the search stops
+/// within a batch of the last match that is asked for.
+#[derive(Debug, Clone)]
+pub struct NonceMiner {
+    key: Vec<u8>,
+    start: u64,
+    chunk_size: u64,
+}
+
+impl NonceMiner {
+    pub fn new(key: &str) -> Self {
+        NonceMiner {
+            key: key.as_bytes().to_vec(),
+            start: 0,
+            chunk_size: 1 << 14,
+        }
+    }
+
+    /// First nonce to try
+    pub fn starting_at(mut self, nonce: u64) -> Self {
+        self.start = nonce;
+        self
+    }
+
+    /// Nonces mined by a thread at once
+    pub fn chunk_size(mut self, chunk_size: u64) -> Self {
+        assert!(chunk_size > 0, "expected a positive chunk size");
+        self.chunk_size = chunk_size;
+        self
+    }
+
+    /// Matches of `accept` in increasing nonce order, the mining goes on as long as the iterator is consumed
+    pub fn matches<'a>(
+        &'a self,
+        accept: impl Fn(&[u8; 16]) -> bool + Sync + 'a,
+    ) -> impl Iterator<Item = NonceMatch> + 'a {
+        let mut context = md5::Context::new();
+        context.consume(&self.key);
+        let batch_size = self.chunk_size * thread_count() as u64;
+        let mut next = Some(self.start);
+        let mut found = VecDeque::new();
+        std::iter::from_fn(move || {
+            while found.is_empty() {
+                let start = next?;
+                let chunks: Vec<(u64, u64)> = (0..thread_count() as u64)
+                    .map_while(|i| {
+                        let first = start.checked_add(i * self.chunk_size)?;
+                        Some((first, first.saturating_add(self.chunk_size)))
+                    })
+                    .collect();
+                next = start.checked_add(batch_size);
+                let matches =
+                    parallel_map(&chunks, |&(first, end)| mine(&context, first..end, &accept));
+                found.extend(matches.into_iter().flatten());
+            }
+            found.pop_front()
+        })
+    }
+
+    /// Match of `accept` with the smallest nonce
+    pub fn first(&self, accept: impl Fn(&[u8; 16]) -> bool + Sync) -> Option<NonceMatch> {
+        self.matches(accept).next()
+    }
+
+    /// Smallest nonce whose hexadecimal digest starts with `zeros` zeros
+    pub fn first_with_leading_zeros(&self, zeros: u32) -> Option<u64> {
+        self.first(|digest| leading_zero_nibbles(digest) >= zeros)
+            .map(|found| found.nonce)
+    }
+}
+
+// Hashes a range of nonces, `context` has already consumed the key
+fn mine(
+    context: &md5::Context,
+    nonces: std::ops::Range<u64>,
+    accept: &impl Fn(&[u8; 16]) -> bool,
+) -> Vec<NonceMatch> {
+    let mut digits = [0u8; 20];
+    nonces
+        .filter_map(|nonce| {
+            let mut context = context.clone();
+            context.consume(decimal(nonce, &mut digits));
+            let digest = context.compute().0;
+            accept(&digest).then_some(NonceMatch { nonce, digest })
+        })
+        .collect()
+}
+
+// Decimal digits of `value`, written at the end of `buffer`
+fn decimal(mut value: u64, buffer: &mut [u8; 20]) -> &[u8] {
+    let mut start = buffer.len();
+    loop {
+        start -= 1;
+        buffer[start] = b'0' + (value % 10) as u8;
+        value /= 10;
+        if value == 0 {
+            return &buffer[start..];
+        }
+    }
+}
+
+#[cfg(test)]
+mod nonce_miner_tests {
+    use super::*;
+
+    #[test]
+    fn test_digits() {
+        let digest = md5::compute("abcdef609043").0;
+        assert_eq!(leading_zero_nibbles(&digest), 5);
+        assert_eq!(leading_zero_nibbles(&[0; 16]), 32);
+        assert_eq!(nibble(&digest, 5), 1);
+        let mut buffer = [0; 20];
+        assert_eq!(decimal(0, &mut buffer), b"0");
+        assert_eq!(decimal(u64::MAX, &mut buffer), b"18446744073709551615");
+    }
+
+    #[test]
+    fn test_matches() {
+        // Small chunks, so that the matches cross several batches
+        let miner = NonceMiner::new("abc").chunk_size(100);
+        let expected: Vec<u64> = (0..5000)
+            .filter(|nonce| md5::compute(format!("abc{nonce}")).0[0] == 0)
+            .collect();
+        let found: Vec<u64> = miner
+            .matches(|digest| digest[0] == 0)
+            .take_while(|found| found.nonce < 5000)
+            .map(|found| found.nonce)
+            .collect();
+        assert_eq!(found, expected);
+
+        let found = miner
+            .starting_at(expected[0] + 1)
+            .first(|digest| digest[0] == 0)
+            .unwrap();
+        assert_eq!(found.nonce, expected[1]);
+        assert_eq!(
+            NonceMiner::new("abcdef").first_with_leading_zeros(5),
+            Some(609043)
+        );
+    }
+}
@@ -0,0 +1,89 @@
+/// Height of the letters drawn by the puzzles
+pub const LETTER_HEIGHT: usize = 6;
+
+/// Columns taken by a letter, a blank column between letters included
+pub const LETTER_WIDTH: usize = 5;
+
+const FONT: [(char, [&str; LETTER_HEIGHT]); 18] = [
+    ('A', [".##..", "#..#.", "#..#.", "####.", "#..#.", "#..#."]),
+    ('B', ["###..", "#..#.", "###..", "#..#.", "#..#.", "###.."]),
+    ('C', [".##..", "#..#.", "#....", "#....", "#..#.", ".##.."]),
+    ('E', ["####.", "#....", "###..", "#....", "#....", "####."]),
+    ('F', ["####.", "#....", "###..", "#....", "#....", "#...."]),
+    ('G', [".##..", "#..#.", "#....", "#.##.", "#..#.", ".###."]),
+    ('H', ["#..#.", "#..#.", "####.", "#..#.", "#..#.", "#..#."]),
+    ('I', [".###.", "..#..", "..#..", "..#..", "..#..", ".###."]),
+    ('J', ["..##.", "...#.", "...#.", "...#.", "#..#.", ".##.."]),
+    ('K', ["#..#.", "#.#..", "##...", "#.#..", "#.#..", "#..#."]),
+    ('L', ["#....", "#....", "#....", "#....", "#....", "####."]),
+    ('O', [".##..", "#..#.", "#..#.", "#..#.", "#..#.", ".##.."]),
+    ('P', ["###..", "#..#.", "#..#.", "###..", "#....", "#...."]),
+    ('R', ["###..", "#..#.", "#..#.", "###..", "#.#..", "#..#."]),
+    ('S', [".###.", "#....", "#....", ".##..", "...#.", "###.."]),
+    ('U', ["#..#.", "#..#.", "#..#.", "#..#.", "#..#.", ".##.."]),
+    ('Y', ["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', ["####.", "...#.", "..#..", ".#...", "#....", "####."]),
+];
+
+/// Text spelled by lit pixels in the letter font of the puzzles, `None` when a letter is not recognized
+///
+/// The letters start every `LETTER_WIDTH` columns, a missing last blank column is fine.
+pub fn read_letters<R: AsRef<[bool]>>(rows: &[R]) -> Option<String> {
+    if rows.len() != LETTER_HEIGHT {
+        return None;
+    }
+    let width = rows.iter().map(|row| row.as_ref().len()).max()?;
+    (0..width.div_ceil(LETTER_WIDTH))
+        .map(|letter| {
+            let lit = |row: &R, column: usize| {
+                let column = letter * LETTER_WIDTH + column;
+                row.as_ref().get(column).copied().unwrap_or(false)
+            };
+            FONT.iter()
+                .find(|(_, glyph)| {
+                    rows.iter().zip(glyph).all(|(row, glyph_row)| {
+                        glyph_row
+                            .bytes()
+                            .enumerate()
+                            .all(|(column, pixel)| lit(row, column) == (pixel == b'#'))
+                    })
+                })
+                .map(|&(c, _)| c)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod ocr_tests {
+    use super::*;
+
+    fn pixels(picture: &str) -> Vec<Vec<bool>> {
+        picture
+            .lines()
+            .map(|line| line.chars().map(|c| c == '#').collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_read_letters() {
+        let screen = pixels(
+            "\
+.##..###..#..#.#...#
+#..#.#..#.#..#.#...#
+#..#.#..#.####..#.#.
+####.###..#..#...#..
+#..#.#.#..#..#...#..
+#..#.#..#.#..#...#..",
+        );
+        assert_eq!(read_letters(&screen).as_deref(), Some("ARHY"));
+
+        // The blank column after the last letter may be cut off
+        let trimmed: Vec<_> = screen.iter().map(|row| &row[..9]).collect();
+        assert_eq!(read_letters(&trimmed).as_deref(), Some("AR"));
+
+        assert_eq!(read_letters(&pixels(".#..#.#\n#.#....\n.#.....")), None);
+        let mut unknown = screen.clone();
+        unknown[0][0] = true;
+        assert_eq!(read_letters(&unknown), None);
+    }
+}
@@ -0,0 +1,45 @@
+use std::num::NonZeroUsize;
+use std::thread;
+
+/// Number of threads worth starting, one per available core
+pub fn thread_count() -> usize {
+    thread::available_parallelism().map_or(1, NonZeroUsize::get)
+}
+
+/// `items.iter().map(f)` with the items split into one chunk per thread, the results keep the order of the items
+pub fn parallel_map<T, R>(items: &[T], f: impl Fn(&T) -> R + Sync) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+{
+    if items.is_empty() {
+        return Vec::new();
+    }
+    let chunk_size = items.len().div_ceil(thread_count());
+    let f = &f;
+    thread::scope(|scope| {
+        let handles: Vec<_> = items
+            .chunks(chunk_size)
+            .map(|chunk| scope.spawn(move || chunk.iter().map(f).collect::<Vec<_>>()))
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().expect("a worker thread panicked"))
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod parallel_tests {
+    use super::*;
+
+    #[test]
+    fn test_parallel_map() {
+        let items: Vec<u64> = (0..1000).collect();
+        assert_eq!(
+            parallel_map(&items, |&x| x * x),
+            items.iter().map(|&x| x * x).collect::<Vec<_>>()
+        );
+        assert!(parallel_map(&[] as &[u8], |&x| x).is_empty());
+    }
+}
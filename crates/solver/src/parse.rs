@@ -0,0 +1,254 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{Grid, ParseError};
+
+/// Parses each line with `f`, an error is located on the line it comes from
+pub fn parse_lines<'a, T>(
+    input: &'a str,
+    mut f: impl FnMut(&'a str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| f(line).map_err(|error| error.on_line(index + 1, line)))
+        .collect()
+}
+
+/// Parses a single field of a line, e.g. a number
+pub fn parse_field<T>(field: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    field
+        .parse()
+        .map_err(|error| ParseError::new(format!("invalid field, {error}")).with_snippet(field))
+}
+
+/// Splits `input` at the blank line between two sections, also returns the line number of the second one
+pub fn split_sections(input: &str) -> Result<(&str, &str, usize), ParseError> {
+    let (first, second) = input
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::new("expected two sections separated by a blank line"))?;
+    Ok((first, second, first.lines().count() + 2))
+}
+
+/// Splits `input` at every blank line, each section comes with the number of its first line
+pub fn sections(input: &str) -> Vec<(&str, usize)> {
+    let mut line = 1;
+    input
+        .trim_end_matches('\n')
+        .split("\n\n")
+        .map(|section| {
+            let first_line = line;
+            line += section.lines().count() + 1;
+            (section, first_line)
+        })
+        .collect()
+}
+
+/// Every integer in `text`, where a `-` or `+` right before the digits is a sign unless it follows a digit
+///
+/// The separators do not matter, e.g. `p=0,4 v=3,-3` holds `0`, `4`, `3` and `-3` but `1-3` holds `1` and `3`.
+pub fn integers<T>(text: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    let bytes = text.as_bytes();
+    let mut result = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+        let mut start = i;
+        if start > 0
+            && matches!(bytes[start - 1], b'-' | b'+')
+            && (start < 2 || !bytes[start - 2].is_ascii_digit())
+        {
+            start -= 1;
+        }
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        result.push(parse_field(&text[start..i]).map_err(|error| error.at_column(start + 1))?);
+    }
+    Ok(result)
+}
+
+/// Exactly `N` integers of `text`, see `integers`
+pub fn integer_array<T, const N: usize>(text: &str) -> Result<[T; N], ParseError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    let integers = integers(text)?;
+    let count = integers.len();
+    integers.try_into().map_err(|_| {
+        ParseError::new(format!("expected {N} numbers, found {count}")).with_snippet(text)
+    })
+}
+
+/// Splits `text` around `separator` and trims both sides
+pub fn split_pair<'a>(text: &'a str, separator: &str) -> Result<(&'a str, &'a str), ParseError> {
+    let (first, second) = text
+        .split_once(separator)
+        .ok_or_else(|| ParseError::expected(&format!("`<a>{separator}<b>`"), text))?;
+    Ok((first.trim(), second.trim()))
+}
+
+/// Splits a `key: value` line
+pub fn parse_key_value(line: &str) -> Result<(&str, &str), ParseError> {
+    split_pair(line, ":")
+}
+
+/// Splits an `a -> b` line
+pub fn parse_rule(line: &str) -> Result<(&str, &str), ParseError> {
+    split_pair(line, "->")
+}
+
+/// Grid of cells given by `mapping`, an unknown character is an error listing the expected ones
+pub fn parse_char_map<T: Clone>(input: &str, mapping: &[(char, T)]) -> Result<Grid<T>, ParseError> {
+    Grid::parse(input, |c| {
+        mapping
+            .iter()
+            .find(|(symbol, _)| *symbol == c)
+            .map(|(_, cell)| cell.clone())
+            .ok_or_else(|| {
+                let symbols: Vec<_> = mapping
+                    .iter()
+                    .map(|(symbol, _)| format!("`{symbol}`"))
+                    .collect();
+                ParseError::expected(&format!("one of {}", symbols.join(", ")), &c.to_string())
+            })
+    })
+}
+
+/// Cuts `line` into fields of the given widths in characters, the last field can be shorter or even empty
+pub fn fixed_width_fields<'a>(line: &'a str, widths: &[usize]) -> Result<Vec<&'a str>, ParseError> {
+    let mut fields = Vec::with_capacity(widths.len());
+    let mut rest = line;
+    let mut column = 1;
+    for (i, &width) in widths.iter().enumerate() {
+        let end = rest
+            .char_indices()
+            .nth(width)
+            .map_or(rest.len(), |(end, _)| end);
+        let field = &rest[..end];
+        if i + 1 < widths.len() && field.chars().count() < width {
+            return Err(ParseError::new(format!("expected {} fields", widths.len()))
+                .with_snippet(field)
+                .at_column(column));
+        }
+        fields.push(field);
+        rest = &rest[end..];
+        column += width;
+    }
+    Ok(fields)
+}
+
+#[cfg(test)]
+mod parse_tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_lines() {
+        assert_eq!(parse_lines("1\n2\n3", parse_field::<u8>), Ok(vec![1, 2, 3]));
+
+        let error = parse_lines("1\n2 3\n4", |line| match line.contains(' ') {
+            true => Err(ParseError::new("expected a single number")),
+            false => parse_field::<u8>(line),
+        })
+        .unwrap_err();
+        assert_eq!(error.line(), Some(2));
+        assert_eq!(error.snippet(), "2 3");
+        assert_eq!(error.column(), None);
+
+        let error = parse_lines("1\n2 x", |line| {
+            line.split(' ')
+                .map(parse_field::<u8>)
+                .sum::<Result<u8, _>>()
+        })
+        .unwrap_err();
+        assert_eq!(error.line(), Some(2));
+        assert_eq!(error.column(), Some(3));
+        assert_eq!(error.snippet(), "x");
+    }
+
+    #[test]
+    fn test_split_sections() {
+        let (first, second, line) = split_sections("a\nb\n\nc\nd").unwrap();
+        assert_eq!((first, second, line), ("a\nb", "c\nd", 4));
+        assert!(split_sections("a\nb").is_err());
+
+        let error = parse_lines(second, parse_field::<u8>)
+            .unwrap_err()
+            .below(line - 1);
+        assert_eq!(error.line(), Some(4));
+    }
+
+    #[test]
+    fn test_sections() {
+        assert_eq!(
+            sections("a\nb\n\nc\n\nd\ne\nf\n"),
+            [("a\nb", 1), ("c", 4), ("d\ne\nf", 6)]
+        );
+        assert_eq!(sections("a"), [("a", 1)]);
+    }
+
+    #[test]
+    fn test_integers() {
+        assert_eq!(integers::<i32>("p=0,4 v=3,-3"), Ok(vec![0, 4, 3, -3]));
+        assert_eq!(integers::<u32>("1-3 a: 25"), Ok(vec![1, 3, 25]));
+        assert_eq!(integers::<i64>("X+94, Y=-34"), Ok(vec![94, -34]));
+        assert_eq!(integers::<u8>("no numbers"), Ok(vec![]));
+
+        let error = integers::<u8>("1, 300").unwrap_err();
+        assert_eq!((error.snippet(), error.column()), ("300", Some(4)));
+        assert!(integers::<u8>("-1").is_err());
+
+        assert_eq!(integer_array::<i32, 2>("x=1, y=-2"), Ok([1, -2]));
+        let error = integer_array::<i32, 3>("x=1, y=-2").unwrap_err();
+        assert_eq!(error.message(), "expected 3 numbers, found 2");
+    }
+
+    #[test]
+    fn test_pairs() {
+        assert_eq!(
+            parse_key_value("Monkey 0: 79, 98"),
+            Ok(("Monkey 0", "79, 98"))
+        );
+        assert_eq!(parse_rule("x AND y -> z"), Ok(("x AND y", "z")));
+        let error = parse_rule("x AND y").unwrap_err();
+        assert_eq!(error.to_string(), "expected `<a>-><b>`, found `x AND y`");
+    }
+
+    #[test]
+    fn test_parse_char_map() {
+        let grid = parse_char_map("#.\n.#", &[('#', 1), ('.', 0)]).unwrap();
+        assert_eq!(
+            grid.iter().map(|(_, &cell)| cell).collect::<Vec<_>>(),
+            [1, 0, 0, 1]
+        );
+
+        let error = parse_char_map("#.\n.x", &[('#', 1), ('.', 0)]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 2: expected one of `#`, `.`, found `x`"
+        );
+    }
+
+    #[test]
+    fn test_fixed_width_fields() {
+        assert_eq!(
+            fixed_width_fields("123 328  51", &[4, 4, 4]),
+            Ok(vec!["123 ", "328 ", " 51"])
+        );
+        assert_eq!(fixed_width_fields("ab", &[2, 0]), Ok(vec!["ab", ""]));
+        let error = fixed_width_fields("123 32", &[4, 4, 4]).unwrap_err();
+        assert_eq!((error.snippet(), error.column()), ("32", Some(5)));
+    }
+}
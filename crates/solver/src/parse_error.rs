@@ -0,0 +1,130 @@
+use std::fmt;
+
+/// Malformed puzzle input, located by day, line and column when they are known
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    day: Option<usize>,
+    line: Option<usize>,
+    column: Option<usize>,
+    snippet: String,
+    message: String,
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>) -> Self {
+        ParseError {
+            day: None,
+            line: None,
+            column: None,
+            snippet: String::new(),
+            message: message.into(),
+        }
+    }
+
+    /// Error about the offending part of the input, e.g. `expected("a number", "x1")`
+    pub fn expected(what: &str, found: &str) -> Self {
+        ParseError::new(format!("expected {what}")).with_snippet(found)
+    }
+
+    pub fn with_snippet(mut self, snippet: &str) -> Self {
+        self.snippet = snippet.to_owned();
+        self
+    }
+
+    /// Column numbers start at 1
+    pub fn at_column(mut self, column: usize) -> Self {
+        self.column = Some(column);
+        self
+    }
+
+    /// Line numbers start at 1, the column is where the snippet appears in the line unless it is already known
+    pub fn on_line(mut self, number: usize, line: &str) -> Self {
+        self.line = Some(number);
+        if self.snippet.is_empty() {
+            self.snippet = line.to_owned();
+        } else if self.column.is_none() {
+            self.column = line.find(&self.snippet).map(|offset| offset + 1);
+        }
+        self
+    }
+
+    /// Moves the error down by `lines`, for inputs made of sections parsed one by one
+    pub fn below(mut self, lines: usize) -> Self {
+        self.line = self.line.map(|line| line + lines);
+        self
+    }
+
+    /// The day is usually filled in by the registry
+    pub fn for_day(mut self, day: usize) -> Self {
+        self.day = Some(day);
+        self
+    }
+
+    pub fn day(&self) -> Option<usize> {
+        self.day
+    }
+
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    pub fn column(&self) -> Option<usize> {
+        self.column
+    }
+
+    pub fn snippet(&self) -> &str {
+        &self.snippet
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let location: Vec<_> = [
+            self.day.map(|day| format!("day {day}")),
+            self.line.map(|line| format!("line {line}")),
+            self.column.map(|column| format!("column {column}")),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        if !location.is_empty() {
+            write!(f, "{}: ", location.join(", "))?;
+        }
+        write!(f, "{}", self.message)?;
+        if !self.snippet.is_empty() {
+            write!(f, ", found `{}`", self.snippet)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod parse_error_tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let error = ParseError::expected("`{...}` joltage block", "(3)")
+            .on_line(47, "[.##.] (3)")
+            .for_day(10);
+        assert_eq!(
+            error.to_string(),
+            "day 10, line 47, column 8: expected `{...}` joltage block, found `(3)`"
+        );
+        assert_eq!(ParseError::new("empty input").to_string(), "empty input");
+    }
+
+    #[test]
+    fn test_column_is_kept() {
+        let error = ParseError::expected("`#` or `.`", "x")
+            .at_column(5)
+            .on_line(1, "x..#x");
+        assert_eq!(error.column(), Some(5));
+    }
+}
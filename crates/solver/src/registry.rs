@@ -0,0 +1,380 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use crate::{
+    ANSWERS_FILE_NAME, Answer, InputError, InputLocator, ParseError, SolveError, SolverBase,
+    input_file_name, read_input,
+};
+
+pub type Year = u16;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn number(self) -> usize {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+
+    pub fn from_number(number: usize) -> Option<Part> {
+        match number {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+
+    pub fn answer(self, solver: &dyn SolverBase) -> Answer {
+        match self {
+            Part::One => solver.solve_part_one(),
+            Part::Two => solver.solve_part_two(),
+        }
+    }
+
+    /// Like `answer`, but a panic, a missing implementation, an input without solution or an empty answer is an error
+    pub fn solve(self, solver: &dyn SolverBase) -> Result<Answer, SolveError> {
+        let answer = panic::catch_unwind(AssertUnwindSafe(|| self.answer(solver)))
+            .map_err(|payload| SolveError::Panicked(panic_message(payload.as_ref())))?;
+        match answer {
+            Answer::Unsolved => Err(SolveError::NotImplemented),
+            Answer::NoSolution => Err(SolveError::NoSolution),
+            answer if answer.is_empty() => Err(SolveError::EmptyAnswer),
+            answer => Ok(answer),
+        }
+    }
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_owned()
+    }
+}
+
+pub type SolverFactory = fn(&str) -> Result<Box<dyn SolverBase>, ParseError>;
+
+#[derive(Debug)]
+pub enum LoadError {
+    Input(InputError),
+    Parse(ParseError),
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadError::Input(error) => error.fmt(f),
+            LoadError::Parse(error) => write!(f, "invalid input, {error}"),
+        }
+    }
+}
+
+impl std::error::Error for LoadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            LoadError::Input(error) => Some(error),
+            LoadError::Parse(error) => Some(error),
+        }
+    }
+}
+
+/// Day of a year together with the way its solver is built from the puzzle input
+pub struct Puzzle {
+    year: Year,
+    day: usize,
+    input_dir: PathBuf,
+    input_file: String,
+    factory: SolverFactory,
+}
+
+impl Puzzle {
+    /// Input is read from the default file of the day, see `input_file_name`
+    pub fn new(day: usize, factory: SolverFactory) -> Self {
+        Puzzle {
+            year: 0,
+            day,
+            input_dir: PathBuf::new(),
+            input_file: input_file_name(day),
+            factory,
+        }
+    }
+
+    pub fn with_input_file(mut self, file_name: &str) -> Self {
+        self.input_file = file_name.to_owned();
+        self
+    }
+
+    pub fn year(&self) -> Year {
+        self.year
+    }
+
+    pub fn day(&self) -> usize {
+        self.day
+    }
+
+    pub fn input_file(&self) -> &str {
+        &self.input_file
+    }
+
+    pub fn input_path(&self, locator: &InputLocator) -> PathBuf {
+        locator.locate(self.year, &self.input_dir, &self.input_file)
+    }
+
+    /// Answers of a year are recorded next to its inputs
+    pub fn answers_path(&self, locator: &InputLocator) -> PathBuf {
+        locator.locate(self.year, &self.input_dir, ANSWERS_FILE_NAME)
+    }
+
+    /// A parse error is reported for the day of the puzzle
+    pub fn build(&self, input: &str) -> Result<Box<dyn SolverBase>, ParseError> {
+        let solver = (self.factory)(input).map_err(|error| error.for_day(self.day))?;
+        debug_assert_eq!(
+            solver.day_number(),
+            self.day,
+            "solver registered for wrong day"
+        );
+        Ok(solver)
+    }
+
+    pub fn load(&self, locator: &InputLocator) -> Result<Box<dyn SolverBase>, LoadError> {
+        let input = read_input(&self.input_path(locator)).map_err(LoadError::Input)?;
+        self.build(&input).map_err(LoadError::Parse)
+    }
+}
+
+/// Puzzles of all years, keyed by year and day number
+#[derive(Default)]
+pub struct Registry {
+    years: BTreeMap<Year, BTreeMap<usize, Puzzle>>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers puzzles of a year with inputs in `input_dir`, a later puzzle for the same day replaces the earlier one
+    pub fn add_year(&mut self, year: Year, input_dir: impl Into<PathBuf>, puzzles: Vec<Puzzle>) {
+        let input_dir = input_dir.into();
+        let days = self.years.entry(year).or_default();
+        for mut puzzle in puzzles {
+            puzzle.year = year;
+            puzzle.input_dir = input_dir.clone();
+            days.insert(puzzle.day, puzzle);
+        }
+    }
+
+    pub fn years(&self) -> impl Iterator<Item = Year> + '_ {
+        self.years.keys().copied()
+    }
+
+    pub fn get(&self, year: Year, day: usize) -> Option<&Puzzle> {
+        self.years.get(&year)?.get(&day)
+    }
+
+    /// Returns puzzles ordered by year and day, `None` matches everything
+    pub fn select(&self, year: Option<Year>, day: Option<usize>) -> Vec<&Puzzle> {
+        self.years
+            .iter()
+            .filter(|(y, _)| year.is_none_or(|year| year == **y))
+            .flat_map(|(_, days)| days.values())
+            .filter(|puzzle| day.is_none_or(|day| day == puzzle.day))
+            .collect()
+    }
+
+    /// Runs every puzzle on its default input and prints the answers, the whole program of a per-year binary
+    pub fn run_all(&self) -> ExitCode {
+        let locator = InputLocator::from_env();
+        let mut exit_code = ExitCode::SUCCESS;
+        for puzzle in self.select(None, None) {
+            let solver = match puzzle.load(&locator) {
+                Ok(solver) => solver,
+                Err(error) => {
+                    println!("Day {}", puzzle.day());
+                    eprintln!("  error: {error}");
+                    exit_code = ExitCode::FAILURE;
+                    continue;
+                }
+            };
+            println!("Day {}: {}", solver.day_number(), solver.description());
+            if solver.skip_run() {
+                println!("  skipping...");
+                continue;
+            }
+            for part in [Part::One, Part::Two] {
+                match part.solve(solver.as_ref()) {
+                    Ok(answer) => println!("  Part {}: {answer}", part.number()),
+                    Err(error) => {
+                        if let SolveError::Panicked(_) = error {
+                            exit_code = ExitCode::FAILURE;
+                        }
+                        println!("  Part {}: {error}", part.number());
+                    }
+                }
+            }
+        }
+        exit_code
+    }
+}
+
+#[cfg(test)]
+mod registry_tests {
+    use super::*;
+
+    struct DummySolver(usize, String);
+
+    impl DummySolver {
+        fn new(day: usize, input: &str) -> Result<Self, ParseError> {
+            match input {
+                "invalid" => Err(ParseError::expected("a valid input", input).on_line(1, input)),
+                _ => Ok(DummySolver(day, input.to_owned())),
+            }
+        }
+    }
+
+    impl SolverBase for DummySolver {
+        fn solve_part_one(&self) -> Answer {
+            format!("{} {}", self.0, self.1).into()
+        }
+
+        fn solve_part_two(&self) -> Answer {
+            match self.1.as_str() {
+                "empty" => "".into(),
+                "panic" => panic!("no solution"),
+                "none" => Answer::NoSolution,
+                _ => Answer::Unsolved,
+            }
+        }
+
+        fn day_number(&self) -> usize {
+            self.0
+        }
+    }
+
+    fn registry() -> Registry {
+        let mut registry = Registry::new();
+        registry.add_year(
+            2017,
+            "input_2017",
+            vec![
+                Puzzle::new(2, |input| Ok(Box::new(DummySolver::new(2, input)?))),
+                Puzzle::new(1, |input| Ok(Box::new(DummySolver::new(1, input)?))),
+            ],
+        );
+        registry.add_year(
+            2015,
+            "input_2015",
+            vec![
+                Puzzle::new(1, |input| Ok(Box::new(DummySolver::new(1, input)?)))
+                    .with_input_file("day01.json"),
+            ],
+        );
+        registry
+    }
+
+    fn days(puzzles: Vec<&Puzzle>) -> Vec<(Year, usize)> {
+        puzzles
+            .into_iter()
+            .map(|puzzle| (puzzle.year(), puzzle.day()))
+            .collect()
+    }
+
+    #[test]
+    fn test_years_are_sorted() {
+        assert_eq!(registry().years().collect::<Vec<_>>(), vec![2015, 2017]);
+    }
+
+    #[test]
+    fn test_get() {
+        let registry = registry();
+        assert_eq!(registry.get(2017, 2).map(|p| p.day()), Some(2));
+        assert!(registry.get(2017, 3).is_none());
+        assert!(registry.get(2016, 1).is_none());
+    }
+
+    #[test]
+    fn test_select() {
+        let registry = registry();
+        assert_eq!(
+            days(registry.select(None, Some(1))),
+            vec![(2015, 1), (2017, 1)]
+        );
+        assert_eq!(
+            days(registry.select(Some(2017), None)),
+            vec![(2017, 1), (2017, 2)]
+        );
+        assert!(registry.select(Some(2017), Some(25)).is_empty());
+    }
+
+    #[test]
+    fn test_input_path() {
+        let registry = registry();
+        let locator = InputLocator::default();
+        assert_eq!(
+            registry.get(2017, 2).unwrap().input_path(&locator),
+            PathBuf::from("input_2017/day02.txt")
+        );
+        assert_eq!(
+            registry.get(2015, 1).unwrap().input_path(&locator),
+            PathBuf::from("input_2015/day01.json")
+        );
+        assert_eq!(
+            registry.get(2015, 1).unwrap().answers_path(&locator),
+            PathBuf::from("input_2015/answers.json")
+        );
+    }
+
+    #[test]
+    fn test_build() {
+        let solver = registry().get(2017, 1).unwrap().build("abc").unwrap();
+        assert_eq!(
+            Part::One.solve(solver.as_ref()),
+            Ok(Answer::Text("1 abc".to_owned()))
+        );
+        assert_eq!(
+            Part::Two.solve(solver.as_ref()),
+            Err(SolveError::NotImplemented)
+        );
+    }
+
+    #[test]
+    fn test_build_invalid_input() {
+        let error = registry().get(2017, 2).unwrap().build("invalid").err();
+        assert_eq!(
+            error.map(|error| error.to_string()),
+            Some("day 2, line 1, column 1: expected a valid input, found `invalid`".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_solve_errors() {
+        let registry = registry();
+        let puzzle = registry.get(2017, 1).unwrap();
+        let solver = puzzle.build("empty").unwrap();
+        assert_eq!(
+            Part::Two.solve(solver.as_ref()),
+            Err(SolveError::EmptyAnswer)
+        );
+        let solver = puzzle.build("panic").unwrap();
+        assert_eq!(
+            Part::Two.solve(solver.as_ref()),
+            Err(SolveError::Panicked("no solution".to_owned()))
+        );
+        let solver = puzzle.build("none").unwrap();
+        assert_eq!(
+            Part::Two.solve(solver.as_ref()),
+            Err(SolveError::NoSolution)
+        );
+    }
+}
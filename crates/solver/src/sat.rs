@@ -0,0 +1,393 @@
+use std::ops::Not;
+
+use varisat::{ExtendFormula, Lit};
+
+/// Boolean variable of a `Model`, `!` gives its negation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BoolVar(Lit);
+
+impl Not for BoolVar {
+    type Output = BoolVar;
+
+    fn not(self) -> BoolVar {
+        BoolVar(!self.0)
+    }
+}
+
+/// Unsigned integer of a `Model`, made of boolean variables with the least significant bit first
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntVar {
+    bits: Vec<BoolVar>,
+}
+
+impl IntVar {
+    pub fn bits(&self) -> &[BoolVar] {
+        &self.bits
+    }
+
+    pub fn width(&self) -> usize {
+        self.bits.len()
+    }
+}
+
+/// Constraint model backed by a SAT solver, where every constraint is turned into clauses
+pub struct Model {
+    solver: varisat::Solver<'static>,
+    variable_count: usize,
+    true_var: BoolVar,
+}
+
+/// Values of the variables in a satisfying assignment
+#[derive(Debug, Clone)]
+pub struct Solution {
+    values: Vec<bool>,
+}
+
+impl Solution {
+    pub fn value(&self, var: BoolVar) -> bool {
+        self.values[var.0.index()] == var.0.is_positive()
+    }
+
+    pub fn int(&self, var: &IntVar) -> u64 {
+        assert!(var.width() <= 64, "{} bits do not fit a u64", var.width());
+        var.bits
+            .iter()
+            .enumerate()
+            .map(|(i, &bit)| (self.value(bit) as u64) << i)
+            .sum()
+    }
+}
+
+impl Default for Model {
+    fn default() -> Self {
+        Model::new()
+    }
+}
+
+impl Model {
+    pub fn new() -> Self {
+        let mut solver = varisat::Solver::new();
+        let true_var = BoolVar(solver.new_lit());
+        solver.add_clause(&[true_var.0]);
+        Model {
+            solver,
+            variable_count: 1,
+            true_var,
+        }
+    }
+
+    pub fn bool(&mut self) -> BoolVar {
+        self.variable_count += 1;
+        BoolVar(self.solver.new_lit())
+    }
+
+    /// Variable fixed to `value`
+    pub fn constant(&self, value: bool) -> BoolVar {
+        if value { self.true_var } else { !self.true_var }
+    }
+
+    /// At least one of `vars` is true, none at all makes the model unsatisfiable
+    pub fn add_clause(&mut self, vars: &[BoolVar]) {
+        let literals: Vec<Lit> = vars.iter().map(|var| var.0).collect();
+        self.solver.add_clause(&literals);
+    }
+
+    pub fn assert(&mut self, var: BoolVar) {
+        self.add_clause(&[var]);
+    }
+
+    pub fn implies(&mut self, a: BoolVar, b: BoolVar) {
+        self.add_clause(&[!a, b]);
+    }
+
+    pub fn equal(&mut self, a: BoolVar, b: BoolVar) {
+        self.implies(a, b);
+        self.implies(b, a);
+    }
+
+    /// New variable which is `a AND b`
+    pub fn and(&mut self, a: BoolVar, b: BoolVar) -> BoolVar {
+        let result = self.bool();
+        self.add_clause(&[!result, a]);
+        self.add_clause(&[!result, b]);
+        self.add_clause(&[result, !a, !b]);
+        result
+    }
+
+    /// New variable which is `a OR b`
+    pub fn or(&mut self, a: BoolVar, b: BoolVar) -> BoolVar {
+        !self.and(!a, !b)
+    }
+
+    /// New variable which is `a XOR b`
+    pub fn xor(&mut self, a: BoolVar, b: BoolVar) -> BoolVar {
+        let result = self.bool();
+        self.add_clause(&[!result, a, b]);
+        self.add_clause(&[!result, !a, !b]);
+        self.add_clause(&[result, !a, b]);
+        self.add_clause(&[result, a, !b]);
+        result
+    }
+
+    /// At most `k` of `vars` are true, with a sequential counter
+    pub fn at_most(&mut self, vars: &[BoolVar], k: usize) {
+        if k == 0 {
+            for &var in vars {
+                self.assert(!var);
+            }
+            return;
+        }
+        if vars.len() <= k {
+            return;
+        }
+        // counts[j] of the previous var is true when at least j + 1 vars up to it are true
+        let mut previous: Vec<BoolVar> = Vec::new();
+        for (i, &var) in vars.iter().enumerate() {
+            if i > 0 {
+                // The count is already full, this var must be false
+                self.implies(var, !previous[k - 1]);
+            }
+            if i == vars.len() - 1 {
+                break;
+            }
+            let counts: Vec<BoolVar> = (0..k).map(|_| self.bool()).collect();
+            self.implies(var, counts[0]);
+            for (j, &count) in counts.iter().enumerate() {
+                if let Some(&previous_count) = previous.get(j) {
+                    self.implies(previous_count, count);
+                }
+                if j > 0 {
+                    match previous.get(j - 1) {
+                        Some(&previous_count) => self.add_clause(&[!var, !previous_count, count]),
+                        None => self.assert(!count),
+                    }
+                }
+            }
+            previous = counts;
+        }
+    }
+
+    /// At least `k` of `vars` are true
+    pub fn at_least(&mut self, vars: &[BoolVar], k: usize) {
+        if k > vars.len() {
+            self.add_clause(&[]);
+            return;
+        }
+        let negated: Vec<BoolVar> = vars.iter().map(|&var| !var).collect();
+        self.at_most(&negated, vars.len() - k);
+    }
+
+    pub fn exactly(&mut self, vars: &[BoolVar], k: usize) {
+        self.at_most(vars, k);
+        self.at_least(vars, k);
+    }
+
+    pub fn at_most_one(&mut self, vars: &[BoolVar]) {
+        self.at_most(vars, 1);
+    }
+
+    pub fn exactly_one(&mut self, vars: &[BoolVar]) {
+        self.add_clause(vars);
+        self.at_most_one(vars);
+    }
+
+    /// Integer in `0..2^width`
+    pub fn int(&mut self, width: usize) -> IntVar {
+        IntVar {
+            bits: (0..width).map(|_| self.bool()).collect(),
+        }
+    }
+
+    /// Integer fixed to `value`, with as many bits as it needs
+    pub fn int_constant(&self, value: u64) -> IntVar {
+        IntVar {
+            bits: (0..64 - value.leading_zeros())
+                .map(|i| self.constant(value >> i & 1 == 1))
+                .collect(),
+        }
+    }
+
+    /// `a + b` with a ripple carry adder, one bit wider than the widest of them
+    pub fn add(&mut self, a: &IntVar, b: &IntVar) -> IntVar {
+        let zero = self.constant(false);
+        let mut carry = zero;
+        let mut bits = Vec::new();
+        for i in 0..a.width().max(b.width()) {
+            let x = a.bits.get(i).copied().unwrap_or(zero);
+            let y = b.bits.get(i).copied().unwrap_or(zero);
+            let half = self.xor(x, y);
+            bits.push(self.xor(half, carry));
+            let both = self.and(x, y);
+            let carried = self.and(half, carry);
+            carry = self.or(both, carried);
+        }
+        bits.push(carry);
+        IntVar { bits }
+    }
+
+    /// `a * factor`, as a sum of shifted copies of `a`
+    pub fn scale(&mut self, a: &IntVar, factor: u64) -> IntVar {
+        let zero = self.constant(false);
+        let mut result = self.int_constant(0);
+        for shift in (0..64).filter(|shift| factor >> shift & 1 == 1) {
+            let shifted = IntVar {
+                bits: std::iter::repeat_n(zero, shift)
+                    .chain(a.bits.iter().copied())
+                    .collect(),
+            };
+            result = self.add(&result, &shifted);
+        }
+        result
+    }
+
+    pub fn sum(&mut self, values: &[IntVar]) -> IntVar {
+        values
+            .iter()
+            .fold(self.int_constant(0), |sum, value| self.add(&sum, value))
+    }
+
+    pub fn assert_equal_to(&mut self, a: &IntVar, value: u64) {
+        if a.width() < 64 && value >> a.width() != 0 {
+            self.add_clause(&[]);
+        }
+        for (i, &bit) in a.bits.iter().enumerate() {
+            self.assert(if i < 64 && value >> i & 1 == 1 {
+                bit
+            } else {
+                !bit
+            });
+        }
+    }
+
+    pub fn assert_at_most(&mut self, a: &IntVar, value: u64) {
+        for (i, &bit) in a.bits.iter().enumerate() {
+            if i < 64 && value >> i & 1 == 1 {
+                continue;
+            }
+            // A bit above `value` is only allowed when a higher bit is below it
+            let mut clause = vec![!bit];
+            clause.extend(
+                (i + 1..a.width().min(64))
+                    .filter(|&j| value >> j & 1 == 1)
+                    .map(|j| !a.bits[j]),
+            );
+            self.add_clause(&clause);
+        }
+    }
+
+    /// Satisfying assignment, `None` when the constraints cannot all hold
+    pub fn solve(&mut self) -> Option<Solution> {
+        if !self.solver.solve().expect("the SAT solver failed") {
+            return None;
+        }
+        let mut values = vec![false; self.variable_count];
+        for literal in self.solver.model()? {
+            values[literal.index()] = literal.is_positive();
+        }
+        Some(Solution { values })
+    }
+
+    /// Every solution which differs on `projection`, blocking each one with a clause once it is found
+    pub fn solutions<'a>(
+        &'a mut self,
+        projection: &'a [BoolVar],
+    ) -> impl Iterator<Item = Solution> + 'a {
+        std::iter::from_fn(move || {
+            let solution = self.solve()?;
+            let blocking: Vec<BoolVar> = projection
+                .iter()
+                .map(|&var| if solution.value(var) { !var } else { var })
+                .collect();
+            self.add_clause(&blocking);
+            Some(solution)
+        })
+    }
+}
+
+#[cfg(test)]
+mod sat_tests {
+    use super::*;
+
+    #[test]
+    fn test_gates() {
+        let mut model = Model::new();
+        let (a, b) = (model.bool(), model.bool());
+        let and = model.and(a, b);
+        let or = model.or(a, b);
+        let xor = model.xor(a, b);
+        let inputs = [a, b];
+        let solutions: Vec<_> = model.solutions(&inputs).collect();
+        assert_eq!(solutions.len(), 4);
+        for solution in solutions {
+            let (x, y) = (solution.value(a), solution.value(b));
+            assert_eq!(solution.value(and), x && y);
+            assert_eq!(solution.value(or), x || y);
+            assert_eq!(solution.value(xor), x != y);
+        }
+
+        model.equal(a, !b);
+        model.assert(and);
+        assert!(model.solve().is_none());
+    }
+
+    #[test]
+    fn test_cardinality() {
+        for (k, count) in [(0, 1), (1, 5), (2, 11), (4, 16), (5, 16)] {
+            let mut model = Model::new();
+            let vars: Vec<_> = (0..4).map(|_| model.bool()).collect();
+            model.at_most(&vars, k);
+            assert_eq!(model.solutions(&vars).count(), count, "at most {k}");
+        }
+
+        let mut model = Model::new();
+        let vars: Vec<_> = (0..5).map(|_| model.bool()).collect();
+        model.exactly(&vars, 2);
+        assert_eq!(model.solutions(&vars).count(), 10);
+
+        let mut model = Model::new();
+        let vars: Vec<_> = (0..5).map(|_| model.bool()).collect();
+        model.exactly_one(&vars);
+        for solution in model.solutions(&vars).collect::<Vec<_>>() {
+            assert_eq!(vars.iter().filter(|&&var| solution.value(var)).count(), 1);
+        }
+        model.at_least(&vars, 6);
+        assert!(model.solve().is_none());
+    }
+
+    #[test]
+    fn test_integers() {
+        // 3a + 5b = 19 and a + b <= 6
+        let mut model = Model::new();
+        let (a, b) = (model.int(4), model.int(4));
+        let (a3, b5) = (model.scale(&a, 3), model.scale(&b, 5));
+        let total = model.add(&a3, &b5);
+        model.assert_equal_to(&total, 19);
+        let count = model.add(&a, &b);
+        model.assert_at_most(&count, 6);
+        let projection: Vec<_> = a.bits().iter().chain(b.bits()).copied().collect();
+        let mut found: Vec<_> = model
+            .solutions(&projection)
+            .map(|solution| (solution.int(&a), solution.int(&b)))
+            .collect();
+        found.sort();
+        assert_eq!(found, [(3, 2)]);
+
+        // Every value of 4 bits up to 9
+        let mut model = Model::new();
+        let a = model.int(4);
+        model.assert_at_most(&a, 9);
+        let mut values: Vec<_> = model
+            .solutions(&a.bits.clone())
+            .map(|solution| solution.int(&a))
+            .collect();
+        values.sort();
+        assert_eq!(values, (0..=9).collect::<Vec<_>>());
+
+        let mut model = Model::new();
+        let values = [model.int_constant(7), model.int_constant(30)];
+        let sum = model.sum(&values);
+        assert_eq!(model.solve().unwrap().int(&sum), 37);
+        model.assert_equal_to(&sum, 36);
+        assert!(model.solve().is_none());
+    }
+}
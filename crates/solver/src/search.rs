@@ -0,0 +1,427 @@
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Costs of the states reached by a search and the state each of them was first reached from
+#[derive(Debug, Clone)]
+pub struct SearchResult<S, C> {
+    start: S,
+    goal: Option<S>,
+    costs: HashMap<S, C>,
+    parents: HashMap<S, S>,
+    expanded: usize,
+}
+
+impl<S, C> SearchResult<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy,
+{
+    fn new(start: S, cost: C) -> Self {
+        SearchResult {
+            start: start.clone(),
+            goal: None,
+            costs: HashMap::from([(start, cost)]),
+            parents: HashMap::new(),
+            expanded: 0,
+        }
+    }
+
+    /// The first goal state taken from the frontier, `None` when no goal is reachable
+    pub fn goal(&self) -> Option<&S> {
+        self.goal.as_ref()
+    }
+
+    /// Cost of the path to the goal
+    pub fn goal_cost(&self) -> Option<C> {
+        self.cost(self.goal.as_ref()?)
+    }
+
+    /// Cost of the best known path to `state`, which is optimal for expanded states
+    pub fn cost(&self, state: &S) -> Option<C> {
+        self.costs.get(state).copied()
+    }
+
+    /// States from the start to the goal, both included
+    pub fn path(&self) -> Option<Vec<S>> {
+        self.path_to(self.goal.as_ref()?)
+    }
+
+    /// States from the start to `state`, both included
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        if !self.costs.contains_key(state) {
+            return None;
+        }
+        let mut path = vec![state.clone()];
+        while let Some(parent) = self.parents.get(path.last()?) {
+            path.push(parent.clone());
+        }
+        path.reverse();
+        debug_assert!(path[0] == self.start);
+        Some(path)
+    }
+
+    /// Number of states taken from the frontier
+    pub fn expanded(&self) -> usize {
+        self.expanded
+    }
+
+    /// Number of distinct states seen, expanded or still on the frontier
+    pub fn reached(&self) -> usize {
+        self.costs.len()
+    }
+}
+
+/// Breadth-first search, the cost of a state is its number of steps from `start`
+///
+/// Stops at the first goal, an `is_goal` that is always false explores everything reachable.
+pub fn bfs<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> SearchResult<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut result = SearchResult::new(start.clone(), 0);
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((state, steps)) = queue.pop_front() {
+        result.expanded += 1;
+        if is_goal(&state) {
+            result.goal = Some(state);
+            break;
+        }
+        for next in successors(&state) {
+            if let Entry::Vacant(entry) = result.costs.entry(next.clone()) {
+                entry.insert(steps + 1);
+                result.parents.insert(next.clone(), state.clone());
+                queue.push_back((next, steps + 1));
+            }
+        }
+    }
+    result
+}
+
+/// Shortest paths with non-negative step costs, `successors` yields states with the cost of the step
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> SearchResult<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+/// Like `dijkstra`, but states with a lower cost plus `heuristic` are expanded first
+///
+/// The path is optimal when the heuristic never overestimates the remaining cost and never
+/// drops by more than the cost of a step.
+pub fn astar<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> SearchResult<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut result = SearchResult::new(start.clone(), C::default());
+    let mut frontier = BinaryHeap::from([Frontier {
+        priority: heuristic(&start),
+        cost: C::default(),
+        state: start,
+    }]);
+    while let Some(Frontier { cost, state, .. }) = frontier.pop() {
+        if result.costs.get(&state).is_some_and(|&best| best < cost) {
+            continue;
+        }
+        result.expanded += 1;
+        if is_goal(&state) {
+            result.goal = Some(state);
+            break;
+        }
+        for (next, step_cost) in successors(&state) {
+            let next_cost = cost + step_cost;
+            if result.costs.get(&next).is_none_or(|&best| next_cost < best) {
+                result.costs.insert(next.clone(), next_cost);
+                result.parents.insert(next.clone(), state.clone());
+                frontier.push(Frontier {
+                    priority: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    state: next,
+                });
+            }
+        }
+    }
+    result
+}
+
+/// Every optimal path to the cheapest goals, as a DAG of predecessors
+#[derive(Debug, Clone)]
+pub struct AllShortestPaths<S, C> {
+    goals: Vec<S>,
+    costs: HashMap<S, C>,
+    predecessors: HashMap<S, Vec<S>>,
+    expanded: usize,
+}
+
+impl<S, C> AllShortestPaths<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy,
+{
+    /// Goal states reached at the lowest cost, in the order they were expanded
+    pub fn goals(&self) -> &[S] {
+        &self.goals
+    }
+
+    pub fn goal_cost(&self) -> Option<C> {
+        self.cost(self.goals.first()?)
+    }
+
+    pub fn cost(&self, state: &S) -> Option<C> {
+        self.costs.get(state).copied()
+    }
+
+    /// States one step before `state` on its optimal paths
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.predecessors.get(state).map_or(&[], Vec::as_slice)
+    }
+
+    /// States that lie on at least one optimal path to a goal
+    pub fn states_on_paths(&self) -> HashSet<S> {
+        let mut states: HashSet<S> = self.goals.iter().cloned().collect();
+        let mut stack = self.goals.clone();
+        while let Some(state) = stack.pop() {
+            for predecessor in self.predecessors(&state) {
+                if states.insert(predecessor.clone()) {
+                    stack.push(predecessor.clone());
+                }
+            }
+        }
+        states
+    }
+
+    /// Every optimal path from the start to a goal, their number can grow exponentially
+    pub fn paths(&self) -> Vec<Vec<S>> {
+        let mut paths = Vec::new();
+        for goal in &self.goals {
+            self.collect_paths(&mut vec![goal.clone()], &mut paths);
+        }
+        paths
+    }
+
+    fn collect_paths(&self, reversed_path: &mut Vec<S>, paths: &mut Vec<Vec<S>>) {
+        let predecessors = self.predecessors(reversed_path.last().expect("path is not empty"));
+        if predecessors.is_empty() {
+            paths.push(reversed_path.iter().rev().cloned().collect());
+        }
+        for predecessor in predecessors {
+            reversed_path.push(predecessor.clone());
+            self.collect_paths(reversed_path, paths);
+            reversed_path.pop();
+        }
+    }
+
+    /// Number of states taken from the frontier
+    pub fn expanded(&self) -> usize {
+        self.expanded
+    }
+}
+
+/// Dijkstra that keeps every predecessor of a state on an optimal path, not only the first one
+///
+/// The search continues until the frontier is more expensive than the cheapest goal, so every goal
+/// reached at that cost is returned.
+pub fn dijkstra_all<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> AllShortestPaths<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut result = AllShortestPaths {
+        goals: Vec::new(),
+        costs: HashMap::from([(start.clone(), C::default())]),
+        predecessors: HashMap::new(),
+        expanded: 0,
+    };
+    let mut goal_cost = None;
+    let mut frontier = BinaryHeap::from([Frontier {
+        priority: C::default(),
+        cost: C::default(),
+        state: start,
+    }]);
+    while let Some(Frontier { cost, state, .. }) = frontier.pop() {
+        if goal_cost.is_some_and(|goal_cost| cost > goal_cost) {
+            break;
+        }
+        if result.costs.get(&state).is_some_and(|&best| best < cost) {
+            continue;
+        }
+        result.expanded += 1;
+        if is_goal(&state) {
+            goal_cost = Some(cost);
+            result.goals.push(state.clone());
+        }
+        for (next, step_cost) in successors(&state) {
+            let next_cost = cost + step_cost;
+            match result.costs.get(&next).map(|best| next_cost.cmp(best)) {
+                Some(Ordering::Greater) => {}
+                Some(Ordering::Equal) => {
+                    result
+                        .predecessors
+                        .entry(next)
+                        .or_default()
+                        .push(state.clone());
+                }
+                None | Some(Ordering::Less) => {
+                    result.costs.insert(next.clone(), next_cost);
+                    result
+                        .predecessors
+                        .insert(next.clone(), vec![state.clone()]);
+                    frontier.push(Frontier {
+                        priority: next_cost,
+                        cost: next_cost,
+                        state: next,
+                    });
+                }
+            }
+        }
+    }
+    result
+}
+
+/// Entry of the frontier, the lowest priority is taken first
+struct Frontier<S, C> {
+    priority: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> PartialEq for Frontier<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S, C: Ord> Eq for Frontier<S, C> {}
+
+impl<S, C: Ord> PartialOrd for Frontier<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> Ord for Frontier<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+#[cfg(test)]
+mod search_tests {
+    use super::*;
+
+    /// Steps of +1 and *2, the cost of a step is the number it leads to
+    fn successors(n: &u32) -> Vec<(u32, u32)> {
+        [n + 1, n * 2]
+            .into_iter()
+            .filter(|&next| next <= 20)
+            .map(|next| (next, next))
+            .collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let result = bfs(
+            1u32,
+            |&n| successors(&n).into_iter().map(|(next, _)| next),
+            |&n| n == 10,
+        );
+        assert_eq!(result.goal(), Some(&10));
+        assert_eq!(result.goal_cost(), Some(4));
+        assert_eq!(result.path(), Some(vec![1, 2, 4, 5, 10]));
+        assert!(result.expanded() <= result.reached());
+
+        let result = bfs(1u32, |&n| (n < 5).then_some(n + 1), |_| false);
+        assert_eq!(result.goal(), None);
+        assert_eq!(result.path(), None);
+        assert_eq!(result.cost(&5), Some(4));
+        assert_eq!(result.path_to(&3), Some(vec![1, 2, 3]));
+        assert_eq!(result.expanded(), 5);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let result = dijkstra(1u32, successors, |&n| n == 10);
+        assert_eq!(result.goal_cost(), Some(2 + 4 + 5 + 10));
+        assert_eq!(result.path(), Some(vec![1, 2, 4, 5, 10]));
+        assert_eq!(dijkstra(1u32, successors, |&n| n == 21).goal(), None);
+    }
+
+    #[test]
+    fn test_astar() {
+        let grid = ["S...#", ".##.#", "...#.", "#....", "...#E"];
+        let open = |(x, y): (i32, i32)| {
+            (0..5).contains(&x)
+                && (0..5).contains(&y)
+                && grid[y as usize].as_bytes()[x as usize] != b'#'
+        };
+        let successors = |&(x, y): &(i32, i32)| {
+            [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+                .into_iter()
+                .filter(|&next| open(next))
+                .map(|next| (next, 1))
+                .collect::<Vec<_>>()
+        };
+        let heuristic = |&(x, y): &(i32, i32)| (4 - x).abs() + (4 - y).abs();
+        let result = astar((0, 0), successors, heuristic, |&state| state == (4, 4));
+        let plain = dijkstra((0, 0), successors, |&state| state == (4, 4));
+        assert_eq!(result.goal_cost(), Some(8));
+        assert_eq!(plain.goal_cost(), Some(8));
+        assert_eq!(result.path().map(|path| path.len()), Some(9));
+        assert!(result.expanded() <= plain.expanded());
+    }
+
+    #[test]
+    fn test_dijkstra_all() {
+        // two ways around a square, and a longer detour
+        let edges: HashMap<char, Vec<(char, u32)>> = HashMap::from([
+            ('a', vec![('b', 1), ('c', 1), ('d', 5)]),
+            ('b', vec![('e', 1)]),
+            ('c', vec![('e', 1)]),
+            ('d', vec![('e', 1)]),
+        ]);
+        let successors = |state: &char| edges.get(state).cloned().unwrap_or_default();
+        let result = dijkstra_all('a', successors, |&state| state == 'e');
+        assert_eq!(result.goals(), ['e']);
+        assert_eq!(result.goal_cost(), Some(2));
+        let mut predecessors = result.predecessors(&'e').to_vec();
+        predecessors.sort();
+        assert_eq!(predecessors, ['b', 'c']);
+        assert_eq!(
+            result.states_on_paths(),
+            HashSet::from(['a', 'b', 'c', 'e'])
+        );
+        let mut paths = result.paths();
+        paths.sort();
+        assert_eq!(paths, [vec!['a', 'b', 'e'], vec!['a', 'c', 'e']]);
+
+        let result = dijkstra_all('a', successors, |&state| "bc".contains(state));
+        assert_eq!(result.goals().len(), 2);
+        assert_eq!(result.paths().len(), 2);
+    }
+}
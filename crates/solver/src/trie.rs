@@ -0,0 +1,208 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+#[derive(Debug, Clone)]
+struct TrieNode<K> {
+    children: HashMap<K, usize>, // symbol -> node index
+    is_end_of_word: bool,
+}
+
+impl<K> TrieNode<K> {
+    fn new() -> Self {
+        TrieNode {
+            children: HashMap::new(),
+            is_end_of_word: false,
+        }
+    }
+}
+
+/// Set of words over symbols `K`, usually the bytes or chars of strings, sharing their common prefixes
+#[derive(Debug, Clone)]
+pub struct Trie<K> {
+    nodes: Vec<TrieNode<K>>, // the root is the first node
+    len: usize,
+}
+
+impl<K> Default for Trie<K>
+where
+    K: Eq + Hash + Clone,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K> Trie<K>
+where
+    K: Eq + Hash + Clone,
+{
+    pub fn new() -> Self {
+        Trie {
+            nodes: vec![TrieNode::new()],
+            len: 0,
+        }
+    }
+
+    /// Number of words
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Adds a word, returns false when it was already there
+    pub fn insert(&mut self, word: impl IntoIterator<Item = K>) -> bool {
+        let mut current = 0;
+        for symbol in word {
+            current = match self.nodes[current].children.get(&symbol) {
+                Some(&next) => next,
+                None => {
+                    self.nodes.push(TrieNode::new());
+                    let next = self.nodes.len() - 1;
+                    self.nodes[current].children.insert(symbol, next);
+                    next
+                }
+            };
+        }
+        let added = !self.nodes[current].is_end_of_word;
+        self.nodes[current].is_end_of_word = true;
+        self.len += added as usize;
+        added
+    }
+
+    pub fn contains(&self, word: &[K]) -> bool {
+        let mut current = 0;
+        for symbol in word {
+            match self.nodes[current].children.get(symbol) {
+                Some(&next) => current = next,
+                None => return false,
+            }
+        }
+        self.nodes[current].is_end_of_word
+    }
+
+    /// Lengths of the words which are a prefix of `text`, shortest first
+    pub fn prefixes<'a>(&'a self, text: &'a [K]) -> impl Iterator<Item = usize> + 'a {
+        let mut current = 0;
+        text.iter()
+            .enumerate()
+            .map_while(move |(i, symbol)| {
+                let next = *self.nodes[current].children.get(symbol)?;
+                current = next;
+                Some((i + 1, self.nodes[next].is_end_of_word))
+            })
+            .filter_map(|(length, is_end_of_word)| is_end_of_word.then_some(length))
+    }
+
+    /// Length of the longest word which is a prefix of `text`
+    pub fn longest_match(&self, text: &[K]) -> Option<usize> {
+        self.prefixes(text).last()
+    }
+
+    /// Number of ways to split `text` into words, where a word can be used several times
+    pub fn count_segmentations(&self, text: &[K]) -> usize {
+        self.segmentation_counts(text)[0]
+    }
+
+    /// Every way to split `text` into words
+    pub fn segmentations<'a>(&self, text: &'a [K]) -> Vec<Vec<&'a [K]>> {
+        let counts = self.segmentation_counts(text);
+        let mut result = Vec::with_capacity(counts[0]);
+        self.collect_segmentations(text, 0, &counts, &mut Vec::new(), &mut result);
+        result
+    }
+
+    /// Number of segmentations of each suffix of `text`, by the index where the suffix starts
+    fn segmentation_counts(&self, text: &[K]) -> Vec<usize> {
+        let mut counts = vec![0; text.len() + 1];
+        counts[text.len()] = 1;
+        for start in (0..text.len()).rev() {
+            counts[start] = self
+                .prefixes(&text[start..])
+                .map(|length| counts[start + length])
+                .sum();
+        }
+        counts
+    }
+
+    fn collect_segmentations<'a>(
+        &self,
+        text: &'a [K],
+        start: usize,
+        counts: &[usize],
+        words: &mut Vec<&'a [K]>,
+        result: &mut Vec<Vec<&'a [K]>>,
+    ) {
+        if start == text.len() {
+            result.push(words.clone());
+            return;
+        }
+        for length in self.prefixes(&text[start..]) {
+            // Only follow the splits which lead somewhere
+            if counts[start + length] > 0 {
+                words.push(&text[start..start + length]);
+                self.collect_segmentations(text, start + length, counts, words, result);
+                words.pop();
+            }
+        }
+    }
+}
+
+impl Trie<u8> {
+    /// Trie over the bytes of `words`
+    pub fn from_words<'a>(words: impl IntoIterator<Item = &'a str>) -> Self {
+        let mut trie = Trie::new();
+        for word in words {
+            trie.insert(word.bytes());
+        }
+        trie
+    }
+}
+
+#[cfg(test)]
+mod trie_tests {
+    use super::*;
+
+    #[test]
+    fn test_insert() {
+        let mut trie = Trie::new();
+        assert!(trie.insert("bwu".chars()));
+        assert!(trie.insert("b".chars()));
+        assert!(!trie.insert("b".chars()));
+        assert_eq!(trie.len(), 2);
+        assert!(trie.contains(&['b', 'w', 'u']));
+        assert!(!trie.contains(&['b', 'w']));
+        assert!(!trie.contains(&[]));
+    }
+
+    #[test]
+    fn test_prefixes() {
+        let trie = Trie::from_words(["b", "bw", "bwu", "r"]);
+        assert_eq!(trie.prefixes(b"bwur").collect::<Vec<_>>(), [1, 2, 3]);
+        assert_eq!(trie.prefixes(b"bx").collect::<Vec<_>>(), [1]);
+        assert_eq!(trie.longest_match(b"bwux"), Some(3));
+        assert_eq!(trie.longest_match(b"g"), None);
+    }
+
+    #[test]
+    fn test_segmentations() {
+        let trie = Trie::from_words(["r", "wr", "b", "g", "bwu", "rb", "gb", "br"]);
+        assert_eq!(trie.count_segmentations(b"gbbr"), 4);
+        assert_eq!(trie.count_segmentations(b"rrbgbr"), 6);
+        assert_eq!(trie.count_segmentations(b"ubwu"), 0);
+        assert_eq!(trie.count_segmentations(b""), 1);
+
+        let mut segmentations = trie.segmentations(b"brwrr");
+        segmentations.sort();
+        assert_eq!(
+            segmentations,
+            [
+                vec![&b"b"[..], b"r", b"wr", b"r"],
+                vec![&b"br"[..], b"wr", b"r"],
+            ]
+        );
+        assert!(trie.segmentations(b"bbrgwb").is_empty());
+    }
+}
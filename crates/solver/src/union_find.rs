@@ -1,9 +1,108 @@
 use std::collections::HashMap;
 use std::hash::Hash;
 
-pub struct UnionFind<T> {
+/// Union-find over the elements `0..len()`, with union by size and path compression
+#[derive(Debug, Clone, Default)]
+pub struct DenseUnionFind {
     parent: Vec<usize>,
-    rank: Vec<usize>,
+    size: Vec<usize>,
+    count: usize,
+}
+
+impl DenseUnionFind {
+    /// Every element starts in its own component
+    pub fn new(len: usize) -> Self {
+        Self {
+            parent: (0..len).collect(),
+            size: vec![1; len],
+            count: len,
+        }
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            parent: Vec::with_capacity(capacity),
+            size: Vec::with_capacity(capacity),
+            count: 0,
+        }
+    }
+
+    /// Adds an element in its own component and returns its index
+    pub fn push(&mut self) -> usize {
+        let i = self.parent.len();
+        self.parent.push(i);
+        self.size.push(1);
+        self.count += 1;
+        i
+    }
+
+    /// Number of elements
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Number of components
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Root of the component of `i`
+    pub fn find(&mut self, i: usize) -> usize {
+        let mut root = i;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        let mut current = i;
+        while self.parent[current] != root {
+            current = std::mem::replace(&mut self.parent[current], root);
+        }
+        root
+    }
+
+    /// Merges the components of `i` and `j`, returns false when they are already the same
+    pub fn union(&mut self, i: usize, j: usize) -> bool {
+        let (mut ri, mut rj) = (self.find(i), self.find(j));
+        if ri == rj {
+            return false;
+        }
+        if self.size[ri] < self.size[rj] {
+            std::mem::swap(&mut ri, &mut rj);
+        }
+        self.parent[rj] = ri;
+        self.size[ri] += self.size[rj];
+        self.count -= 1;
+        true
+    }
+
+    pub fn connected(&mut self, i: usize, j: usize) -> bool {
+        self.find(i) == self.find(j)
+    }
+
+    /// Number of elements in the component of `i`
+    pub fn size(&mut self, i: usize) -> usize {
+        let root = self.find(i);
+        self.size[root]
+    }
+
+    /// One element of each component
+    pub fn roots(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.parent.len()).filter(|&i| self.parent[i] == i)
+    }
+
+    /// Sizes of the components, in the order of `roots`
+    pub fn component_sizes(&self) -> impl Iterator<Item = usize> + '_ {
+        self.roots().map(|root| self.size[root])
+    }
+}
+
+/// Union-find over arbitrary values, which are added by `insert` or `union`
+#[derive(Debug, Clone)]
+pub struct UnionFind<T> {
+    sets: DenseUnionFind,
     index: HashMap<T, usize>,
     values: Vec<T>, // index -> value
 }
@@ -21,74 +120,94 @@ impl<T> UnionFind<T>
 where
     T: Eq + Hash + Clone,
 {
-    fn new() -> Self {
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
         Self {
-            parent: Vec::new(),
-            rank: Vec::new(),
-            index: HashMap::new(),
-            values: Vec::new(),
+            sets: DenseUnionFind::with_capacity(capacity),
+            index: HashMap::with_capacity(capacity),
+            values: Vec::with_capacity(capacity),
         }
     }
 
-    fn get_or_insert(&mut self, x: T) -> usize {
+    /// Adds `x` in its own component unless it is already known, returns its index
+    pub fn insert(&mut self, x: T) -> usize {
         if let Some(&i) = self.index.get(&x) {
             i
         } else {
-            let i = self.parent.len();
-            self.parent.push(i);
-            self.rank.push(0);
+            let i = self.sets.push();
             self.index.insert(x.clone(), i);
             self.values.push(x);
             i
         }
     }
 
-    fn find_index(&mut self, i: usize) -> usize {
-        if self.parent[i] != i {
-            let root = self.find_index(self.parent[i]);
-            self.parent[i] = root;
-        }
-        self.parent[i]
+    pub fn contains(&self, x: &T) -> bool {
+        self.index.contains_key(x)
     }
 
-    pub fn find(&mut self, x: T) -> usize {
-        let i = self.get_or_insert(x);
-        self.find_index(i)
+    /// Number of elements
+    pub fn len(&self) -> usize {
+        self.values.len()
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Number of components
+    pub fn count(&self) -> usize {
+        self.sets.count()
+    }
+
+    /// Index of the root of the component of `x`, `None` when `x` is unknown
+    pub fn find(&mut self, x: &T) -> Option<usize> {
+        let i = *self.index.get(x)?;
+        Some(self.sets.find(i))
+    }
+
+    /// Merges the components of `x` and `y`, inserting them when needed
+    ///
+    /// Returns false when they were already in the same component.
     pub fn union(&mut self, x: T, y: T) -> bool {
-        let rx = self.find(x);
-        let ry = self.find(y);
+        let i = self.insert(x);
+        let j = self.insert(y);
+        self.sets.union(i, j)
+    }
 
-        if rx == ry {
-            return false;
+    /// An unknown element is only connected to itself
+    pub fn connected(&mut self, x: &T, y: &T) -> bool {
+        match (self.index.get(x), self.index.get(y)) {
+            (Some(&i), Some(&j)) => self.sets.connected(i, j),
+            _ => x == y,
         }
+    }
 
-        if self.rank[rx] < self.rank[ry] {
-            self.parent[rx] = ry;
-        } else if self.rank[rx] > self.rank[ry] {
-            self.parent[ry] = rx;
-        } else {
-            self.parent[ry] = rx;
-            self.rank[rx] += 1;
-        }
+    /// Number of elements in the component of `x`, `None` when `x` is unknown
+    pub fn size(&mut self, x: &T) -> Option<usize> {
+        let i = *self.index.get(x)?;
+        Some(self.sets.size(i))
+    }
 
-        true
+    /// One element of each component
+    pub fn roots(&self) -> impl Iterator<Item = &T> + '_ {
+        self.sets.roots().map(|i| &self.values[i])
+    }
+
+    /// Sizes of the components, in the order of `roots`
+    pub fn component_sizes(&self) -> impl Iterator<Item = usize> + '_ {
+        self.sets.component_sizes()
     }
 
     /// Returns groups ordered by descending size
     pub fn groups_by_size(&mut self) -> Vec<Vec<T>> {
-        // Ensure full path compression
-        for i in 0..self.parent.len() {
-            let root = self.find_index(i);
-            self.parent[i] = root;
-        }
-
         // root -> elements
         let mut groups: HashMap<usize, Vec<T>> = HashMap::new();
 
         for (i, value) in self.values.iter().cloned().enumerate() {
-            let root = self.parent[i];
+            let root = self.sets.find(i);
             groups.entry(root).or_default().push(value);
         }
 
@@ -110,3 +229,253 @@ where
         }
     }
 }
+
+/// Union-find with union by rank and no path compression, so that unions can be undone
+#[derive(Debug, Clone, Default)]
+pub struct RollbackUnionFind {
+    parent: Vec<usize>,
+    rank: Vec<u8>,
+    count: usize,
+    history: Vec<Option<(usize, bool)>>, // (attached root, whether the rank of its new parent grew)
+}
+
+impl RollbackUnionFind {
+    /// Every element starts in its own component
+    pub fn new(len: usize) -> Self {
+        Self {
+            parent: (0..len).collect(),
+            rank: vec![0; len],
+            count: len,
+            history: Vec::new(),
+        }
+    }
+
+    /// Number of elements
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Number of components
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Root of the component of `i`, in O(log n) steps
+    pub fn find(&self, mut i: usize) -> usize {
+        while self.parent[i] != i {
+            i = self.parent[i];
+        }
+        i
+    }
+
+    /// Merges the components of `i` and `j`, returns false when they are already the same
+    ///
+    /// Every call can be undone, including the ones returning false.
+    pub fn union(&mut self, i: usize, j: usize) -> bool {
+        let (mut ri, mut rj) = (self.find(i), self.find(j));
+        if ri == rj {
+            self.history.push(None);
+            return false;
+        }
+        if self.rank[ri] < self.rank[rj] {
+            std::mem::swap(&mut ri, &mut rj);
+        }
+        let grew = self.rank[ri] == self.rank[rj];
+        self.parent[rj] = ri;
+        if grew {
+            self.rank[ri] += 1;
+        }
+        self.count -= 1;
+        self.history.push(Some((rj, grew)));
+        true
+    }
+
+    pub fn connected(&self, i: usize, j: usize) -> bool {
+        self.find(i) == self.find(j)
+    }
+
+    /// Current state, to be passed to `rollback`
+    pub fn snapshot(&self) -> usize {
+        self.history.len()
+    }
+
+    /// Undoes every union made since `snapshot`
+    pub fn rollback(&mut self, snapshot: usize) {
+        while self.history.len() > snapshot {
+            if let Some(Some((child, grew))) = self.history.pop() {
+                let root = self.parent[child];
+                self.parent[child] = child;
+                if grew {
+                    self.rank[root] -= 1;
+                }
+                self.count += 1;
+            }
+        }
+    }
+}
+
+/// Index of the first addition after which `a` and `b` are connected
+///
+/// The edges are `(i, j, time)` over the elements `0..len`, the edges with the same time are added together.
+pub fn first_connection(
+    len: usize,
+    edges: impl IntoIterator<Item = (usize, usize, usize)>,
+    a: usize,
+    b: usize,
+) -> Option<usize> {
+    let mut edges: Vec<_> = edges.into_iter().collect();
+    edges.sort_unstable_by_key(|&(_, _, time)| time);
+    let mut sets = RollbackUnionFind::new(len);
+    for (i, j, time) in edges {
+        sets.union(i, j);
+        if sets.connected(a, b) {
+            return Some(time);
+        }
+    }
+    None
+}
+
+/// Index of the first removal after which `a` and `b` are no longer connected
+///
+/// The edges are `(i, j, time)` over the elements `0..len`, where `time` is the removal removing the edge, `None` if
+/// it is never removed. The removals are replayed backwards, so this takes a single pass over the edges. Returns
+/// `None` when `a` and `b` stay connected, or are not connected to begin with.
+pub fn first_disconnection(
+    len: usize,
+    edges: impl IntoIterator<Item = (usize, usize, Option<usize>)>,
+    a: usize,
+    b: usize,
+) -> Option<usize> {
+    let mut edges: Vec<_> = edges.into_iter().collect();
+    // Edges never removed first, then the latest removals
+    edges.sort_unstable_by_key(|&(_, _, time)| (time.is_some(), std::cmp::Reverse(time)));
+    let mut sets = RollbackUnionFind::new(len);
+    let mut edges = edges.into_iter().peekable();
+    while let Some(&(_, _, None)) = edges.peek() {
+        let (i, j, _) = edges.next().unwrap();
+        sets.union(i, j);
+    }
+    if sets.connected(a, b) {
+        return None;
+    }
+    while let Some((i, j, time)) = edges.next() {
+        sets.union(i, j);
+        // The state before a removal is only known once all of its edges are back
+        if edges.peek().is_none_or(|&(_, _, next)| next != time) && sets.connected(a, b) {
+            return time;
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod union_find_tests {
+    use super::*;
+
+    #[test]
+    fn test_dense() {
+        let mut sets = DenseUnionFind::new(5);
+        assert_eq!(sets.count(), 5);
+        assert!(sets.union(0, 1));
+        assert!(sets.union(3, 4));
+        assert!(sets.union(1, 4));
+        assert!(!sets.union(0, 3));
+        assert_eq!(sets.count(), 2);
+        assert!(sets.connected(0, 4));
+        assert!(!sets.connected(2, 4));
+        assert_eq!(sets.size(3), 4);
+        assert_eq!(sets.size(2), 1);
+        assert_eq!(sets.roots().count(), 2);
+        let mut sizes: Vec<_> = sets.component_sizes().collect();
+        sizes.sort();
+        assert_eq!(sizes, [1, 4]);
+
+        let i = sets.push();
+        assert_eq!((i, sets.len(), sets.count()), (5, 6, 3));
+    }
+
+    #[test]
+    fn test_values() {
+        let mut sets = UnionFind::with_capacity(4);
+        assert!(sets.union("a", "b"));
+        assert!(sets.union("c", "d"));
+        assert!(!sets.union("b", "a"));
+        assert_eq!((sets.len(), sets.count()), (4, 2));
+        assert!(sets.connected(&"a", &"b"));
+        assert!(!sets.connected(&"a", &"c"));
+        assert_eq!(sets.size(&"d"), Some(2));
+
+        sets.insert("e");
+        assert_eq!(sets.count(), 3);
+        let mut roots: Vec<_> = sets.roots().copied().collect();
+        roots.sort();
+        assert_eq!(roots.len(), 3);
+        assert!(roots.contains(&"e"));
+        assert_eq!(sets.groups_by_size()[0].len(), 2);
+    }
+
+    #[test]
+    fn test_queries_do_not_insert() {
+        let mut sets = UnionFind::new();
+        sets.insert(1);
+        assert_eq!(sets.find(&2), None);
+        assert_eq!(sets.size(&2), None);
+        assert!(!sets.connected(&1, &2));
+        assert!(sets.connected(&2, &2));
+        assert!(!sets.contains(&2));
+        assert_eq!(sets.len(), 1);
+    }
+
+    #[test]
+    fn test_rollback() {
+        let mut sets = RollbackUnionFind::new(4);
+        assert!(sets.union(0, 1));
+        let snapshot = sets.snapshot();
+        assert!(sets.union(2, 3));
+        assert!(!sets.union(3, 2));
+        assert!(sets.union(1, 2));
+        assert!(sets.connected(0, 3));
+        assert_eq!(sets.count(), 1);
+
+        sets.rollback(snapshot);
+        assert_eq!(sets.count(), 3);
+        assert!(sets.connected(0, 1));
+        assert!(!sets.connected(1, 2));
+        assert!(!sets.connected(2, 3));
+        assert!(sets.union(3, 0));
+        assert_eq!(sets.count(), 2);
+    }
+
+    #[test]
+    fn test_first_connection() {
+        let edges = [(0, 1, 2), (2, 3, 0), (1, 2, 1), (3, 4, 5)];
+        assert_eq!(first_connection(5, edges, 0, 3), Some(2));
+        assert_eq!(first_connection(5, edges, 2, 3), Some(0));
+        assert_eq!(first_connection(5, edges, 0, 4), Some(5));
+        assert_eq!(first_connection(6, edges, 0, 5), None);
+    }
+
+    #[test]
+    fn test_first_disconnection() {
+        // Two paths between 0 and 3: 0-1-3 and 0-2-3
+        let edges = [
+            (0, 1, Some(4)),
+            (1, 3, Some(1)),
+            (0, 2, None),
+            (2, 3, Some(3)),
+            (3, 4, None),
+        ];
+        assert_eq!(first_disconnection(5, edges, 0, 3), Some(3));
+        assert_eq!(first_disconnection(5, edges, 0, 1), Some(4));
+        assert_eq!(first_disconnection(5, edges, 3, 4), None);
+        assert_eq!(first_disconnection(6, edges, 0, 5), None);
+
+        // Both paths are cut by the same removal
+        let edges = [(0, 1, Some(2)), (1, 3, None), (0, 2, Some(2)), (2, 3, None)];
+        assert_eq!(first_disconnection(4, edges, 0, 3), Some(2));
+    }
+}
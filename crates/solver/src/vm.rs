@@ -0,0 +1,354 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// Instruction of a register machine, usually an enum whose variants are the operations
+pub trait Instruction {
+    type Value: Copy + Default;
+
+    /// Runs the instruction against the registers and the channels, and tells where to go next
+    fn execute(&self, cpu: &mut Cpu<Self::Value>) -> Step;
+}
+
+/// What happens after an instruction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    /// Go to the next instruction
+    Next,
+    /// Move the instruction pointer by an offset
+    Jump(isize),
+    /// Go to an instruction
+    JumpTo(usize),
+    /// Stop the machine
+    Halt,
+    /// Stay on this instruction until there is some input, the instruction must not have changed anything
+    Wait,
+}
+
+/// Why a machine stopped
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stop {
+    /// The instruction pointer left the program
+    Finished,
+    /// An instruction asked to halt
+    Halted,
+    /// An instruction waits for input
+    WaitingForInput,
+    /// The step limit was reached
+    StepLimit,
+    /// The machine came back to an earlier state, so it would run forever
+    Loop,
+}
+
+/// Register or immediate operand
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operand<V> {
+    Register(usize),
+    Value(V),
+}
+
+/// Indices of named registers, handed out in the order the names are first seen while parsing a program
+#[derive(Debug, Clone, Default)]
+pub struct RegisterNames {
+    index: HashMap<String, usize>,
+    names: Vec<String>,
+}
+
+impl RegisterNames {
+    pub fn new() -> Self {
+        RegisterNames::default()
+    }
+
+    /// Index of the register, a new one for an unknown name
+    pub fn register(&mut self, name: &str) -> usize {
+        if let Some(&i) = self.index.get(name) {
+            return i;
+        }
+        let i = self.names.len();
+        self.index.insert(name.to_owned(), i);
+        self.names.push(name.to_owned());
+        i
+    }
+
+    pub fn get(&self, name: &str) -> Option<usize> {
+        self.index.get(name).copied()
+    }
+
+    pub fn name(&self, register: usize) -> &str {
+        &self.names[register]
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+}
+
+/// Registers and channels of a machine, which the instructions work on
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cpu<V> {
+    pub registers: Vec<V>,
+    input: VecDeque<V>,
+    output: Vec<V>,
+}
+
+impl<V: Copy + Default> Cpu<V> {
+    pub fn get(&self, operand: Operand<V>) -> V {
+        match operand {
+            Operand::Register(register) => self.registers[register],
+            Operand::Value(value) => value,
+        }
+    }
+
+    /// Takes the next input value, the instruction should return `Step::Wait` when there is none
+    pub fn read(&mut self) -> Option<V> {
+        self.input.pop_front()
+    }
+
+    pub fn write(&mut self, value: V) {
+        self.output.push(value);
+    }
+}
+
+/// State of the machine before an instruction runs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceEntry<V> {
+    pub step: usize,
+    pub instruction_pointer: usize,
+    pub registers: Vec<V>,
+}
+
+/// Runs a program with a shared fetch and execute loop
+#[derive(Debug, Clone)]
+pub struct Machine<'a, I: Instruction> {
+    program: &'a [I],
+    pub cpu: Cpu<I::Value>,
+    pub instruction_pointer: usize,
+    steps: usize,
+    step_limit: Option<usize>,
+    trace: Option<Vec<TraceEntry<I::Value>>>,
+}
+
+impl<'a, I: Instruction> Machine<'a, I> {
+    /// Machine at the start of `program`, with every register at its default value
+    pub fn new(program: &'a [I], register_count: usize) -> Self {
+        Machine::with_registers(program, vec![I::Value::default(); register_count])
+    }
+
+    pub fn with_registers(program: &'a [I], registers: Vec<I::Value>) -> Self {
+        Machine {
+            program,
+            cpu: Cpu {
+                registers,
+                input: VecDeque::new(),
+                output: Vec::new(),
+            },
+            instruction_pointer: 0,
+            steps: 0,
+            step_limit: None,
+            trace: None,
+        }
+    }
+
+    /// Stops the machine once it has run `limit` instructions in total
+    pub fn with_step_limit(mut self, limit: usize) -> Self {
+        self.step_limit = Some(limit);
+        self
+    }
+
+    /// Records the state before every instruction
+    pub fn with_trace(mut self) -> Self {
+        self.trace = Some(Vec::new());
+        self
+    }
+
+    pub fn registers(&self) -> &[I::Value] {
+        &self.cpu.registers
+    }
+
+    /// Number of instructions run so far
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    pub fn trace(&self) -> &[TraceEntry<I::Value>] {
+        self.trace.as_deref().unwrap_or_default()
+    }
+
+    pub fn push_input(&mut self, value: I::Value) {
+        self.cpu.input.push_back(value);
+    }
+
+    /// Takes the values written so far
+    pub fn take_output(&mut self) -> Vec<I::Value> {
+        std::mem::take(&mut self.cpu.output)
+    }
+
+    pub fn output(&self) -> &[I::Value] {
+        &self.cpu.output
+    }
+
+    /// Runs a single instruction, `Some` when the machine stops instead
+    pub fn step(&mut self) -> Option<Stop> {
+        let Some(instruction) = self.program.get(self.instruction_pointer) else {
+            return Some(Stop::Finished);
+        };
+        if self.step_limit.is_some_and(|limit| self.steps >= limit) {
+            return Some(Stop::StepLimit);
+        }
+        let entry = self.trace.is_some().then(|| TraceEntry {
+            step: self.steps,
+            instruction_pointer: self.instruction_pointer,
+            registers: self.cpu.registers.clone(),
+        });
+        let step = instruction.execute(&mut self.cpu);
+        if step == Step::Wait {
+            return Some(Stop::WaitingForInput);
+        }
+        if let (Some(trace), Some(entry)) = (&mut self.trace, entry) {
+            trace.push(entry);
+        }
+        let next = match step {
+            Step::Next => self.instruction_pointer.checked_add(1),
+            Step::Jump(offset) => self.instruction_pointer.checked_add_signed(offset),
+            Step::JumpTo(target) => Some(target),
+            Step::Halt => {
+                self.steps += 1;
+                return Some(Stop::Halted);
+            }
+            Step::Wait => unreachable!(),
+        };
+        self.steps += 1;
+        // A jump before the start leaves the program just like one past the end
+        self.instruction_pointer = next.unwrap_or(usize::MAX);
+        None
+    }
+
+    /// Runs until the machine stops
+    pub fn run(&mut self) -> Stop {
+        loop {
+            if let Some(stop) = self.step() {
+                return stop;
+            }
+        }
+    }
+
+    /// Like `run`, but also stops when the instruction pointer and the registers repeat
+    ///
+    /// Only valid for programs whose instructions depend on nothing but the registers, e.g. without input.
+    pub fn run_detecting_loops(&mut self) -> Stop
+    where
+        I::Value: Eq + Hash,
+    {
+        let mut seen = HashSet::new();
+        loop {
+            if !seen.insert((self.instruction_pointer, self.cpu.registers.clone())) {
+                return Stop::Loop;
+            }
+            if let Some(stop) = self.step() {
+                return stop;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod vm_tests {
+    use super::*;
+
+    // Small assembunny-like instruction set
+    #[derive(Debug)]
+    enum Op {
+        Cpy(Operand<i64>, usize),
+        Inc(usize),
+        Dec(usize),
+        Jnz(Operand<i64>, isize),
+        In(usize),
+        Out(Operand<i64>),
+        Hlt,
+    }
+
+    impl Instruction for Op {
+        type Value = i64;
+
+        fn execute(&self, cpu: &mut Cpu<i64>) -> Step {
+            match *self {
+                Op::Cpy(from, to) => cpu.registers[to] = cpu.get(from),
+                Op::Inc(register) => cpu.registers[register] += 1,
+                Op::Dec(register) => cpu.registers[register] -= 1,
+                Op::Jnz(condition, offset) if cpu.get(condition) != 0 => return Step::Jump(offset),
+                Op::Jnz(..) => {}
+                Op::In(register) => match cpu.read() {
+                    Some(value) => cpu.registers[register] = value,
+                    None => return Step::Wait,
+                },
+                Op::Out(operand) => cpu.write(cpu.get(operand)),
+                Op::Hlt => return Step::Halt,
+            }
+            Step::Next
+        }
+    }
+
+    #[test]
+    fn test_run() {
+        let mut names = RegisterNames::new();
+        let (a, b) = (names.register("a"), names.register("b"));
+        assert_eq!(names.register("a"), a);
+        assert_eq!(
+            (names.get("b"), names.name(b), names.len()),
+            (Some(1), "b", 2)
+        );
+
+        // Moves 4 from b to a one at a time
+        let program = [
+            Op::Cpy(Operand::Value(4), b),
+            Op::Inc(a),
+            Op::Dec(b),
+            Op::Jnz(Operand::Register(b), -2),
+            Op::Out(Operand::Register(a)),
+        ];
+        let mut machine = Machine::new(&program, names.len()).with_trace();
+        assert_eq!(machine.run(), Stop::Finished);
+        assert_eq!(machine.registers(), [4, 0]);
+        assert_eq!(machine.take_output(), [4]);
+        assert_eq!(machine.steps(), 1 + 4 * 3 + 1);
+        assert_eq!(machine.trace().len(), machine.steps());
+        assert_eq!(
+            machine.trace()[2],
+            TraceEntry {
+                step: 2,
+                instruction_pointer: 2,
+                registers: vec![1, 4],
+            }
+        );
+
+        let mut machine = Machine::new(&program, 2).with_step_limit(5);
+        assert_eq!(machine.run(), Stop::StepLimit);
+        assert_eq!(machine.instruction_pointer, 2);
+    }
+
+    #[test]
+    fn test_channels_and_loops() {
+        let program = [
+            Op::In(0),
+            Op::Out(Operand::Register(0)),
+            Op::Jnz(Operand::Register(0), -2),
+            Op::Hlt,
+        ];
+        let mut machine = Machine::new(&program, 1);
+        assert_eq!(machine.run(), Stop::WaitingForInput);
+        machine.push_input(7);
+        machine.push_input(0);
+        assert_eq!(machine.run(), Stop::Halted);
+        assert_eq!(machine.output(), [7, 0]);
+
+        let program = [Op::Inc(0), Op::Dec(0), Op::Jnz(Operand::Value(1), -2)];
+        let mut machine = Machine::new(&program, 1);
+        assert_eq!(machine.run_detecting_loops(), Stop::Loop);
+        assert_eq!(machine.steps(), 3);
+        let program = [Op::Jnz(Operand::Value(1), -1)];
+        assert_eq!(Machine::new(&program, 0).run(), Stop::Finished);
+    }
+}